@@ -0,0 +1,726 @@
+use linear_algebra_42::traits::{Magnitude, Negative, One, Zero};
+use linear_algebra_42::{Complex, Matrix, Rational, Vector};
+use std::cell::Cell;
+use std::env;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, Read};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::process;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Det,
+    Inv,
+    Rank,
+    Rref,
+    Transpose,
+    Mul,
+    Solve,
+    Eig,
+    Svd,
+}
+
+impl Operation {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "det" => Some(Operation::Det),
+            "inv" => Some(Operation::Inv),
+            "rank" => Some(Operation::Rank),
+            "rref" => Some(Operation::Rref),
+            "transpose" => Some(Operation::Transpose),
+            "mul" => Some(Operation::Mul),
+            "solve" => Some(Operation::Solve),
+            "eig" => Some(Operation::Eig),
+            "svd" => Some(Operation::Svd),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Operation::Det => "det",
+            Operation::Inv => "inv",
+            Operation::Rank => "rank",
+            Operation::Rref => "rref",
+            Operation::Transpose => "transpose",
+            Operation::Mul => "mul",
+            Operation::Solve => "solve",
+            Operation::Eig => "eig",
+            Operation::Svd => "svd",
+        }
+    }
+
+    /// Minimum and maximum number of matrices the operation takes.
+    fn arity(self) -> (usize, usize) {
+        match self {
+            Operation::Mul => (2, usize::MAX),
+            Operation::Solve => (2, 2),
+            _ => (1, 1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Real,
+    Complex,
+    Rational,
+}
+
+impl Mode {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "real" => Some(Mode::Real),
+            "complex" => Some(Mode::Complex),
+            "rational" => Some(Mode::Rational),
+            _ => None,
+        }
+    }
+}
+
+struct CalculatorArgs {
+    mode: Mode,
+    operation: Operation,
+    inputs: Vec<String>,
+}
+
+/// Scalar types the calculator can run in.
+trait Scalar:
+    Copy
+    + PartialEq
+    + Display
+    + Zero
+    + One
+    + Negative
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + Magnitude<Output = f32>
+{
+    /// Name of the mode, used in error messages.
+    const MODE: &'static str;
+
+    fn parse(text: &str) -> Option<Self>;
+
+    fn eigenvalues(_matrix: &Matrix<Self>) -> Result<String, String> {
+        Err(format!(
+            "eig is only available in real mode, not {}",
+            Self::MODE
+        ))
+    }
+
+    fn singular_values(_matrix: &Matrix<Self>) -> Result<String, String> {
+        Err(format!(
+            "svd is only available in real mode, not {}",
+            Self::MODE
+        ))
+    }
+
+    /// Whether arithmetic overflowed since the last call, which makes the
+    /// result of the computation meaningless.
+    fn take_overflow() -> bool {
+        false
+    }
+}
+
+impl Scalar for f64 {
+    const MODE: &'static str = "real";
+
+    fn parse(text: &str) -> Option<Self> {
+        text.parse().ok().filter(|value: &f64| value.is_finite())
+    }
+
+    fn eigenvalues(matrix: &Matrix<Self>) -> Result<String, String> {
+        let values = matrix.eigenvalues().map_err(|e| e.to_string())?;
+        Ok(Vector::from(values).to_string())
+    }
+
+    fn singular_values(matrix: &Matrix<Self>) -> Result<String, String> {
+        Ok(Vector::from(matrix.singular_values()).to_string())
+    }
+}
+
+impl Scalar for Complex {
+    const MODE: &'static str = "complex";
+
+    fn parse(text: &str) -> Option<Self> {
+        text.parse()
+            .ok()
+            .filter(|z: &Complex| z.real().is_finite() && z.imaginary().is_finite())
+    }
+}
+
+thread_local! {
+    /// Set when an `Exact` operation overflows.
+    static OVERFLOWED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Entry of rational mode: a `Rational<i64>` whose operators use checked
+/// arithmetic. An operation that overflows gives zero and sets `OVERFLOWED`,
+/// so that `evaluate` reports an error instead of a wrong result.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Exact(Rational<i64>);
+
+impl Exact {
+    fn checked(result: Option<Rational<i64>>) -> Self {
+        Exact(result.unwrap_or_else(|| {
+            OVERFLOWED.with(|overflowed| overflowed.set(true));
+            Rational::zero()
+        }))
+    }
+}
+
+impl Display for Exact {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Zero for Exact {
+    fn zero() -> Self {
+        Exact(Rational::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl One for Exact {
+    fn one() -> Self {
+        Exact(Rational::one())
+    }
+}
+
+impl Negative for Exact {
+    fn negative_one() -> Self {
+        Exact(Rational::negative_one())
+    }
+}
+
+impl Magnitude for Exact {
+    type Output = f32;
+
+    fn magnitude(&self) -> f32 {
+        self.0.magnitude()
+    }
+}
+
+impl Add for Exact {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Exact::checked(self.0.checked_add(other.0))
+    }
+}
+
+impl Sub for Exact {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Exact::checked(self.0.checked_sub(other.0))
+    }
+}
+
+impl Mul for Exact {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Exact::checked(self.0.checked_mul(other.0))
+    }
+}
+
+impl Div for Exact {
+    type Output = Self;
+
+    /// Division by zero, which only follows an overflow, also counts as one.
+    fn div(self, other: Self) -> Self {
+        Exact::checked(self.0.checked_div(other.0))
+    }
+}
+
+impl Neg for Exact {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Exact::checked(self.0.checked_neg())
+    }
+}
+
+impl Scalar for Exact {
+    const MODE: &'static str = "rational";
+
+    /// Reads integers, fractions `p/q` and decimals such as `-1.25`.
+    fn parse(text: &str) -> Option<Self> {
+        if let Some((numerator, denominator)) = text.split_once('/') {
+            let denominator: i64 = denominator.parse().ok()?;
+            let numerator: i64 = numerator.parse().ok()?;
+            return Rational::from_integer(numerator)
+                .checked_div(Rational::from_integer(denominator))
+                .map(Exact);
+        }
+
+        let (sign, digits) = match text.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, text.strip_prefix('+').unwrap_or(text)),
+        };
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        if whole.is_empty() && fraction.is_empty() {
+            return None;
+        }
+        if !(whole.bytes().all(|b| b.is_ascii_digit())
+            && fraction.bytes().all(|b| b.is_ascii_digit()))
+        {
+            return None;
+        }
+
+        let scale = 10_i64.checked_pow(fraction.len() as u32)?;
+        let whole: i64 = if whole.is_empty() {
+            0
+        } else {
+            whole.parse().ok()?
+        };
+        let fraction: i64 = if fraction.is_empty() {
+            0
+        } else {
+            fraction.parse().ok()?
+        };
+        let numerator = whole.checked_mul(scale)?.checked_add(fraction)?;
+        Some(Exact(Rational::new(sign * numerator, scale)))
+    }
+
+    fn take_overflow() -> bool {
+        OVERFLOWED.with(|overflowed| overflowed.replace(false))
+    }
+}
+
+fn print_help() {
+    println!("Linear Algebra Calculator\n");
+    println!("USAGE:");
+    println!("    linalg [OPTIONS] <OPERATION> [FILE...]\n");
+    println!("OPERATIONS:");
+    println!("    det                     Determinant of a square matrix");
+    println!("    inv                     Inverse of a square matrix");
+    println!("    rank                    Rank of a matrix");
+    println!("    rref                    Reduced row echelon form");
+    println!("    transpose               Transpose");
+    println!("    mul                     Product of two or more matrices, left to right");
+    println!("    solve                   Solution x of A·x = b, with b a single row or column");
+    println!("    eig                     Eigenvalues (real mode)");
+    println!("    svd                     Singular values (real mode)\n");
+    println!("OPTIONS:");
+    println!("    -h, --help              Show this help message");
+    println!("    --mode <MODE>           real, complex or rational (default: real)\n");
+    println!("INPUT:");
+    println!("    Matrices are read from the files in order, or from stdin when no file");
+    println!("    is given or a file is -. Each line is a row, with entries separated by");
+    println!("    commas or spaces; brackets are ignored, so printed results can be read");
+    println!("    back. A blank line separates two matrices in the same input.");
+    println!("    Complex entries are written 1+2i, rational entries 3/4 or 0.75.");
+    println!("    Rational mode is exact and stops with an error when a numerator or");
+    println!("    denominator does not fit in 64 bits.\n");
+    println!("EXAMPLES:");
+    println!("    linalg det matrix.txt");
+    println!("    linalg --mode rational inv matrix.txt");
+    println!("    linalg solve a.txt b.txt");
+    println!("    printf '1 2\\n3 4\\n' | linalg eig");
+}
+
+fn parse_args() -> CalculatorArgs {
+    let args: Vec<String> = env::args().collect();
+    let mut mode = Mode::Real;
+    let mut operation = None;
+    let mut inputs = Vec::new();
+
+    let mut i = 1;
+    while i < args.len() {
+        match args[i].as_str() {
+            "-h" | "--help" => {
+                print_help();
+                process::exit(0);
+            }
+            "--mode" => {
+                let Some(value) = args.get(i + 1) else {
+                    eprintln!("Error: --mode requires a value");
+                    process::exit(1);
+                };
+                match Mode::parse(value) {
+                    Some(value) => mode = value,
+                    None => {
+                        eprintln!("Error: unknown mode: {value}");
+                        process::exit(1);
+                    }
+                }
+                i += 1;
+            }
+            arg if arg.starts_with("--") => {
+                eprintln!("Error: unknown argument: {arg}");
+                eprintln!("Use --help to see available options");
+                process::exit(1);
+            }
+            arg if operation.is_none() => match Operation::parse(arg) {
+                Some(value) => operation = Some(value),
+                None => {
+                    eprintln!("Error: unknown operation: {arg}");
+                    eprintln!("Use --help to see available operations");
+                    process::exit(1);
+                }
+            },
+            arg => inputs.push(arg.to_string()),
+        }
+        i += 1;
+    }
+
+    let Some(operation) = operation else {
+        eprintln!("Error: missing operation");
+        eprintln!("Use --help to see available operations");
+        process::exit(1);
+    };
+    if inputs.is_empty() {
+        inputs.push("-".to_string());
+    }
+
+    CalculatorArgs {
+        mode,
+        operation,
+        inputs,
+    }
+}
+
+/// Splits a line into entries: at commas when there are any, so that
+/// complex entries such as `1 + 2i` can contain spaces, and at whitespace
+/// otherwise.
+fn split_entries(line: &str) -> Vec<String> {
+    if line.contains(',') {
+        line.split(',')
+            .map(|entry| entry.split_whitespace().collect())
+            .collect()
+    } else {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+}
+
+/// Reads the blank-line separated matrices of one input.
+fn parse_matrices<K: Scalar>(source: &str, text: &str) -> Result<Vec<Matrix<K>>, String> {
+    let mut matrices = Vec::new();
+    let mut rows: Vec<Vec<K>> = Vec::new();
+
+    let mut finish = |rows: &mut Vec<Vec<K>>| {
+        if !rows.is_empty() {
+            let rows = std::mem::take(rows);
+            let columns = rows[0].len();
+            matrices.push(Matrix::from_fn(rows.len(), columns, |i, j| rows[i][j]));
+        }
+    };
+
+    for (index, line) in text.lines().enumerate() {
+        let line = line.replace(['[', ']'], "");
+        if line.trim().is_empty() {
+            finish(&mut rows);
+            continue;
+        }
+
+        let row = split_entries(&line)
+            .iter()
+            .map(|entry| {
+                K::parse(entry).ok_or_else(|| {
+                    format!(
+                        "{source}:{}: invalid {} number '{entry}'",
+                        index + 1,
+                        K::MODE
+                    )
+                })
+            })
+            .collect::<Result<Vec<K>, String>>()?;
+
+        if let Some(first) = rows.first() {
+            if first.len() != row.len() {
+                return Err(format!(
+                    "{source}:{}: row has {} entries, expected {}",
+                    index + 1,
+                    row.len(),
+                    first.len()
+                ));
+            }
+        }
+        rows.push(row);
+    }
+    finish(&mut rows);
+
+    Ok(matrices)
+}
+
+fn read_input(source: &str) -> Result<String, String> {
+    if source == "-" {
+        let mut text = String::new();
+        io::stdin()
+            .read_to_string(&mut text)
+            .map_err(|e| format!("cannot read stdin: {e}"))?;
+        Ok(text)
+    } else {
+        fs::read_to_string(source).map_err(|e| format!("cannot read '{source}': {e}"))
+    }
+}
+
+fn shape<K>(matrix: &Matrix<K>) -> String {
+    format!("{}x{}", matrix.rows(), matrix.columns())
+}
+
+fn require_square<K>(operation: Operation, matrix: &Matrix<K>) -> Result<(), String> {
+    if matrix.is_square() {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} needs a square matrix, got {}",
+            operation.name(),
+            shape(matrix)
+        ))
+    }
+}
+
+/// Reads a single row or single column matrix as a vector.
+fn as_vector<K: Copy>(matrix: &Matrix<K>) -> Option<Vector<K>> {
+    match matrix.shape() {
+        (1, _) => Some(Vector::from(&matrix[0])),
+        (_, 1) => Some(Vector::from(
+            matrix.iter().map(|row| row[0]).collect::<Vec<K>>(),
+        )),
+        _ => None,
+    }
+}
+
+/// Runs `operation` on `matrices` and renders the result, or reports that
+/// the arithmetic overflowed.
+fn evaluate<K: Scalar>(operation: Operation, matrices: &[Matrix<K>]) -> Result<String, String> {
+    K::take_overflow();
+    let result = compute(operation, matrices);
+    if K::take_overflow() {
+        return Err(format!("{} arithmetic overflowed 64-bit integers", K::MODE));
+    }
+    result
+}
+
+fn compute<K: Scalar>(operation: Operation, matrices: &[Matrix<K>]) -> Result<String, String> {
+    let (min, max) = operation.arity();
+    if matrices.len() < min || matrices.len() > max {
+        let expected = match (min, max) {
+            (min, max) if min == max => format!("{min}"),
+            (min, _) => format!("at least {min}"),
+        };
+        return Err(format!(
+            "{} takes {expected} matrices, got {}",
+            operation.name(),
+            matrices.len()
+        ));
+    }
+    let a = &matrices[0];
+
+    match operation {
+        Operation::Det => {
+            require_square(operation, a)?;
+            Ok(a.determinant().to_string())
+        }
+        Operation::Inv => {
+            require_square(operation, a)?;
+            a.inverse()
+                .map(|inverse| inverse.to_string())
+                .map_err(|e| e.to_string())
+        }
+        Operation::Rank => Ok(a.clone().rank().to_string()),
+        Operation::Rref => Ok(a.row_echelon().to_string()),
+        Operation::Transpose => Ok(a.transpose().to_string()),
+        Operation::Mul => {
+            let mut product = a.clone();
+            for b in &matrices[1..] {
+                if product.columns() != b.rows() {
+                    return Err(format!(
+                        "cannot multiply {} by {}: inner dimensions differ",
+                        shape(&product),
+                        shape(b)
+                    ));
+                }
+                product = product.mul_mat(b);
+            }
+            Ok(product.to_string())
+        }
+        Operation::Solve => {
+            require_square(operation, a)?;
+            let b = &matrices[1];
+            let b = as_vector(b).ok_or_else(|| {
+                format!(
+                    "the right-hand side must be a single row or column, got {}",
+                    shape(b)
+                )
+            })?;
+            if b.len() != a.rows() {
+                return Err(format!(
+                    "the right-hand side has {} entries, expected {}",
+                    b.len(),
+                    a.rows()
+                ));
+            }
+            a.solve(&b)
+                .map(|x| x.to_string())
+                .map_err(|e| e.to_string())
+        }
+        Operation::Eig => {
+            require_square(operation, a)?;
+            K::eigenvalues(a)
+        }
+        Operation::Svd => K::singular_values(a),
+    }
+}
+
+fn run<K: Scalar>(args: &CalculatorArgs) -> Result<String, String> {
+    let mut matrices = Vec::new();
+    for source in &args.inputs {
+        let name = if source == "-" { "stdin" } else { source };
+        matrices.extend(parse_matrices::<K>(name, &read_input(source)?)?);
+    }
+    evaluate(args.operation, &matrices)
+}
+
+fn main() {
+    let args = parse_args();
+
+    let result = match args.mode {
+        Mode::Real => run::<f64>(&args),
+        Mode::Complex => run::<Complex>(&args),
+        Mode::Rational => run::<Exact>(&args),
+    };
+
+    match result {
+        Ok(output) => println!("{output}"),
+        Err(e) => {
+            eprintln!("Error: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+fn matrices<K: Scalar>(text: &str) -> Vec<Matrix<K>> {
+    parse_matrices("test", text).unwrap()
+}
+
+#[test]
+fn test_parse_scalars() {
+    assert_eq!(<f64 as Scalar>::parse("-2.5e1"), Some(-25.0));
+    assert_eq!(<f64 as Scalar>::parse("inf"), None);
+
+    assert_eq!(Complex::parse("3"), Some(Complex::new(3.0, 0.0)));
+    assert_eq!(Complex::parse("-i"), Some(Complex::new(0.0, -1.0)));
+    assert_eq!(Complex::parse("2.5i"), Some(Complex::new(0.0, 2.5)));
+    assert_eq!(Complex::parse("1-2i"), Some(Complex::new(1.0, -2.0)));
+    assert_eq!(Complex::parse("1e2+i"), Some(Complex::new(100.0, 1.0)));
+    assert_eq!(Complex::parse("1+e2i"), None);
+
+    assert_eq!(Exact::parse("-3/4"), Some(Exact(Rational::new(-3, 4))));
+    assert_eq!(Exact::parse("-1.25"), Some(Exact(Rational::new(-5, 4))));
+    assert_eq!(Exact::parse(".5"), Some(Exact(Rational::new(1, 2))));
+    assert_eq!(Exact::parse("1/0"), None);
+    assert_eq!(Exact::parse("1.2.3"), None);
+    assert_eq!(Exact::parse("-9223372036854775808/-1"), None);
+}
+
+#[test]
+fn test_parse_matrices() {
+    let parsed = matrices::<f64>("[1, 2]\n[3, 4]\n\n5 6\n");
+    assert_eq!(
+        parsed,
+        vec![
+            Matrix::from([[1.0, 2.0], [3.0, 4.0]]),
+            Matrix::from([[5.0, 6.0]])
+        ]
+    );
+
+    // The crate's own display format reads back, including complex entries
+    let complex = Matrix::from([[Complex::new(1.0, 2.0), Complex::new(0.0, -1.0)]]);
+    assert_eq!(matrices::<Complex>(&complex.to_string()), vec![complex]);
+
+    assert_eq!(
+        parse_matrices::<f64>("a.txt", "1 2\n3\n").unwrap_err(),
+        "a.txt:2: row has 1 entries, expected 2"
+    );
+    assert_eq!(
+        parse_matrices::<Exact>("a.txt", "1 x\n").unwrap_err(),
+        "a.txt:1: invalid rational number 'x'"
+    );
+}
+
+#[test]
+fn test_operations() {
+    let a = matrices::<Exact>("2 1\n1 3\n\n5\n10\n");
+
+    assert_eq!(evaluate(Operation::Det, &a[..1]).unwrap(), "5");
+    assert_eq!(
+        evaluate(Operation::Inv, &a[..1]).unwrap(),
+        Matrix::from([
+            [Rational::new(3, 5), Rational::new(-1, 5)],
+            [Rational::new(-1, 5), Rational::new(2, 5)]
+        ])
+        .to_string()
+    );
+    assert_eq!(evaluate(Operation::Solve, &a).unwrap(), "[1, 3]");
+    assert_eq!(evaluate(Operation::Rank, &a[..1]).unwrap(), "2");
+
+    let real = matrices::<f64>("2 0\n0 3\n");
+    assert_eq!(evaluate(Operation::Eig, &real).unwrap(), "[3, 2]");
+    assert_eq!(evaluate(Operation::Svd, &real).unwrap(), "[3, 2]");
+}
+
+#[test]
+fn test_operation_errors() {
+    let a = matrices::<f64>("1 2 3\n4 5 6\n\n1 2\n3 4\n");
+
+    assert_eq!(
+        evaluate(Operation::Mul, &a).unwrap_err(),
+        "cannot multiply 2x3 by 2x2: inner dimensions differ"
+    );
+    assert_eq!(
+        evaluate(Operation::Det, &a[..1]).unwrap_err(),
+        "det needs a square matrix, got 2x3"
+    );
+    assert_eq!(
+        evaluate(Operation::Solve, &[a[1].clone(), a[0].clone()]).unwrap_err(),
+        "the right-hand side must be a single row or column, got 2x3"
+    );
+    assert_eq!(
+        evaluate(Operation::Solve, &a[1..]).unwrap_err(),
+        "solve takes 2 matrices, got 1"
+    );
+    assert_eq!(
+        evaluate(Operation::Inv, &matrices::<f64>("1 2\n2 4\n")).unwrap_err(),
+        linear_algebra_42::MatrixInverseError::Singular.to_string()
+    );
+
+    let complex = matrices::<Complex>("1 i\n-i 1\n");
+    assert_eq!(
+        evaluate(Operation::Eig, &complex).unwrap_err(),
+        "eig is only available in real mode, not complex"
+    );
+}
+
+#[test]
+fn test_rational_overflow() {
+    let overflowed = "rational arithmetic overflowed 64-bit integers";
+
+    // The 12x12 Hilbert matrix, whose determinant is about 2.6e-78
+    let hilbert: String = (1..=12)
+        .map(|i| {
+            let row: Vec<String> = (i..i + 12).map(|j| format!("1/{j}")).collect();
+            row.join(" ") + "\n"
+        })
+        .collect();
+    let hilbert = matrices::<Exact>(&hilbert);
+    assert_eq!(evaluate(Operation::Det, &hilbert).unwrap_err(), overflowed);
+
+    let large = matrices::<Exact>("9223372036854775807\n\n2\n");
+    assert_eq!(evaluate(Operation::Mul, &large).unwrap_err(), overflowed);
+
+    // The flag does not leak into the next computation
+    assert_eq!(evaluate(Operation::Det, &large[1..]).unwrap(), "2");
+}
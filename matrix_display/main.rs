@@ -1,47 +1,306 @@
-use linear_algebra_42::Matrix;
+use linear_algebra_42::transform::{self, ClipSpace, DepthRange, Handedness};
+use linear_algebra_42::{Matrix, TransformError, Vector};
 use std::env;
-use std::fs::File;
-use std::io::Write;
+use std::fs::{self, File};
+use std::io::{self, Write};
 use std::process;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Perspective,
+    Orthographic,
+    Infinite,
+    ReversedZ,
+    LookAt,
+}
+
+impl Kind {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "perspective" => Some(Kind::Perspective),
+            "orthographic" => Some(Kind::Orthographic),
+            "infinite" => Some(Kind::Infinite),
+            "reversed-z" => Some(Kind::ReversedZ),
+            "look-at" => Some(Kind::LookAt),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Kind::Perspective => "perspective",
+            Kind::Orthographic => "orthographic",
+            Kind::Infinite => "infinite",
+            Kind::ReversedZ => "reversed-z",
+            Kind::LookAt => "look-at",
+        }
+    }
+}
+
+/// Graphics API whose clip space and matrix layout the output targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Convention {
+    OpenGl,
+    Vulkan,
+    DirectX,
+    Metal,
+}
+
+/// How an API reads the written matrix. The numbers are the same for all of
+/// them: the library's row-vector matrix, one row per line, is both the
+/// column-major storage of the column-vector matrix and the row-major
+/// storage of the row-vector matrix that D3DX builds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Layout {
+    ColumnMajor,
+    RowMajor,
+}
+
+impl Convention {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "opengl" => Some(Convention::OpenGl),
+            "vulkan" => Some(Convention::Vulkan),
+            "directx" => Some(Convention::DirectX),
+            "metal" => Some(Convention::Metal),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Convention::OpenGl => "opengl",
+            Convention::Vulkan => "vulkan",
+            Convention::DirectX => "directx",
+            Convention::Metal => "metal",
+        }
+    }
+
+    fn clip_space(self) -> ClipSpace {
+        match self {
+            Convention::OpenGl => {
+                ClipSpace::new(Handedness::RightHanded, DepthRange::NegativeOneToOne)
+            }
+            Convention::Vulkan | Convention::Metal => {
+                ClipSpace::new(Handedness::RightHanded, DepthRange::ZeroToOne)
+            }
+            Convention::DirectX => ClipSpace::new(Handedness::LeftHanded, DepthRange::ZeroToOne),
+        }
+    }
+
+    fn layout(self) -> Layout {
+        match self {
+            Convention::DirectX => Layout::RowMajor,
+            _ => Layout::ColumnMajor,
+        }
+    }
+}
+
+/// Text format of the written matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Csv,
+    Json,
+    Glsl,
+    Hlsl,
+    Rust,
+    CArray,
+    Latex,
+}
+
+impl Format {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "csv" => Some(Format::Csv),
+            "json" => Some(Format::Json),
+            "glsl" => Some(Format::Glsl),
+            "hlsl" => Some(Format::Hlsl),
+            "rust" => Some(Format::Rust),
+            "c-array" => Some(Format::CArray),
+            "latex" => Some(Format::Latex),
+            _ => None,
+        }
+    }
+}
+
 struct ProjectionArgs {
+    kind: Kind,
+    convention: Convention,
     fov_degrees: f32,
     ratio: f32,
     near: f32,
     far: f32,
+    left: Option<f32>,
+    right: Option<f32>,
+    bottom: Option<f32>,
+    top: Option<f32>,
+    eye: [f32; 3],
+    target: [f32; 3],
+    up: [f32; 3],
     output_file: String,
+    format: Format,
+    precision: Option<usize>,
+    check: bool,
+    points: Vec<[f32; 3]>,
 }
 
 impl Default for ProjectionArgs {
     fn default() -> Self {
         Self {
+            kind: Kind::Perspective,
+            convention: Convention::Vulkan,
             fov_degrees: 45.,
             ratio: 16. / 9.,
             near: 0.1,
             far: 100.0,
+            left: None,
+            right: None,
+            bottom: None,
+            top: None,
+            eye: [0.0, 0.0, 5.0],
+            target: [0.0, 0.0, 0.0],
+            up: [0.0, 1.0, 0.0],
             output_file: "proj".to_string(),
+            format: Format::Csv,
+            precision: None,
+            check: false,
+            points: Vec::new(),
+        }
+    }
+}
+
+impl ProjectionArgs {
+    /// Whether the matrix goes to stdout instead of a file.
+    fn writes_stdout(&self) -> bool {
+        self.output_file == "-"
+    }
+
+    /// Identifier used by the source code formats.
+    fn variable_name(&self) -> &'static str {
+        match self.kind {
+            Kind::LookAt => "view",
+            _ => "projection",
         }
     }
+
+    /// Orthographic box, defaulting to a height of 2 and the aspect ratio.
+    fn bounds(&self) -> (f32, f32, f32, f32) {
+        (
+            self.left.unwrap_or(-self.ratio),
+            self.right.unwrap_or(self.ratio),
+            self.bottom.unwrap_or(-1.0),
+            self.top.unwrap_or(1.0),
+        )
+    }
 }
 
 fn print_help() {
     println!("Projection Matrix Generator\n");
     println!("USAGE:");
-    println!("    projection [OPTIONS]\n");
+    println!("    projection [KIND] [OPTIONS]\n");
+    println!("KINDS:");
+    println!("    perspective             Perspective projection (default)");
+    println!("    orthographic            Orthographic projection");
+    println!("    infinite                Perspective projection with the far plane at infinity");
+    println!("    reversed-z              Perspective projection mapping near to 1 and far to 0");
+    println!("    look-at                 View matrix of a camera looking at a target\n");
     println!("OPTIONS:");
     println!("    -h, --help              Show this help message");
+    println!("    --convention <API>      opengl, vulkan, directx or metal (default: vulkan)");
     println!("    --fov <DEGREES>         Vertical field of view in degrees (default: 45.0)");
     println!("    --ratio <RATIO>         Aspect ratio width/height (default: 16:9)");
     println!("    --near <DISTANCE>       Near plane distance (default: 0.1)");
     println!("    --far <DISTANCE>        Far plane distance (default: 100.0)");
-    println!("    --output <FILE>         Output file name (default: proj)\n");
+    println!("    --left <X>              Orthographic left edge (default: -ratio)");
+    println!("    --right <X>             Orthographic right edge (default: ratio)");
+    println!("    --bottom <Y>            Orthographic bottom edge (default: -1.0)");
+    println!("    --top <Y>               Orthographic top edge (default: 1.0)");
+    println!("    --eye <X,Y,Z>           Camera position for look-at (default: 0,0,5)");
+    println!("    --target <X,Y,Z>        Point looked at (default: 0,0,0)");
+    println!("    --up <X,Y,Z>            Up direction (default: 0,1,0)");
+    println!("    --output <FILE>         Output file name, or - for stdout (default: proj)");
+    println!(
+        "    --format <FORMAT>       csv, json, glsl, hlsl, rust, c-array or latex (default: csv)"
+    );
+    println!("    --precision <DIGITS>    Fixed number of decimals (default: shortest exact)");
+    println!("    --check                 Report where points land instead of writing the matrix");
+    println!("    --point <X,Y,Z>         View space point to check, may be repeated");
+    println!("    --points <FILE>         File of points to check, one X,Y,Z per line\n");
+    println!("CONVENTIONS:");
+    println!("    opengl                  Right-handed, depth [-1, 1], column-major");
+    println!("    vulkan                  Right-handed, depth [0, 1], column-major");
+    println!("    directx                 Left-handed, depth [0, 1], row-major");
+    println!("    metal                   Right-handed, depth [0, 1], column-major\n");
     println!("EXAMPLES:");
     println!("    projection");
     println!("    projection --fov 45 --ratio 1.7778 --near 0.1 --far 1000");
-    println!("    projection --fov 60 --ratio 1.333 --output matrix.txt\n");
+    println!("    projection --fov 60 --ratio 1.333 --output matrix.txt");
+    println!("    projection reversed-z --convention directx --far 10000");
+    println!("    projection orthographic --left 0 --right 800 --bottom 0 --top 600");
+    println!("    projection look-at --eye 3,2,5 --target 0,0,0 --output view");
+    println!("    projection --convention opengl --format glsl --precision 6 --output -");
+    println!("    projection --check --point 0,0,-0.1 --point 1,1,-50 --points points.txt\n");
     println!("OUTPUT:");
-    println!("    The program generates a file containing the projection matrix");
-    println!("    in column-major format, compatible with OpenGL.");
+    println!("    The program generates a file containing the matrix, one line per");
+    println!("    column of the column-vector form, which is also one line per row of");
+    println!("    the row-vector form DirectX uses. Every convention gets the same");
+    println!("    order, so GLSL's mat4 and HLSL's float4x4 constructors take it as is.");
+}
+
+/// Returns the value following the flag at `i`, exiting if it is missing.
+fn flag_value(args: &[String], i: usize) -> &str {
+    match args.get(i + 1) {
+        Some(value) => value,
+        None => {
+            eprintln!("Error: {} requires a value", args[i]);
+            process::exit(1);
+        }
+    }
+}
+
+fn parse_number(flag: &str, value: &str) -> f32 {
+    match value.parse::<f32>() {
+        Ok(value) if value.is_finite() => value,
+        _ => {
+            eprintln!("Error: invalid value for {flag}: {value}");
+            process::exit(1);
+        }
+    }
+}
+
+fn parse_point(flag: &str, value: &str) -> [f32; 3] {
+    let coordinates: Vec<f32> = value
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .map(|part| parse_number(flag, part))
+        .collect();
+
+    match coordinates[..] {
+        [x, y, z] => [x, y, z],
+        _ => {
+            eprintln!("Error: {flag} expects three comma-separated values: {value}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Reads one point per line from `path`, skipping blank lines and lines
+/// starting with `#`.
+fn read_points(path: &str) -> Vec<[f32; 3]> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("Error reading points from '{path}': {e}");
+            process::exit(1);
+        }
+    };
+
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| parse_point(path, line))
+        .collect()
 }
 
 fn parse_args() -> ProjectionArgs {
@@ -49,98 +308,114 @@ fn parse_args() -> ProjectionArgs {
     let mut result = ProjectionArgs::default();
 
     let mut i = 1;
+    if let Some(kind) = args.get(1).filter(|arg| !arg.starts_with('-')) {
+        match Kind::parse(kind) {
+            Some(kind) => result.kind = kind,
+            None => {
+                eprintln!("Error: unknown kind: {kind}");
+                eprintln!("Use --help to see available kinds");
+                process::exit(1);
+            }
+        }
+        i += 1;
+    }
+
     while i < args.len() {
-        match args[i].as_str() {
+        let flag = args[i].as_str();
+        match flag {
             "-h" | "--help" => {
                 print_help();
                 process::exit(0);
             }
-            "--fov" => {
-                if i + 1 >= args.len() {
-                    eprintln!("Error: --fov requires a value");
-                    process::exit(1);
-                }
-                match args[i + 1].parse::<f32>() {
-                    Ok(value) => {
-                        if value <= 0.0 || value >= 180.0 {
-                            eprintln!("Error: FOV must be between 0 and 180 degrees");
-                            process::exit(1);
-                        }
-                        result.fov_degrees = value;
-                    }
-                    Err(_) => {
-                        eprintln!("Error: invalid value for --fov: {}", args[i + 1]);
+            "--convention" => {
+                let value = flag_value(&args, i);
+                match Convention::parse(value) {
+                    Some(convention) => result.convention = convention,
+                    None => {
+                        eprintln!("Error: unknown convention: {value}");
                         process::exit(1);
                     }
                 }
                 i += 1;
             }
-            "--ratio" => {
-                if i + 1 >= args.len() {
-                    eprintln!("Error: --ratio requires a value");
+            "--fov" => {
+                let value = parse_number(flag, flag_value(&args, i));
+                if value <= 0.0 || value >= 180.0 {
+                    eprintln!("Error: FOV must be between 0 and 180 degrees");
                     process::exit(1);
                 }
-                match args[i + 1].parse::<f32>() {
-                    Ok(value) => {
-                        if value <= 0.0 {
-                            eprintln!("Error: aspect ratio must be positive");
-                            process::exit(1);
-                        }
-                        result.ratio = value;
-                    }
-                    Err(_) => {
-                        eprintln!("Error: invalid value for --ratio: {}", args[i + 1]);
-                        process::exit(1);
-                    }
+                result.fov_degrees = value;
+                i += 1;
+            }
+            "--ratio" => {
+                let value = parse_number(flag, flag_value(&args, i));
+                if value <= 0.0 {
+                    eprintln!("Error: aspect ratio must be positive");
+                    process::exit(1);
                 }
+                result.ratio = value;
                 i += 1;
             }
             "--near" => {
-                if i + 1 >= args.len() {
-                    eprintln!("Error: --near requires a value");
-                    process::exit(1);
+                result.near = parse_number(flag, flag_value(&args, i));
+                i += 1;
+            }
+            "--far" => {
+                result.far = parse_number(flag, flag_value(&args, i));
+                i += 1;
+            }
+            "--left" | "--right" | "--bottom" | "--top" => {
+                let value = Some(parse_number(flag, flag_value(&args, i)));
+                match flag {
+                    "--left" => result.left = value,
+                    "--right" => result.right = value,
+                    "--bottom" => result.bottom = value,
+                    _ => result.top = value,
                 }
-                match args[i + 1].parse::<f32>() {
-                    Ok(value) => {
-                        if value <= 0.0 {
-                            eprintln!("Error: near plane distance must be positive");
-                            process::exit(1);
-                        }
-                        result.near = value;
-                    }
-                    Err(_) => {
-                        eprintln!("Error: invalid value for --near: {}", args[i + 1]);
+                i += 1;
+            }
+            "--eye" | "--target" | "--up" => {
+                let value = parse_point(flag, flag_value(&args, i));
+                match flag {
+                    "--eye" => result.eye = value,
+                    "--target" => result.target = value,
+                    _ => result.up = value,
+                }
+                i += 1;
+            }
+            "--format" => {
+                let value = flag_value(&args, i);
+                match Format::parse(value) {
+                    Some(format) => result.format = format,
+                    None => {
+                        eprintln!("Error: unknown format: {value}");
                         process::exit(1);
                     }
                 }
                 i += 1;
             }
-            "--far" => {
-                if i + 1 >= args.len() {
-                    eprintln!("Error: --far requires a value");
-                    process::exit(1);
-                }
-                match args[i + 1].parse::<f32>() {
-                    Ok(value) => {
-                        if value <= 0.0 {
-                            eprintln!("Error: far plane distance must be positive");
-                            process::exit(1);
-                        }
-                        result.far = value;
-                    }
+            "--precision" => {
+                let value = flag_value(&args, i);
+                match value.parse::<usize>() {
+                    Ok(digits) => result.precision = Some(digits),
                     Err(_) => {
-                        eprintln!("Error: invalid value for --far: {}", args[i + 1]);
+                        eprintln!("Error: invalid value for --precision: {value}");
                         process::exit(1);
                     }
                 }
                 i += 1;
             }
+            "--check" => result.check = true,
+            "--point" => {
+                result.points.push(parse_point(flag, flag_value(&args, i)));
+                i += 1;
+            }
+            "--points" => {
+                result.points.extend(read_points(flag_value(&args, i)));
+                i += 1;
+            }
             "--output" => {
-                if i + 1 >= args.len() {
-                    eprintln!("Error: --output requires a value");
-                    process::exit(1);
-                }
-                result.output_file = args[i + 1].clone();
+                result.output_file = flag_value(&args, i).to_string();
                 i += 1;
             }
             arg => {
@@ -152,86 +427,356 @@ fn parse_args() -> ProjectionArgs {
         i += 1;
     }
 
-    if result.near >= result.far {
+    validate(&result);
+    result
+}
+
+/// Checks the clip planes the selected kind depends on.
+fn validate(args: &ProjectionArgs) {
+    let perspective = matches!(
+        args.kind,
+        Kind::Perspective | Kind::Infinite | Kind::ReversedZ
+    );
+    let bounded = matches!(
+        args.kind,
+        Kind::Perspective | Kind::ReversedZ | Kind::Orthographic
+    );
+
+    if perspective && args.near <= 0.0 {
+        eprintln!("Error: near plane distance must be positive");
+        process::exit(1);
+    }
+    if bounded && args.near >= args.far {
         eprintln!(
             "Error: near plane ({}) must be less than far plane ({})",
-            result.near, result.far
+            args.near, args.far
         );
         process::exit(1);
     }
+    if args.check && args.kind == Kind::LookAt {
+        eprintln!("Error: --check needs a projection, not a view matrix");
+        process::exit(1);
+    }
+    if args.check && args.points.is_empty() {
+        eprintln!("Error: --check needs points from --point or --points");
+        process::exit(1);
+    }
+}
 
-    result
+/// Builds the requested matrix in the library's row-vector layout.
+fn generate(args: &ProjectionArgs) -> Result<Matrix<f32>, TransformError> {
+    let clip = args.convention.clip_space();
+    let fov = args.fov_degrees.to_radians();
+    let (ratio, near, far) = (args.ratio, args.near, args.far);
+
+    match args.kind {
+        Kind::Perspective => transform::perspective(fov, ratio, near, far, clip),
+        Kind::Infinite => transform::perspective_infinite(fov, ratio, near, clip),
+        Kind::ReversedZ => transform::perspective_reversed_z(fov, ratio, near, far, clip),
+        Kind::Orthographic => {
+            let (left, right, bottom, top) = args.bounds();
+            transform::orthographic(left, right, bottom, top, near, far, clip)
+        }
+        Kind::LookAt => transform::look_at(
+            &Vector::from(args.eye),
+            &Vector::from(args.target),
+            &Vector::from(args.up),
+            clip.handedness,
+        ),
+    }
+}
+
+/// Where a view space point lands under a projection matrix.
+struct PointCheck {
+    point: [f32; 3],
+    clip: Vector<f32>,
+    /// Normalized device coordinates, unless the point maps to `w = 0`
+    ndc: Option<Vector<f32>>,
+    /// Clip volume bounds the point violates; empty when it is inside
+    outside: Vec<&'static str>,
+}
+
+/// Sends `point` through `matrix` and tests it against the clip volume.
+///
+/// The test runs in clip space, `-w ≤ x, y ≤ w` and `low·w ≤ z ≤ w` with
+/// `low` the bottom of `depth_range`, allowing for rounding on the planes.
+fn check_point(matrix: &Matrix<f32>, point: [f32; 3], depth_range: DepthRange) -> PointCheck {
+    let clip = matrix.mul_vec(&Vector::from([point[0], point[1], point[2], 1.0]));
+    let ndc = clip.perspective_divide().ok();
+    let (x, y, z, w) = (clip[0], clip[1], clip[2], clip[3]);
+
+    let mut outside = Vec::new();
+    if w <= 0.0 {
+        outside.push("behind the camera");
+    } else {
+        let slack = 1e-5 * w;
+        let low = match depth_range {
+            DepthRange::ZeroToOne => 0.0,
+            DepthRange::NegativeOneToOne => -w,
+        };
+        if x.abs() > w + slack {
+            outside.push("x");
+        }
+        if y.abs() > w + slack {
+            outside.push("y");
+        }
+        if z < low - slack || z > w + slack {
+            outside.push("depth");
+        }
+    }
+
+    PointCheck {
+        point,
+        clip,
+        ndc,
+        outside,
+    }
+}
+
+fn describe_check(check: &PointCheck, precision: Option<usize>) -> String {
+    let vector = |values: &mut dyn Iterator<Item = f32>| {
+        let values: Vec<String> = values
+            .map(|value| format_number(value, Some(precision.unwrap_or(4))))
+            .collect();
+        format!("[{}]", values.join(", "))
+    };
+
+    let ndc = match &check.ndc {
+        Some(ndc) => vector(&mut ndc.iter().copied()),
+        None => "undefined (w = 0)".to_string(),
+    };
+    let status = if check.outside.is_empty() {
+        "inside".to_string()
+    } else {
+        format!("outside ({})", check.outside.join(", "))
+    };
+
+    format!(
+        "{} -> clip {} -> NDC {ndc}: {status}",
+        vector(&mut check.point.iter().copied()),
+        vector(&mut check.clip.iter().copied()),
+    )
 }
 
-fn projection(fov: f32, ratio: f32, near: f32, far: f32) -> Matrix<f32> {
-    let tan_half_fov = (fov / 2.0).tan();
+fn run_check(args: &ProjectionArgs, matrix: &Matrix<f32>) {
+    let depth_range = args.convention.clip_space().depth_range;
+    let checks: Vec<PointCheck> = args
+        .points
+        .iter()
+        .map(|&point| check_point(matrix, point, depth_range))
+        .collect();
+
+    println!(
+        "Checking {} matrix ({}):\n{matrix}\n",
+        args.kind.name(),
+        args.convention.name()
+    );
+    for check in &checks {
+        println!("{}", describe_check(check, args.precision));
+    }
+
+    let inside = checks
+        .iter()
+        .filter(|check| check.outside.is_empty())
+        .count();
+    println!(
+        "\n{inside} of {} points inside the clip volume",
+        checks.len()
+    );
+}
 
-    let x_scale = 1.0 / (ratio * tan_half_fov);
-    let y_scale = 1.0 / tan_half_fov;
-    let z_scale = far / (near - far);
-    let z_translation = (near * far) / (near - far);
+fn format_number(value: f32, precision: Option<usize>) -> String {
+    match precision {
+        Some(digits) => format!("{value:.digits$}"),
+        None => value.to_string(),
+    }
+}
 
-    Matrix::from([
-        [x_scale, 0., 0., 0.],
-        [0., y_scale, 0., 0.],
-        [0., 0., z_scale, -1.],
-        [0., 0., z_translation, 0.],
-    ])
+/// Formats `value` with a decimal point, so that shader and source code
+/// compilers read it as a float.
+fn float_literal(value: f32, precision: Option<usize>) -> String {
+    let text = format_number(value, precision);
+    if text.contains(['.', 'e', 'E']) {
+        text
+    } else {
+        format!("{text}.0")
+    }
 }
 
-fn write_matrix_to_file(matrix: &Matrix<f32>, filename: &str) -> std::io::Result<()> {
-    let mut file = File::create(filename)?;
+/// Renders `matrix` row by row in `format`, declaring `name` for the
+/// source code formats.
+fn format_matrix(
+    matrix: &Matrix<f32>,
+    format: Format,
+    precision: Option<usize>,
+    name: &str,
+) -> String {
+    let rows = |literal: &dyn Fn(f32) -> String, separator: &str| -> Vec<String> {
+        matrix
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&value| literal(value))
+                    .collect::<Vec<String>>()
+                    .join(separator)
+            })
+            .collect()
+    };
+    let number = |value| format_number(value, precision);
+    let float = |value| float_literal(value, precision);
 
-    for row in matrix.iter() {
-        writeln!(
-            file,
-            "{}",
-            row.iter()
-                .map(|element| element.to_string())
+    match format {
+        Format::Csv => rows(&number, ", ")
+            .iter()
+            .map(|row| format!("{row}\n"))
+            .collect(),
+        Format::Json => format!(
+            "[\n{}\n]\n",
+            rows(&number, ", ")
+                .iter()
+                .map(|row| format!("  [{row}]"))
                 .collect::<Vec<String>>()
-                .join(", ")
-        )?;
+                .join(",\n")
+        ),
+        Format::Glsl => format!(
+            "mat4 {name} = mat4(\n    {}\n);\n",
+            rows(&float, ", ").join(",\n    ")
+        ),
+        Format::Hlsl => format!(
+            "float4x4 {name} = {{\n    {}\n}};\n",
+            rows(&float, ", ").join(",\n    ")
+        ),
+        Format::Rust => format!(
+            "const {}: [[f32; 4]; 4] = [\n    [{}],\n];\n",
+            name.to_uppercase(),
+            rows(&float, ", ").join("],\n    [")
+        ),
+        Format::CArray => format!(
+            "const float {name}[4][4] = {{\n    {{{}}},\n}};\n",
+            rows(&|value| format!("{}f", float(value)), ", ").join("},\n    {")
+        ),
+        Format::Latex => format!(
+            "\\begin{{pmatrix}}\n{}\n\\end{{pmatrix}}\n",
+            rows(&number, " & ").join(" \\\\\n")
+        ),
+    }
+}
+
+/// Writes `text` to `output`, or to stdout when `output` is `-`.
+fn write_output(text: &str, output: &str) -> io::Result<()> {
+    if output == "-" {
+        io::stdout().write_all(text.as_bytes())
+    } else {
+        File::create(output)?.write_all(text.as_bytes())
     }
+}
+
+fn describe_parameters(args: &ProjectionArgs) -> Vec<String> {
+    let mut lines = Vec::new();
+    let clip = args.convention.clip_space();
+    lines.push("\nParameters used:".to_string());
+    lines.push(format!("Kind: {}", args.kind.name()));
+    lines.push(format!(
+        "Convention: {} ({:?}, {:?}, {:?})",
+        args.convention.name(),
+        clip.handedness,
+        clip.depth_range,
+        args.convention.layout()
+    ));
 
-    Ok(())
+    match args.kind {
+        Kind::LookAt => {
+            lines.push(format!("Eye: {}", Vector::from(args.eye)));
+            lines.push(format!("Target: {}", Vector::from(args.target)));
+            lines.push(format!("Up: {}", Vector::from(args.up)));
+        }
+        Kind::Orthographic => {
+            let (left, right, bottom, top) = args.bounds();
+            lines.push(format!("Left/right: {left:.4} / {right:.4}"));
+            lines.push(format!("Bottom/top: {bottom:.4} / {top:.4}"));
+            lines.push(format!("Near plane: {:.4}", args.near));
+            lines.push(format!("Far plane: {:.4}", args.far));
+        }
+        _ => {
+            lines.push(format!(
+                "FOV: {:.4} radians ({:.2} degrees)",
+                args.fov_degrees.to_radians(),
+                args.fov_degrees
+            ));
+            lines.push(format!("Aspect ratio: {:.4}", args.ratio));
+            lines.push(format!("Near plane: {:.4}", args.near));
+            if args.kind == Kind::Infinite {
+                lines.push("Far plane: infinity".to_string());
+            } else {
+                lines.push(format!("Far plane: {:.4}", args.far));
+            }
+        }
+    }
+    lines.push(format!("Output file: {}", args.output_file));
+    lines
 }
+
 fn main() {
     let args = parse_args();
 
-    let fov = args.fov_degrees.to_radians();
-    let ratio = args.ratio;
-    let near = args.near;
-    let far = args.far;
+    let matrix = match generate(&args) {
+        Ok(matrix) => matrix,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            process::exit(1);
+        }
+    };
+
+    if args.check {
+        run_check(&args, &matrix);
+        return;
+    }
 
-    let proj_matrix = projection(fov, ratio, near, far);
+    let text = format_matrix(&matrix, args.format, args.precision, args.variable_name());
 
-    println!("Calculated projection matrix:\n{proj_matrix}");
+    if let Err(e) = write_output(&text, &args.output_file) {
+        eprintln!("Error saving file: {e}");
+        process::exit(1);
+    }
 
-    match write_matrix_to_file(&proj_matrix, &args.output_file) {
-        Ok(()) => println!(
-            "\nProjection matrix saved to file '{}' successfully!",
+    // Keep stdout clean for the matrix itself when it is written there
+    let mut report = vec![format!("Calculated {} matrix:\n{matrix}", args.kind.name())];
+    if !args.writes_stdout() {
+        report.push(format!(
+            "\nMatrix saved to file '{}' successfully!",
             args.output_file
-        ),
-        Err(e) => eprintln!("Error saving file: {e}"),
+        ));
     }
+    report.extend(describe_parameters(&args));
+
+    let report = report.join("\n");
+    if args.writes_stdout() {
+        eprintln!("{report}");
+    } else {
+        println!("{report}");
+    }
+}
 
-    println!("\nParameters used:");
-    println!("FOV: {fov:.4} radians ({:.2} degrees)", args.fov_degrees);
-    println!("Aspect ratio: {ratio:.4}");
-    println!("Near plane: {near:.4}");
-    println!("Far plane: {far:.4}");
-    println!("Output file: {}", args.output_file);
+#[cfg(test)]
+fn perspective_args(fov_degrees: f32) -> ProjectionArgs {
+    ProjectionArgs {
+        fov_degrees,
+        ratio: 1.0,
+        near: 0.1,
+        far: 100.0,
+        ..Default::default()
+    }
 }
 
 #[test]
 fn test_projection_matrix_fov_100() {
-    let m = projection((100.0f32).to_radians(), 1.0, 0.1, 100.0);
+    let m = generate(&perspective_args(100.0)).unwrap();
 
-    let expected = vec![
-        vec![0.839100, 0.0, 0.0, 0.0],
-        vec![0.0, 0.839100, 0.0, 0.0],
-        vec![0.0, 0.0, -1.001001, -1.0],
-        vec![0.0, 0.0, -0.100100, 0.0],
+    let expected = [
+        [0.839100, 0.0, 0.0, 0.0],
+        [0.0, 0.839100, 0.0, 0.0],
+        [0.0, 0.0, -1.001001, -1.0],
+        [0.0, 0.0, -0.100100, 0.0],
     ];
 
     for (row_e, row_r) in expected.iter().zip(m.iter()) {
@@ -246,13 +791,13 @@ fn test_projection_matrix_fov_100() {
 
 #[test]
 fn test_projection_matrix_fov_70() {
-    let m = projection((70.0f32).to_radians(), 1.0, 0.1, 100.0);
+    let m = generate(&perspective_args(70.0)).unwrap();
 
-    let expected = vec![
-        vec![1.428148, 0.0, 0.0, 0.0],
-        vec![0.0, 1.428148, 0.0, 0.0],
-        vec![0.0, 0.0, -1.001001, -1.0],
-        vec![0.0, 0.0, -0.100100, 0.0],
+    let expected = [
+        [1.428148, 0.0, 0.0, 0.0],
+        [0.0, 1.428148, 0.0, 0.0],
+        [0.0, 0.0, -1.001001, -1.0],
+        [0.0, 0.0, -0.100100, 0.0],
     ];
 
     for (row_e, row_r) in expected.iter().zip(m.iter()) {
@@ -267,13 +812,13 @@ fn test_projection_matrix_fov_70() {
 
 #[test]
 fn test_projection_matrix_fov_40() {
-    let m = projection((40.0f32).to_radians(), 1.0, 0.1, 100.0);
+    let m = generate(&perspective_args(40.0)).unwrap();
 
-    let expected = vec![
-        vec![2.747477, 0.0, 0.0, 0.0],
-        vec![0.0, 2.747477, 0.0, 0.0],
-        vec![0.0, 0.0, -1.001001, -1.0],
-        vec![0.0, 0.0, -0.100100, 0.0],
+    let expected = [
+        [2.747477, 0.0, 0.0, 0.0],
+        [0.0, 2.747477, 0.0, 0.0],
+        [0.0, 0.0, -1.001001, -1.0],
+        [0.0, 0.0, -0.100100, 0.0],
     ];
 
     for (row_e, row_r) in expected.iter().zip(m.iter()) {
@@ -285,3 +830,133 @@ fn test_projection_matrix_fov_40() {
         }
     }
 }
+
+#[test]
+fn test_conventions() {
+    let mut args = perspective_args(90.0);
+
+    args.convention = Convention::OpenGl;
+    let opengl = generate(&args).unwrap();
+    // -(f + n) / (f - n) and -2fn / (f - n)
+    assert!((opengl[2][2] + 1.002002).abs() < 1e-5);
+    assert!((opengl[3][2] + 0.2002).abs() < 1e-5);
+    assert_eq!(opengl[2][3], -1.0);
+
+    // Written as is, this is XMMatrixPerspectiveFovLH: f / (f - n) at _33,
+    // 1 at _34 and -n·f / (f - n) at _43
+    args.convention = Convention::DirectX;
+    let directx = generate(&args).unwrap();
+    assert!((directx[2][2] - 1.001001).abs() < 1e-5);
+    assert_eq!(directx[2][3], 1.0);
+    assert!((directx[3][2] + 0.1001001).abs() < 1e-6);
+    assert_eq!(directx[3][3], 0.0);
+}
+
+#[test]
+fn test_kinds() {
+    let mut args = perspective_args(90.0);
+
+    args.kind = Kind::ReversedZ;
+    let reversed = generate(&args).unwrap();
+    let near = reversed.mul_vec(&Vector::from([0.0, 0.0, -0.1, 1.0]));
+    assert!((near[2] / near[3] - 1.0).abs() < 1e-5);
+
+    args.kind = Kind::Infinite;
+    let infinite = generate(&args).unwrap();
+    assert_eq!(infinite[2][2], -1.0);
+    assert!((infinite[3][2] + 0.1).abs() < 1e-6);
+
+    args.kind = Kind::Orthographic;
+    args.ratio = 2.0;
+    let orthographic = generate(&args).unwrap();
+    assert_eq!(orthographic[0][0], 0.5);
+    assert_eq!(orthographic[1][1], 1.0);
+
+    args.kind = Kind::LookAt;
+    let view = generate(&args).unwrap();
+    let target = view.mul_vec(&Vector::from([0.0, 0.0, 0.0, 1.0]));
+    assert_eq!(target, Vector::from([0.0, 0.0, -5.0, 1.0]));
+
+    args.target = args.eye;
+    assert!(generate(&args).is_err());
+}
+
+#[test]
+fn test_formats() {
+    let m = Matrix::from([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 0.5, 0.0, 0.0],
+        [0.0, 0.0, -1.25, -1.0],
+        [2.0, 0.0, -0.1, 0.0],
+    ]);
+
+    assert_eq!(
+        format_matrix(&m, Format::Csv, None, "projection"),
+        "1, 0, 0, 0\n0, 0.5, 0, 0\n0, 0, -1.25, -1\n2, 0, -0.1, 0\n"
+    );
+    assert_eq!(
+        format_matrix(&m, Format::Glsl, Some(2), "projection"),
+        "mat4 projection = mat4(\n    \
+         1.00, 0.00, 0.00, 0.00,\n    \
+         0.00, 0.50, 0.00, 0.00,\n    \
+         0.00, 0.00, -1.25, -1.00,\n    \
+         2.00, 0.00, -0.10, 0.00\n);\n"
+    );
+    assert_eq!(
+        format_matrix(&m, Format::Rust, None, "view").lines().nth(1),
+        Some("    [1.0, 0.0, 0.0, 0.0],")
+    );
+    assert_eq!(
+        format_matrix(&m, Format::CArray, Some(0), "view")
+            .lines()
+            .nth(4),
+        Some("    {2.0f, 0.0f, -0.0f, 0.0f},")
+    );
+    assert_eq!(
+        format_matrix(&m, Format::Latex, None, "view")
+            .lines()
+            .nth(3),
+        Some("0 & 0 & -1.25 & -1 \\\\")
+    );
+    assert!(format_matrix(&m, Format::Json, None, "view").starts_with("[\n  [1, 0, 0, 0],\n"));
+    assert!(format_matrix(&m, Format::Hlsl, None, "view").starts_with("float4x4 view = {\n"));
+}
+
+#[test]
+fn test_check_points() {
+    let mut args = perspective_args(90.0);
+    args.convention = Convention::OpenGl;
+    let m = generate(&args).unwrap();
+    let depth_range = args.convention.clip_space().depth_range;
+
+    let near = check_point(&m, [0.0, 0.0, -0.1], depth_range);
+    assert!(near.outside.is_empty());
+    assert!((near.ndc.as_ref().unwrap()[2] + 1.0).abs() < 1e-5);
+
+    // The corner of the far plane, at 45° on both axes
+    assert!(check_point(&m, [100.0, 100.0, -100.0], depth_range)
+        .outside
+        .is_empty());
+    assert_eq!(
+        check_point(&m, [3.0, 0.0, -2.0], depth_range).outside,
+        ["x"]
+    );
+    assert_eq!(
+        check_point(&m, [0.0, 0.0, -200.0], depth_range).outside,
+        ["depth"]
+    );
+    assert_eq!(
+        check_point(&m, [0.0, 0.0, 1.0], depth_range).outside,
+        ["behind the camera"]
+    );
+
+    let camera = check_point(&m, [0.0, 0.0, 0.0], depth_range);
+    assert!(camera.ndc.is_none());
+    assert!(
+        describe_check(&camera, None).ends_with("undefined (w = 0): outside (behind the camera)")
+    );
+    assert_eq!(
+        describe_check(&near, Some(1)),
+        "[0.0, 0.0, -0.1] -> clip [0.0, 0.0, -0.1, 0.1] -> NDC [0.0, 0.0, -1.0]: inside"
+    );
+}
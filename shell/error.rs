@@ -0,0 +1,65 @@
+/// Byte range `start..end` of a line of input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// Smallest span covering both `self` and `other`.
+    pub fn to(self, other: Span) -> Span {
+        Span::new(self.start.min(other.start), self.end.max(other.end))
+    }
+}
+
+/// An error in a line of input, pointing at the part that caused it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShellError {
+    pub message: String,
+    pub span: Span,
+}
+
+impl ShellError {
+    pub fn new(message: impl Into<String>, span: Span) -> Self {
+        Self {
+            message: message.into(),
+            span,
+        }
+    }
+
+    /// Renders the error under the offending line, underlining the span:
+    ///
+    /// ```text
+    ///   x = A \ c
+    ///           ^ unknown variable 'c'
+    /// ```
+    pub fn render(&self, source: &str) -> String {
+        let column = |byte: usize| source[..byte.min(source.len())].chars().count();
+        let start = column(self.span.start);
+        let width = column(self.span.end).saturating_sub(start).max(1);
+
+        format!(
+            "  {source}\n  {}{} {}",
+            " ".repeat(start),
+            "^".repeat(width),
+            self.message
+        )
+    }
+}
+
+#[test]
+fn test_render() {
+    let error = ShellError::new("unknown variable 'abc'", Span::new(8, 11));
+    assert_eq!(
+        error.render("x = A + abc"),
+        "  x = A + abc\n          ^^^ unknown variable 'abc'"
+    );
+
+    // An error at the end of the line still gets a caret
+    let error = ShellError::new("expected ']'", Span::new(4, 4));
+    assert_eq!(error.render("[1, "), "  [1, \n      ^ expected ']'");
+}
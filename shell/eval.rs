@@ -0,0 +1,395 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+
+use linear_algebra_42::{Matrix, Vector};
+
+use crate::error::{ShellError, Span};
+use crate::parser::{BinaryOp, Expr, ExprKind, Statement};
+
+/// Name of the variable holding the last unassigned result.
+pub const ANSWER: &str = "ans";
+
+/// Most entries `eye` and `zeros` may allocate, 8 MiB of `f64`.
+const MAX_ENTRIES: usize = 1 << 20;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Scalar(f64),
+    Vector(Vector<f64>),
+    Matrix(Matrix<f64>),
+}
+
+impl Value {
+    /// Short description of the value's kind and shape for error messages.
+    fn describe(&self) -> String {
+        match self {
+            Value::Scalar(_) => "a scalar".to_string(),
+            Value::Vector(v) => format!("a vector of length {}", v.len()),
+            Value::Matrix(m) => format!("a {}x{} matrix", m.rows(), m.columns()),
+        }
+    }
+
+    fn scale(&self, factor: f64) -> Value {
+        match self {
+            Value::Scalar(x) => Value::Scalar(x * factor),
+            Value::Vector(v) => Value::Vector(v.scl_new(factor)),
+            Value::Matrix(m) => Value::Matrix(m.scl_new(factor)),
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Scalar(x) => write!(f, "{x}"),
+            Value::Vector(v) => write!(f, "{v}"),
+            Value::Matrix(m) => write!(f, "{m}"),
+        }
+    }
+}
+
+/// Variables defined so far in the session.
+#[derive(Debug, Default)]
+pub struct Environment {
+    variables: BTreeMap<String, Value>,
+}
+
+impl Environment {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.variables.get(name)
+    }
+
+    pub fn variables(&self) -> impl Iterator<Item = (&String, &Value)> {
+        self.variables.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.variables.clear();
+    }
+
+    /// Runs a statement, storing its result, and returns the name it was
+    /// stored under with the value.
+    pub fn execute(&mut self, statement: &Statement) -> Result<(String, Value), ShellError> {
+        let (name, expr) = match statement {
+            Statement::Assign { name, value } => (name.as_str(), value),
+            Statement::Expr(expr) => (ANSWER, expr),
+        };
+        let value = self.evaluate(expr)?;
+        self.variables.insert(name.to_string(), value.clone());
+        Ok((name.to_string(), value))
+    }
+
+    pub fn evaluate(&self, expr: &Expr) -> Result<Value, ShellError> {
+        match &expr.kind {
+            ExprKind::Number(value) => Ok(Value::Scalar(*value)),
+            ExprKind::Variable(name) => self
+                .get(name)
+                .cloned()
+                .ok_or_else(|| ShellError::new(format!("unknown variable '{name}'"), expr.span)),
+            ExprKind::Negate(operand) => Ok(self.evaluate(operand)?.scale(-1.0)),
+            ExprKind::Transpose(operand) => Ok(transpose(self.evaluate(operand)?)),
+            ExprKind::Binary {
+                op, left, right, ..
+            } => {
+                let left = self.evaluate(left)?;
+                let right = self.evaluate(right)?;
+                binary(*op, left, right, expr.span)
+            }
+            ExprKind::Call {
+                name,
+                name_span,
+                args,
+            } => {
+                let args = args
+                    .iter()
+                    .map(|arg| Ok((self.evaluate(arg)?, arg.span)))
+                    .collect::<Result<Vec<_>, ShellError>>()?;
+                call(name, *name_span, &args, expr.span)
+            }
+            ExprKind::Vector(items) => Ok(Value::Vector(Vector::from(
+                items
+                    .iter()
+                    .map(|item| self.entry(item))
+                    .collect::<Result<Vec<f64>, ShellError>>()?,
+            ))),
+            ExprKind::Matrix(rows) => {
+                let rows = rows
+                    .iter()
+                    .map(|row| row.iter().map(|item| self.entry(item)).collect())
+                    .collect::<Result<Vec<Vec<f64>>, ShellError>>()?;
+                let columns = rows[0].len();
+                Ok(Value::Matrix(Matrix::from_fn(
+                    rows.len(),
+                    columns,
+                    |i, j| rows[i][j],
+                )))
+            }
+        }
+    }
+
+    /// Evaluates an entry of a vector or matrix literal.
+    fn entry(&self, expr: &Expr) -> Result<f64, ShellError> {
+        match self.evaluate(expr)? {
+            Value::Scalar(x) => Ok(x),
+            other => Err(ShellError::new(
+                format!("entries must be scalars, found {}", other.describe()),
+                expr.span,
+            )),
+        }
+    }
+}
+
+fn transpose(value: Value) -> Value {
+    match value {
+        Value::Scalar(x) => Value::Scalar(x),
+        // A vector is a row, so its transpose is a single column
+        Value::Vector(v) => Value::Matrix(Matrix::from_fn(v.len(), 1, |i, _| v[i])),
+        Value::Matrix(m) => Value::Matrix(m.transpose()),
+    }
+}
+
+fn mismatch(action: &str, left: &Value, right: &Value, span: Span) -> ShellError {
+    ShellError::new(
+        format!(
+            "cannot {action} {} and {}",
+            left.describe(),
+            right.describe()
+        ),
+        span,
+    )
+}
+
+fn binary(op: BinaryOp, left: Value, right: Value, span: Span) -> Result<Value, ShellError> {
+    use Value::{Matrix as M, Scalar as S, Vector as V};
+
+    match (op, &left, &right) {
+        (BinaryOp::Add, S(a), S(b)) => Ok(S(a + b)),
+        (BinaryOp::Add, V(a), V(b)) if a.len() == b.len() => Ok(V(a.add_new(b))),
+        (BinaryOp::Add, M(a), M(b)) if a.shape() == b.shape() => Ok(M(a.add_new(b))),
+        (BinaryOp::Add, ..) => Err(mismatch("add", &left, &right, span)),
+
+        (BinaryOp::Sub, S(a), S(b)) => Ok(S(a - b)),
+        (BinaryOp::Sub, V(a), V(b)) if a.len() == b.len() => Ok(V(a.sub_new(b))),
+        (BinaryOp::Sub, M(a), M(b)) if a.shape() == b.shape() => Ok(M(a.sub_new(b))),
+        (BinaryOp::Sub, ..) => Err(mismatch("subtract", &left, &right, span)),
+
+        (BinaryOp::Mul, S(a), _) => Ok(right.scale(*a)),
+        (BinaryOp::Mul, _, S(b)) => Ok(left.scale(*b)),
+        (BinaryOp::Mul, M(a), M(b)) if a.columns() == b.rows() => Ok(M(a.mul_mat(b))),
+        // A·v treats v as a column, v·A as a row
+        (BinaryOp::Mul, M(a), V(v)) if a.columns() == v.len() => Ok(V(a.transpose().mul_vec(v))),
+        (BinaryOp::Mul, V(v), M(a)) if v.len() == a.rows() => Ok(V(a.mul_vec(v))),
+        (BinaryOp::Mul, V(_), V(_)) => Err(ShellError::new(
+            "cannot multiply two vectors, use dot(u, v)",
+            span,
+        )),
+        (BinaryOp::Mul, ..) => Err(mismatch("multiply", &left, &right, span)),
+
+        (BinaryOp::Div, _, S(b)) if *b == 0.0 => Err(ShellError::new("division by zero", span)),
+        (BinaryOp::Div, _, S(b)) => Ok(left.scale(1.0 / b)),
+        (BinaryOp::Div, ..) => Err(mismatch("divide", &left, &right, span)),
+
+        (BinaryOp::LeftDiv, S(a), _) => binary(BinaryOp::Div, right, S(*a), span),
+        (BinaryOp::LeftDiv, M(a), V(b)) if a.rows() == b.len() => a
+            .solve(b)
+            .map(V)
+            .map_err(|e| ShellError::new(e.to_string(), span)),
+        (BinaryOp::LeftDiv, M(a), M(b)) if a.rows() == b.rows() => {
+            // Solve column by column, collecting the solutions as rows
+            let solutions = b
+                .transpose()
+                .iter()
+                .map(|column| a.solve(&Vector::from(column.as_slice())))
+                .collect::<Result<Vec<Vector<f64>>, _>>()
+                .map_err(|e| ShellError::new(e.to_string(), span))?;
+            Ok(M(Matrix::from_fn(a.columns(), b.columns(), |i, j| {
+                solutions[j][i]
+            })))
+        }
+        (BinaryOp::LeftDiv, ..) => Err(mismatch("solve with", &left, &right, span)),
+
+        (BinaryOp::Pow, S(a), S(b)) => Ok(S(a.powf(*b))),
+        (BinaryOp::Pow, M(a), S(b)) => {
+            let result = if b.fract() == 0.0 && b.abs() <= i32::MAX as f64 {
+                a.powi(*b as i32)
+            } else {
+                a.powf(*b)
+            };
+            result
+                .map(M)
+                .map_err(|e| ShellError::new(e.to_string(), span))
+        }
+        (BinaryOp::Pow, ..) => Err(mismatch("raise", &left, &right, span)),
+    }
+}
+
+/// Checks the number of arguments of a function call.
+fn arity(name: &str, args: &[(Value, Span)], count: usize, span: Span) -> Result<(), ShellError> {
+    if args.len() == count {
+        Ok(())
+    } else {
+        Err(ShellError::new(
+            format!("{name} takes {count} argument(s), got {}", args.len()),
+            span,
+        ))
+    }
+}
+
+fn matrix_arg<'a>(
+    name: &str,
+    (value, span): &'a (Value, Span),
+) -> Result<&'a Matrix<f64>, ShellError> {
+    match value {
+        Value::Matrix(m) => Ok(m),
+        other => Err(ShellError::new(
+            format!("{name} expects a matrix, found {}", other.describe()),
+            *span,
+        )),
+    }
+}
+
+fn square_arg<'a>(name: &str, arg: &'a (Value, Span)) -> Result<&'a Matrix<f64>, ShellError> {
+    let m = matrix_arg(name, arg)?;
+    if m.is_square() {
+        Ok(m)
+    } else {
+        Err(ShellError::new(
+            format!("{name} expects a square matrix, found {}", arg.0.describe()),
+            arg.1,
+        ))
+    }
+}
+
+fn size_arg(name: &str, (value, span): &(Value, Span)) -> Result<usize, ShellError> {
+    match value {
+        Value::Scalar(x) if x.is_finite() && *x >= 0.0 && x.fract() == 0.0 => {
+            if *x > MAX_ENTRIES as f64 {
+                return Err(ShellError::new(
+                    format!("{name} size {x} is larger than {MAX_ENTRIES}"),
+                    *span,
+                ));
+            }
+            Ok(*x as usize)
+        }
+        _ => Err(ShellError::new(
+            format!("{name} expects a non-negative integer size"),
+            *span,
+        )),
+    }
+}
+
+/// Checks that a `rows`×`columns` result has at most `MAX_ENTRIES` entries.
+fn check_entries(name: &str, rows: usize, columns: usize, span: Span) -> Result<(), ShellError> {
+    match rows.checked_mul(columns) {
+        Some(entries) if entries <= MAX_ENTRIES => Ok(()),
+        _ => Err(ShellError::new(
+            format!(
+                "{name} would create a {rows}x{columns} matrix, more than {MAX_ENTRIES} entries"
+            ),
+            span,
+        )),
+    }
+}
+
+pub const FUNCTIONS: &[(&str, &str)] = &[
+    ("det(A)", "determinant of a square matrix"),
+    ("inv(A)", "inverse of a square matrix"),
+    ("transpose(X)", "transpose, same as X'"),
+    ("trace(A)", "sum of the diagonal of a square matrix"),
+    ("rank(A)", "rank of a matrix"),
+    ("rref(A)", "reduced row echelon form"),
+    ("solve(A, b)", "solution of A·x = b, same as A \\ b"),
+    ("dot(u, v)", "dot product of two vectors"),
+    (
+        "norm(X)",
+        "Euclidean norm of a vector, Frobenius norm of a matrix",
+    ),
+    ("eye(n)", "n×n identity matrix"),
+    ("zeros(m, n)", "m×n zero matrix"),
+];
+
+fn call(
+    name: &str,
+    name_span: Span,
+    args: &[(Value, Span)],
+    span: Span,
+) -> Result<Value, ShellError> {
+    let library = |e: &dyn Display| ShellError::new(e.to_string(), span);
+
+    match name {
+        "det" => {
+            arity(name, args, 1, span)?;
+            Ok(Value::Scalar(square_arg(name, &args[0])?.determinant()))
+        }
+        "inv" => {
+            arity(name, args, 1, span)?;
+            let m = square_arg(name, &args[0])?;
+            m.inverse().map(Value::Matrix).map_err(|e| library(&e))
+        }
+        "transpose" => {
+            arity(name, args, 1, span)?;
+            Ok(transpose(args[0].0.clone()))
+        }
+        "trace" => {
+            arity(name, args, 1, span)?;
+            Ok(Value::Scalar(square_arg(name, &args[0])?.trace()))
+        }
+        "rank" => {
+            arity(name, args, 1, span)?;
+            Ok(Value::Scalar(
+                matrix_arg(name, &args[0])?.clone().rank() as f64
+            ))
+        }
+        "rref" => {
+            arity(name, args, 1, span)?;
+            Ok(Value::Matrix(matrix_arg(name, &args[0])?.row_echelon()))
+        }
+        "solve" => {
+            arity(name, args, 2, span)?;
+            binary(
+                BinaryOp::LeftDiv,
+                args[0].0.clone(),
+                args[1].0.clone(),
+                span,
+            )
+        }
+        "dot" => {
+            arity(name, args, 2, span)?;
+            match (&args[0].0, &args[1].0) {
+                (Value::Vector(u), Value::Vector(v)) if u.len() == v.len() => {
+                    Ok(Value::Scalar(u.dot(v)))
+                }
+                (left, right) => Err(mismatch("take the dot product of", left, right, span)),
+            }
+        }
+        "norm" => {
+            arity(name, args, 1, span)?;
+            match &args[0].0 {
+                Value::Scalar(x) => Ok(Value::Scalar(x.abs())),
+                Value::Vector(v) => Ok(Value::Scalar(v.norm() as f64)),
+                Value::Matrix(m) => Ok(Value::Scalar(m.norm_frobenius() as f64)),
+            }
+        }
+        "eye" => {
+            arity(name, args, 1, span)?;
+            let size = size_arg(name, &args[0])?;
+            check_entries(name, size, size, args[0].1)?;
+            Ok(Value::Matrix(Matrix::identity(size)))
+        }
+        "zeros" => {
+            arity(name, args, 2, span)?;
+            let rows = size_arg(name, &args[0])?;
+            let columns = size_arg(name, &args[1])?;
+            check_entries(name, rows, columns, args[0].1.to(args[1].1))?;
+            Ok(Value::Matrix(Matrix::zeros(rows, columns)))
+        }
+        _ => Err(ShellError::new(
+            format!("unknown function '{name}'"),
+            name_span,
+        )),
+    }
+}
@@ -0,0 +1,183 @@
+use crate::error::{ShellError, Span};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    Number(f64),
+    Ident(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Backslash,
+    Caret,
+    Quote,
+    Equals,
+    Comma,
+    Semicolon,
+    LeftParen,
+    RightParen,
+    LeftBracket,
+    RightBracket,
+    End,
+}
+
+impl TokenKind {
+    /// How the token is shown in "expected ..., found ..." messages.
+    pub fn describe(&self) -> String {
+        match self {
+            TokenKind::Number(value) => format!("number {value}"),
+            TokenKind::Ident(name) => format!("'{name}'"),
+            TokenKind::Plus => "'+'".to_string(),
+            TokenKind::Minus => "'-'".to_string(),
+            TokenKind::Star => "'*'".to_string(),
+            TokenKind::Slash => "'/'".to_string(),
+            TokenKind::Backslash => "'\\'".to_string(),
+            TokenKind::Caret => "'^'".to_string(),
+            TokenKind::Quote => "'''".to_string(),
+            TokenKind::Equals => "'='".to_string(),
+            TokenKind::Comma => "','".to_string(),
+            TokenKind::Semicolon => "';'".to_string(),
+            TokenKind::LeftParen => "'('".to_string(),
+            TokenKind::RightParen => "')'".to_string(),
+            TokenKind::LeftBracket => "'['".to_string(),
+            TokenKind::RightBracket => "']'".to_string(),
+            TokenKind::End => "end of input".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub span: Span,
+}
+
+/// Splits a line into tokens, ending with a `TokenKind::End` token.
+pub fn tokenize(source: &str) -> Result<Vec<Token>, ShellError> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        let start = i;
+
+        if c.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+        if c == b'#' {
+            break;
+        }
+
+        let kind = if c.is_ascii_digit() || c == b'.' {
+            i = scan_number(bytes, i);
+            let text = &source[start..i];
+            let value = text.parse().map_err(|_| {
+                ShellError::new(format!("invalid number '{text}'"), Span::new(start, i))
+            })?;
+            TokenKind::Number(value)
+        } else if c.is_ascii_alphabetic() || c == b'_' {
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                i += 1;
+            }
+            TokenKind::Ident(source[start..i].to_string())
+        } else {
+            i += 1;
+            match c {
+                b'+' => TokenKind::Plus,
+                b'-' => TokenKind::Minus,
+                b'*' => TokenKind::Star,
+                b'/' => TokenKind::Slash,
+                b'\\' => TokenKind::Backslash,
+                b'^' => TokenKind::Caret,
+                b'\'' => TokenKind::Quote,
+                b'=' => TokenKind::Equals,
+                b',' => TokenKind::Comma,
+                b';' => TokenKind::Semicolon,
+                b'(' => TokenKind::LeftParen,
+                b')' => TokenKind::RightParen,
+                b'[' => TokenKind::LeftBracket,
+                b']' => TokenKind::RightBracket,
+                _ => {
+                    let end = start + source[start..].chars().next().map_or(1, char::len_utf8);
+                    return Err(ShellError::new(
+                        format!("unexpected character '{}'", &source[start..end]),
+                        Span::new(start, end),
+                    ));
+                }
+            }
+        };
+
+        tokens.push(Token {
+            kind,
+            span: Span::new(start, i),
+        });
+    }
+
+    tokens.push(Token {
+        kind: TokenKind::End,
+        span: Span::new(source.len(), source.len()),
+    });
+    Ok(tokens)
+}
+
+/// Returns the end of the number starting at `i`: digits, an optional
+/// fraction and an optional exponent.
+fn scan_number(bytes: &[u8], mut i: usize) -> usize {
+    let digits = |mut i: usize| {
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        i
+    };
+
+    i = digits(i);
+    if i < bytes.len() && bytes[i] == b'.' {
+        i = digits(i + 1);
+    }
+    if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+        let mut exponent = i + 1;
+        if exponent < bytes.len() && (bytes[exponent] == b'+' || bytes[exponent] == b'-') {
+            exponent += 1;
+        }
+        // Only an exponent with digits belongs to the number
+        if exponent < bytes.len() && bytes[exponent].is_ascii_digit() {
+            i = digits(exponent);
+        }
+    }
+    i
+}
+
+#[test]
+fn test_tokenize() {
+    let kinds: Vec<TokenKind> = tokenize("x = A' \\ [1.5e1, 2] # note")
+        .unwrap()
+        .into_iter()
+        .map(|token| token.kind)
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::Ident("x".to_string()),
+            TokenKind::Equals,
+            TokenKind::Ident("A".to_string()),
+            TokenKind::Quote,
+            TokenKind::Backslash,
+            TokenKind::LeftBracket,
+            TokenKind::Number(15.0),
+            TokenKind::Comma,
+            TokenKind::Number(2.0),
+            TokenKind::RightBracket,
+            TokenKind::End,
+        ]
+    );
+
+    let tokens = tokenize("2e").unwrap();
+    assert_eq!(tokens[0].kind, TokenKind::Number(2.0));
+    assert_eq!(tokens[1].kind, TokenKind::Ident("e".to_string()));
+
+    let error = tokenize("a $ b").unwrap_err();
+    assert_eq!(error.span, Span::new(2, 3));
+    assert_eq!(tokenize(".").unwrap_err().message, "invalid number '.'");
+}
@@ -0,0 +1,302 @@
+mod error;
+mod eval;
+mod lexer;
+mod parser;
+
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::process;
+
+use error::ShellError;
+use eval::{Environment, Value, FUNCTIONS};
+
+fn print_help() {
+    println!("Linear Algebra Shell\n");
+    println!("USAGE:");
+    println!("    linalg-shell [FILE]\n");
+    println!("Reads statements from FILE, or interactively from stdin, one per line:\n");
+    println!("    A = [[1, 2], [3, 4]]    Matrix, also written [1, 2; 3, 4]");
+    println!("    b = [5, 6]              Vector");
+    println!("    x = A \\ b               Solution of A·x = b");
+    println!("    A' * A                  Transpose and product, stored in ans\n");
+    println!("OPERATORS:");
+    println!("    + - * /                 Arithmetic; A * v takes v as a column");
+    println!("    \\                       Left division, A \\ b solves A·x = b");
+    println!("    ^                       Power of a scalar or square matrix");
+    println!("    '                       Transpose\n");
+    println!("FUNCTIONS:");
+    for (signature, description) in FUNCTIONS {
+        println!("    {signature:<24}{description}");
+    }
+    println!("\nCOMMANDS:");
+    println!("    help                    Show this help message");
+    println!("    vars                    List the defined variables");
+    println!("    clear                   Remove every variable");
+    println!("    exit, quit              Leave the shell");
+}
+
+/// Formats `name = value`, putting matrices under their name.
+fn format_assignment(name: &str, value: &Value) -> String {
+    match value {
+        Value::Matrix(m) if m.rows() > 0 => format!("{name} =\n{value}"),
+        _ => format!("{name} = {value}"),
+    }
+}
+
+/// Outcome of one line of input.
+enum Outcome {
+    Output(String),
+    Exit,
+}
+
+fn run_line(environment: &mut Environment, line: &str) -> Result<Outcome, ShellError> {
+    match line.trim() {
+        "" => return Ok(Outcome::Output(String::new())),
+        "exit" | "quit" => return Ok(Outcome::Exit),
+        "help" => {
+            print_help();
+            return Ok(Outcome::Output(String::new()));
+        }
+        "vars" => {
+            let listing: Vec<String> = environment
+                .variables()
+                .map(|(name, value)| format_assignment(name, value))
+                .collect();
+            return Ok(Outcome::Output(listing.join("\n")));
+        }
+        "clear" => {
+            environment.clear();
+            return Ok(Outcome::Output(String::new()));
+        }
+        _ => {}
+    }
+
+    let tokens = lexer::tokenize(line)?;
+    if tokens.len() == 1 {
+        // Only a comment
+        return Ok(Outcome::Output(String::new()));
+    }
+    let statement = parser::parse(&tokens)?;
+    let (name, value) = environment.execute(&statement)?;
+    Ok(Outcome::Output(format_assignment(&name, &value)))
+}
+
+/// Runs a script, stopping at the first error.
+fn run_script(path: &str) -> Result<(), String> {
+    let source = fs::read_to_string(path).map_err(|e| format!("cannot read '{path}': {e}"))?;
+    let mut environment = Environment::new();
+
+    for (index, line) in source.lines().enumerate() {
+        match run_line(&mut environment, line) {
+            Ok(Outcome::Output(output)) if !output.is_empty() => println!("{output}"),
+            Ok(Outcome::Output(_)) => {}
+            Ok(Outcome::Exit) => break,
+            Err(e) => return Err(format!("{path}:{}:\n{}", index + 1, e.render(line))),
+        }
+    }
+    Ok(())
+}
+
+fn run_interactive() {
+    let stdin = io::stdin();
+    let interactive = stdin.is_terminal();
+    let mut environment = Environment::new();
+
+    if interactive {
+        println!("Linear Algebra Shell. Type help for the syntax, exit to leave.");
+    }
+    loop {
+        if interactive {
+            print!(">> ");
+            let _ = io::stdout().flush();
+        }
+
+        let mut line = String::new();
+        match stdin.lock().read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(e) => {
+                eprintln!("Error: cannot read stdin: {e}");
+                process::exit(1);
+            }
+        }
+        let line = line.trim_end_matches(['\n', '\r']);
+
+        match run_line(&mut environment, line) {
+            Ok(Outcome::Output(output)) if !output.is_empty() => println!("{output}"),
+            Ok(Outcome::Output(_)) => {}
+            Ok(Outcome::Exit) => break,
+            Err(e) => eprintln!("{}", e.render(line)),
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.as_slice() {
+        [] => run_interactive(),
+        [flag] if flag == "-h" || flag == "--help" => print_help(),
+        [path] => {
+            if let Err(e) = run_script(path) {
+                eprintln!("Error: {e}");
+                process::exit(1);
+            }
+        }
+        _ => {
+            eprintln!("Error: expected at most one script file");
+            eprintln!("Use --help to see the usage");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+fn run_all(lines: &[&str]) -> Vec<Result<String, ShellError>> {
+    let mut environment = Environment::new();
+    lines
+        .iter()
+        .map(|line| match run_line(&mut environment, line) {
+            Ok(Outcome::Output(output)) => Ok(output),
+            Ok(Outcome::Exit) => Ok("exit".to_string()),
+            Err(e) => Err(e),
+        })
+        .collect()
+}
+
+#[test]
+fn test_session() {
+    let outputs = run_all(&[
+        "A = [[2, 1], [1, 3]]",
+        "b = [5, 10]",
+        "x = A \\ b",
+        "det(A)",
+        "A' * A",
+        "ans - [5, 5; 5, 10]",
+        "A * x",
+        "# a comment",
+        "exit",
+    ]);
+    let outputs: Vec<String> = outputs.into_iter().map(Result::unwrap).collect();
+
+    assert_eq!(outputs[0], "A =\n[2, 1]\n[1, 3]");
+    assert_eq!(outputs[1], "b = [5, 10]");
+    assert_eq!(outputs[2], "x = [1, 3]");
+    assert_eq!(outputs[3], "ans = 5");
+    assert_eq!(outputs[4], "ans =\n[5,  5]\n[5, 10]");
+    assert_eq!(outputs[5], "ans =\n[0, 0]\n[0, 0]");
+    assert_eq!(outputs[6], "ans = [5, 10]");
+    assert_eq!(outputs[7], "");
+    assert_eq!(outputs[8], "exit");
+}
+
+#[test]
+fn test_expressions() {
+    let outputs = run_all(&[
+        "-2^2 + 3 * (1 - 4) / 2",
+        "v = [1, 2, 3]",
+        "dot(v, v) + norm([3, 4])",
+        "v'",
+        "[1, 2; 3, 4]^2",
+        "inv([2, 0; 0, 4])",
+        "rank([1, 2; 2, 4]) + trace(eye(3))",
+        "v * [1, 0; 0, 1; 1, 1]",
+    ]);
+    let outputs: Vec<String> = outputs.into_iter().map(Result::unwrap).collect();
+
+    assert_eq!(outputs[0], "ans = -8.5");
+    assert_eq!(outputs[2], "ans = 19");
+    assert_eq!(outputs[3], "ans =\n[1]\n[2]\n[3]");
+    assert_eq!(outputs[4], "ans =\n[ 7, 10]\n[15, 22]");
+    assert_eq!(outputs[5], "ans =\n[0.5,    0]\n[  0, 0.25]");
+    assert_eq!(outputs[6], "ans = 4");
+    assert_eq!(outputs[7], "ans = [4, 5]");
+}
+
+#[test]
+fn test_errors_point_at_source() {
+    let outputs = run_all(&[
+        "A = [[1, 2], [3, 4]]",
+        "x = A \\ c",
+        "A * [1, 2, 3]",
+        "det([1, 2, 3; 4, 5, 6])",
+        "inv([1, 2; 2, 4])",
+        "frobnicate(A)",
+        "[A, 1]",
+    ]);
+    let errors: Vec<ShellError> = outputs
+        .into_iter()
+        .skip(1)
+        .map(Result::unwrap_err)
+        .collect();
+
+    assert_eq!(
+        errors[0].render("x = A \\ c"),
+        "  x = A \\ c\n          ^ unknown variable 'c'"
+    );
+    assert_eq!(
+        errors[1].message,
+        "cannot multiply a 2x2 matrix and a vector of length 3"
+    );
+    assert_eq!(errors[1].span, error::Span::new(0, 13));
+    assert_eq!(
+        errors[2].message,
+        "det expects a square matrix, found a 2x3 matrix"
+    );
+    assert_eq!(errors[2].span, error::Span::new(4, 22));
+    assert_eq!(
+        errors[3].message,
+        linear_algebra_42::MatrixInverseError::Singular.to_string()
+    );
+    assert_eq!(errors[4].message, "unknown function 'frobnicate'");
+    assert_eq!(errors[4].span, error::Span::new(0, 10));
+    assert_eq!(
+        errors[5].message,
+        "entries must be scalars, found a 2x2 matrix"
+    );
+    assert_eq!(errors[5].span, error::Span::new(1, 2));
+}
+
+#[test]
+fn test_size_limits() {
+    let outputs = run_all(&[
+        "eye(1e19)",
+        "zeros(3e9, 3e9)",
+        "zeros(2000, 2000)",
+        "eye(1e400)",
+        "zeros(1, 3)",
+    ]);
+
+    let error = outputs[0].as_ref().unwrap_err();
+    assert_eq!(
+        error.message,
+        "eye size 10000000000000000000 is larger than 1048576"
+    );
+    assert_eq!(error.span, error::Span::new(4, 8));
+    assert_eq!(
+        outputs[1].as_ref().unwrap_err().span,
+        error::Span::new(6, 9)
+    );
+    let error = outputs[2].as_ref().unwrap_err();
+    assert_eq!(
+        error.message,
+        "zeros would create a 2000x2000 matrix, more than 1048576 entries"
+    );
+    assert_eq!(error.span, error::Span::new(6, 16));
+    assert_eq!(
+        outputs[3].as_ref().unwrap_err().message,
+        "eye expects a non-negative integer size"
+    );
+    assert_eq!(outputs[4].as_deref().unwrap(), "ans =\n[0, 0, 0]");
+}
+
+#[test]
+fn test_deep_nesting() {
+    let allowed = "(".repeat(200) + &"-".repeat(297) + "[1, 2]" + &")".repeat(200);
+    let too_deep = "-".repeat(200_000) + "1";
+    let outputs = run_all(&[&allowed, &too_deep]);
+
+    assert_eq!(outputs[0].as_deref().unwrap(), "ans = [-1, -2]");
+    assert_eq!(outputs[1].as_ref().unwrap_err().message, "nesting too deep");
+}
@@ -0,0 +1,432 @@
+use crate::error::{ShellError, Span};
+use crate::lexer::{Token, TokenKind};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    /// `A \ b`, the solution of `A·x = b`
+    LeftDiv,
+    Pow,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExprKind {
+    Number(f64),
+    Variable(String),
+    Negate(Box<Expr>),
+    Transpose(Box<Expr>),
+    Binary {
+        op: BinaryOp,
+        left: Box<Expr>,
+        right: Box<Expr>,
+    },
+    Call {
+        name: String,
+        name_span: Span,
+        args: Vec<Expr>,
+    },
+    Vector(Vec<Expr>),
+    Matrix(Vec<Vec<Expr>>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expr {
+    pub kind: ExprKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    Assign { name: String, value: Expr },
+    Expr(Expr),
+}
+
+/// Deepest nesting a line may have, counting every operator and bracket.
+/// Evaluating and dropping an expression recurse once per level.
+const MAX_DEPTH: usize = 500;
+
+/// Parses one line of tokens as an assignment or an expression.
+pub fn parse(tokens: &[Token]) -> Result<Statement, ShellError> {
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+        depth: 0,
+    };
+
+    let statement = match (&tokens[0].kind, tokens.get(1).map(|t| &t.kind)) {
+        (TokenKind::Ident(name), Some(TokenKind::Equals)) => {
+            parser.position = 2;
+            Statement::Assign {
+                name: name.clone(),
+                value: parser.expression()?,
+            }
+        }
+        _ => Statement::Expr(parser.expression()?),
+    };
+
+    parser.expect(TokenKind::End)?;
+    Ok(statement)
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    position: usize,
+    /// Nesting of the expression being parsed, checked against `MAX_DEPTH`.
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.position]
+    }
+
+    fn advance(&mut self) -> &Token {
+        let token = &self.tokens[self.position];
+        if token.kind != TokenKind::End {
+            self.position += 1;
+        }
+        token
+    }
+
+    /// Consumes the next token if it is `kind`.
+    fn accept(&mut self, kind: TokenKind) -> Option<Span> {
+        (self.peek().kind == kind).then(|| self.advance().span)
+    }
+
+    fn expect(&mut self, kind: TokenKind) -> Result<Span, ShellError> {
+        self.accept(kind.clone()).ok_or_else(|| {
+            let found = self.peek();
+            ShellError::new(
+                format!(
+                    "expected {}, found {}",
+                    kind.describe(),
+                    found.kind.describe()
+                ),
+                found.span,
+            )
+        })
+    }
+
+    /// Enters one more level of nesting, failing at the next token past
+    /// `MAX_DEPTH`. Callers restore `depth` once their expression is built.
+    fn descend(&mut self) -> Result<(), ShellError> {
+        if self.depth == MAX_DEPTH {
+            return Err(ShellError::new("nesting too deep", self.peek().span));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn binary(op: BinaryOp, left: Expr, right: Expr) -> Expr {
+        Expr {
+            span: left.span.to(right.span),
+            kind: ExprKind::Binary {
+                op,
+                left: Box::new(left),
+                right: Box::new(right),
+            },
+        }
+    }
+
+    /// `term (('+' | '-') term)*`
+    fn expression(&mut self) -> Result<Expr, ShellError> {
+        let depth = self.depth;
+        self.descend()?;
+        let mut left = self.term()?;
+        loop {
+            let op = match self.peek().kind {
+                TokenKind::Plus => BinaryOp::Add,
+                TokenKind::Minus => BinaryOp::Sub,
+                _ => break,
+            };
+            self.descend()?;
+            self.advance();
+            let right = self.term()?;
+            left = Self::binary(op, left, right);
+        }
+        self.depth = depth;
+        Ok(left)
+    }
+
+    /// `unary (('*' | '/' | '\') unary)*`
+    fn term(&mut self) -> Result<Expr, ShellError> {
+        let depth = self.depth;
+        let mut left = self.unary()?;
+        loop {
+            let op = match self.peek().kind {
+                TokenKind::Star => BinaryOp::Mul,
+                TokenKind::Slash => BinaryOp::Div,
+                TokenKind::Backslash => BinaryOp::LeftDiv,
+                _ => break,
+            };
+            self.descend()?;
+            self.advance();
+            let right = self.unary()?;
+            left = Self::binary(op, left, right);
+        }
+        self.depth = depth;
+        Ok(left)
+    }
+
+    /// `('-' | '+')* power`, so that `-2^2` is `-(2^2)`
+    fn unary(&mut self) -> Result<Expr, ShellError> {
+        let depth = self.depth;
+        let mut negations = Vec::new();
+        loop {
+            match self.peek().kind {
+                TokenKind::Minus => {
+                    self.descend()?;
+                    negations.push(self.advance().span);
+                }
+                TokenKind::Plus => {
+                    self.advance();
+                }
+                _ => break,
+            }
+        }
+
+        let mut expr = self.power()?;
+        for span in negations.into_iter().rev() {
+            expr = Expr {
+                span: span.to(expr.span),
+                kind: ExprKind::Negate(Box::new(expr)),
+            };
+        }
+        self.depth = depth;
+        Ok(expr)
+    }
+
+    /// `postfix ('^' unary)?`, right associative
+    fn power(&mut self) -> Result<Expr, ShellError> {
+        let depth = self.depth;
+        let base = self.postfix()?;
+        if self.peek().kind != TokenKind::Caret {
+            return Ok(base);
+        }
+        self.descend()?;
+        self.advance();
+        let exponent = self.unary()?;
+        self.depth = depth;
+        Ok(Self::binary(BinaryOp::Pow, base, exponent))
+    }
+
+    /// `primary '''*`
+    fn postfix(&mut self) -> Result<Expr, ShellError> {
+        let depth = self.depth;
+        let mut expr = self.primary()?;
+        while self.peek().kind == TokenKind::Quote {
+            self.descend()?;
+            let span = self.advance().span;
+            expr = Expr {
+                span: expr.span.to(span),
+                kind: ExprKind::Transpose(Box::new(expr)),
+            };
+        }
+        self.depth = depth;
+        Ok(expr)
+    }
+
+    fn primary(&mut self) -> Result<Expr, ShellError> {
+        let token = self.advance().clone();
+        match token.kind {
+            TokenKind::Number(value) => Ok(Expr {
+                kind: ExprKind::Number(value),
+                span: token.span,
+            }),
+            TokenKind::Ident(name) => {
+                if self.accept(TokenKind::LeftParen).is_none() {
+                    return Ok(Expr {
+                        kind: ExprKind::Variable(name),
+                        span: token.span,
+                    });
+                }
+                let args = self.list(TokenKind::RightParen)?;
+                let close = self.expect(TokenKind::RightParen)?;
+                Ok(Expr {
+                    kind: ExprKind::Call {
+                        name,
+                        name_span: token.span,
+                        args,
+                    },
+                    span: token.span.to(close),
+                })
+            }
+            TokenKind::LeftParen => {
+                let inner = self.expression()?;
+                let close = self.expect(TokenKind::RightParen)?;
+                Ok(Expr {
+                    kind: inner.kind,
+                    span: token.span.to(close),
+                })
+            }
+            TokenKind::LeftBracket => self.literal(token.span),
+            kind => Err(ShellError::new(
+                format!("expected an expression, found {}", kind.describe()),
+                token.span,
+            )),
+        }
+    }
+
+    /// Comma separated expressions up to, but not including, `close`.
+    fn list(&mut self, close: TokenKind) -> Result<Vec<Expr>, ShellError> {
+        let mut items = Vec::new();
+        if self.peek().kind == close {
+            return Ok(items);
+        }
+        loop {
+            items.push(self.expression()?);
+            if self.accept(TokenKind::Comma).is_none() {
+                return Ok(items);
+            }
+        }
+    }
+
+    /// Parses a literal after its opening `[`: `[1, 2]` is a vector, while
+    /// `[[1, 2], [3, 4]]` and `[1, 2; 3, 4]` are matrices.
+    fn literal(&mut self, open: Span) -> Result<Expr, ShellError> {
+        let mut rows = Vec::new();
+
+        if self.peek().kind == TokenKind::LeftBracket {
+            loop {
+                let row_open = self.expect(TokenKind::LeftBracket)?;
+                let row = self.list(TokenKind::RightBracket)?;
+                let row_close = self.expect(TokenKind::RightBracket)?;
+                rows.push((row, row_open.to(row_close)));
+                if self.accept(TokenKind::Comma).is_none() {
+                    break;
+                }
+            }
+        } else {
+            loop {
+                let start = self.peek().span;
+                let row = self.list(TokenKind::RightBracket)?;
+                let end = row.last().map_or(start, |item| item.span);
+                rows.push((row, start.to(end)));
+                if self.accept(TokenKind::Semicolon).is_none() {
+                    break;
+                }
+            }
+            if rows.len() == 1 {
+                let close = self.expect(TokenKind::RightBracket)?;
+                return Ok(Expr {
+                    kind: ExprKind::Vector(rows.pop().unwrap().0),
+                    span: open.to(close),
+                });
+            }
+        }
+
+        let close = self.expect(TokenKind::RightBracket)?;
+        let columns = rows[0].0.len();
+        for (index, (row, span)) in rows.iter().enumerate() {
+            if row.len() != columns {
+                return Err(ShellError::new(
+                    format!(
+                        "row {} has {} entries, expected {columns}",
+                        index + 1,
+                        row.len()
+                    ),
+                    *span,
+                ));
+            }
+        }
+
+        Ok(Expr {
+            kind: ExprKind::Matrix(rows.into_iter().map(|(row, _)| row).collect()),
+            span: open.to(close),
+        })
+    }
+}
+
+#[cfg(test)]
+fn parse_line(source: &str) -> Result<Statement, ShellError> {
+    parse(&crate::lexer::tokenize(source)?)
+}
+
+#[test]
+fn test_parse_precedence() {
+    let Statement::Expr(expr) = parse_line("-2^2 + A' * b").unwrap() else {
+        panic!("expected an expression");
+    };
+    let ExprKind::Binary {
+        op: BinaryOp::Add,
+        left,
+        right,
+        ..
+    } = expr.kind
+    else {
+        panic!("expected a sum");
+    };
+    assert!(matches!(left.kind, ExprKind::Negate(_)));
+    assert!(matches!(
+        right.kind,
+        ExprKind::Binary {
+            op: BinaryOp::Mul,
+            ..
+        }
+    ));
+    assert_eq!(expr.span, Span::new(0, 13));
+}
+
+#[test]
+fn test_parse_literals() {
+    let Statement::Assign { name, value } = parse_line("A = [[1, 2], [3, 4]]").unwrap() else {
+        panic!("expected an assignment");
+    };
+    assert_eq!(name, "A");
+    assert!(matches!(value.kind, ExprKind::Matrix(ref rows) if rows.len() == 2));
+
+    let Statement::Expr(expr) = parse_line("[1, 2; 3, 4]").unwrap() else {
+        panic!("expected an expression");
+    };
+    assert!(matches!(expr.kind, ExprKind::Matrix(ref rows) if rows[1].len() == 2));
+
+    let Statement::Expr(expr) = parse_line("[]").unwrap() else {
+        panic!("expected an expression");
+    };
+    assert_eq!(expr.kind, ExprKind::Vector(Vec::new()));
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = parse_line("[[1, 2], [3]]").unwrap_err();
+    assert_eq!(error.message, "row 2 has 1 entries, expected 2");
+    assert_eq!(error.span, Span::new(9, 12));
+
+    let error = parse_line("[1, 2; 3]").unwrap_err();
+    assert_eq!(error.span, Span::new(7, 8));
+
+    let error = parse_line("det(A").unwrap_err();
+    assert_eq!(error.message, "expected ')', found end of input");
+    assert_eq!(error.span, Span::new(5, 5));
+
+    let error = parse_line("A B").unwrap_err();
+    assert_eq!(error.message, "expected end of input, found 'B'");
+
+    let error = parse_line("x = * 2").unwrap_err();
+    assert_eq!(error.message, "expected an expression, found '*'");
+    assert_eq!(error.span, Span::new(4, 5));
+}
+
+#[test]
+fn test_parse_nesting_limit() {
+    for source in [
+        "-".repeat(200_000) + "1",
+        "1".to_string() + &"'".repeat(200_000),
+        "(".repeat(200_000) + "1" + &")".repeat(200_000),
+        "[".repeat(200_000),
+        "2".to_string() + &"^2".repeat(200_000),
+    ] {
+        let error = parse_line(&source).unwrap_err();
+        assert_eq!(error.message, "nesting too deep");
+    }
+
+    let error = parse_line(&("x = ".to_string() + &"-".repeat(1000) + "1")).unwrap_err();
+    assert_eq!(error.span, Span::new(503, 504));
+
+    // Deep but allowed nesting still parses
+    let source = "(".repeat(100) + &"-".repeat(100) + "1" + &"'".repeat(100) + &")".repeat(100);
+    assert!(parse_line(&source).is_ok());
+}
@@ -1,17 +1,28 @@
 use std::ops::{Add, Mul};
 
 use crate::{
+    traits::{Conjugate, Magnitude, RealPart, Zero},
     Vector,
-    traits::{Magnitude, Zero},
 };
 
+/// Returns the cosine of the angle between `u` and `v`.
+///
+/// For complex vectors this is the real part of the inner product
+/// `Σ conj(uᵢ)·vᵢ` over the product of the norms, so a vector always makes
+/// an angle of zero with itself. Returns zero if either vector is zero.
 pub fn angle_cos<K>(u: &Vector<K>, v: &Vector<K>) -> f32
 where
-    K: Copy + Zero + Add<Output = K> + Mul<Output = K> + Magnitude<Output = f32> + Into<f32>,
+    K: Copy
+        + Zero
+        + Conjugate
+        + Add<Output = K>
+        + Mul<Output = K>
+        + Magnitude<Output = f32>
+        + RealPart<Output = f32>,
 {
     debug_assert_eq!(u.len(), v.len(), "Vectors must have same dimension");
 
-    let dot_product = u.dot(v).into();
+    let dot_product = u.complex_dot(v).real_part();
     let norm_u = u.norm();
     let norm_v = v.norm();
 
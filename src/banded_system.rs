@@ -0,0 +1,357 @@
+//! # Banded Linear Systems
+//!
+//! This module solves linear systems given by their diagonals instead of a
+//! dense `Matrix`: tridiagonal systems with the Thomas algorithm, periodic
+//! (cyclic) tridiagonal systems with the Sherman–Morrison correction, and
+//! general banded systems with banded LU. Such systems arise from splines and
+//! finite differences, and cost `O(n)` instead of the `O(n³)` of `inverse`.
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::{
+    errors::BandedSystemError,
+    traits::{Magnitude, One, Zero},
+    Banded, Vector,
+};
+
+/// Checks that the diagonal at `offset` has `expected` entries.
+fn check_diagonal<K>(
+    diagonal: &Vector<K>,
+    offset: isize,
+    expected: usize,
+) -> Result<(), BandedSystemError> {
+    if diagonal.len() == expected {
+        Ok(())
+    } else {
+        Err(BandedSystemError::DiagonalLength {
+            offset,
+            expected,
+            found: diagonal.len(),
+        })
+    }
+}
+
+/// Checks that the right-hand side has `expected` entries.
+fn check_rhs<K>(rhs: &Vector<K>, expected: usize) -> Result<(), BandedSystemError> {
+    if rhs.len() == expected {
+        Ok(())
+    } else {
+        Err(BandedSystemError::RhsLength {
+            expected,
+            found: rhs.len(),
+        })
+    }
+}
+
+/// Solves a banded system with partial pivoting, the fallback of the
+/// specialized solvers when they meet a zero pivot.
+fn solve_pivoted<K>(matrix: &Banded<K>, rhs: &Vector<K>) -> Result<Vector<K>, BandedSystemError>
+where
+    K: Copy + Zero + Sub<Output = K> + Mul<Output = K> + Div<Output = K> + Magnitude<Output = f32>,
+{
+    // Banded matrices are square, so solving only fails on singular systems
+    matrix.solve(rhs).map_err(|_| BandedSystemError::Singular)
+}
+
+/// Runs the Thomas algorithm, Gaussian elimination without pivoting
+/// specialized to tridiagonal systems.
+///
+/// `lower[i]` is entry `(i + 1, i)` and `upper[i]` is entry `(i, i + 1)`.
+/// Returns `None` when a pivot vanishes, which may happen even for
+/// non-singular systems.
+fn thomas<K>(lower: &[K], diagonal: &[K], upper: &[K], rhs: &[K]) -> Option<Vec<K>>
+where
+    K: Copy + Zero + Sub<Output = K> + Mul<Output = K> + Div<Output = K>,
+{
+    let n = diagonal.len();
+    let mut upper_scaled = Vec::with_capacity(n);
+    let mut rhs_scaled: Vec<K> = Vec::with_capacity(n);
+
+    for i in 0..n {
+        let (pivot, numerator) = if i == 0 {
+            (diagonal[0], rhs[0])
+        } else {
+            (
+                diagonal[i] - lower[i - 1] * upper_scaled[i - 1],
+                rhs[i] - lower[i - 1] * rhs_scaled[i - 1],
+            )
+        };
+        if pivot.is_zero() {
+            return None;
+        }
+
+        if i + 1 < n {
+            upper_scaled.push(upper[i] / pivot);
+        }
+        rhs_scaled.push(numerator / pivot);
+    }
+
+    let mut x = rhs_scaled;
+    for i in (0..n.saturating_sub(1)).rev() {
+        x[i] = x[i] - upper_scaled[i] * x[i + 1];
+    }
+    Some(x)
+}
+
+/// Solves the tridiagonal system `A·x = rhs` with the Thomas algorithm.
+///
+/// For an `n×n` system, `diagonal` holds the `n` entries `A[i][i]`, `lower`
+/// the `n - 1` entries `A[i + 1][i]` and `upper` the `n - 1` entries
+/// `A[i][i + 1]`. The Thomas algorithm does not pivot; if it meets a zero
+/// pivot, the system is solved again with pivoted banded LU, so a failure
+/// always means the system is singular.
+///
+/// # Errors
+///
+/// * `BandedSystemError::DiagonalLength` - If `lower` or `upper` does not have
+///   one entry less than `diagonal`
+/// * `BandedSystemError::RhsLength` - If `rhs` does not match `diagonal`
+/// * `BandedSystemError::Singular` - If the system has no unique solution
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::{solve_tridiagonal, Vector};
+///
+/// // 2x - y = 1, -x + 2y - z = 0, -y + 2z = 1
+/// let x = solve_tridiagonal(
+///     &Vector::from([-1.0, -1.0]),
+///     &Vector::from([2.0, 2.0, 2.0]),
+///     &Vector::from([-1.0, -1.0]),
+///     &Vector::from([1.0, 0.0, 1.0]),
+/// )
+/// .unwrap();
+///
+/// assert!(x.iter().all(|&v| (v - 1.0_f64).abs() < 1e-12));
+/// ```
+pub fn solve_tridiagonal<K>(
+    lower: &Vector<K>,
+    diagonal: &Vector<K>,
+    upper: &Vector<K>,
+    rhs: &Vector<K>,
+) -> Result<Vector<K>, BandedSystemError>
+where
+    K: Copy + Zero + Sub<Output = K> + Mul<Output = K> + Div<Output = K> + Magnitude<Output = f32>,
+{
+    let n = diagonal.len();
+    let off_diagonal = n.saturating_sub(1);
+    check_diagonal(lower, -1, off_diagonal)?;
+    check_diagonal(upper, 1, off_diagonal)?;
+    check_rhs(rhs, n)?;
+
+    match thomas(
+        lower.as_slice(),
+        diagonal.as_slice(),
+        upper.as_slice(),
+        rhs.as_slice(),
+    ) {
+        Some(x) => Ok(Vector::from(x)),
+        None => {
+            let matrix = Banded::from_fn(n, 1, 1, |i, j| {
+                if i == j {
+                    diagonal[i]
+                } else if i > j {
+                    lower[j]
+                } else {
+                    upper[i]
+                }
+            });
+            solve_pivoted(&matrix, rhs)
+        }
+    }
+}
+
+/// Solves the cyclic tridiagonal system `A·x = rhs`, a tridiagonal system
+/// with two extra corner entries coupling the first and last unknowns, as
+/// produced by periodic splines and periodic boundary conditions.
+///
+/// All three diagonals hold `n` entries and wrap around: `lower[i]` is entry
+/// `(i, i - 1)`, with `lower[0]` the top-right corner `(0, n - 1)`, and
+/// `upper[i]` is entry `(i, i + 1)`, with `upper[n - 1]` the bottom-left
+/// corner `(n - 1, 0)`. Dropping `lower[0]` and `upper[n - 1]` gives the
+/// arguments of `solve_tridiagonal`.
+///
+/// The corners are removed by a Sherman–Morrison correction, which costs two
+/// Thomas solves. If a pivot vanishes along the way, the system is solved
+/// with pivoted LU over the full matrix, so a failure always means the
+/// system is singular.
+///
+/// # Errors
+///
+/// * `BandedSystemError::DiagonalLength` - If `lower` or `upper` does not
+///   have as many entries as `diagonal`
+/// * `BandedSystemError::RhsLength` - If `rhs` does not match `diagonal`
+/// * `BandedSystemError::Singular` - If the system has no unique solution
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::{solve_cyclic_tridiagonal, Vector};
+///
+/// // Periodic [1, 4, 1] stencil, as in periodic cubic splines
+/// let ones = Vector::from([1.0; 5]);
+/// let x = solve_cyclic_tridiagonal(
+///     &ones,
+///     &Vector::from([4.0; 5]),
+///     &ones,
+///     &Vector::from([6.0; 5]),
+/// )
+/// .unwrap();
+///
+/// assert!(x.iter().all(|&v| (v - 1.0_f64).abs() < 1e-12));
+/// ```
+pub fn solve_cyclic_tridiagonal<K>(
+    lower: &Vector<K>,
+    diagonal: &Vector<K>,
+    upper: &Vector<K>,
+    rhs: &Vector<K>,
+) -> Result<Vector<K>, BandedSystemError>
+where
+    K: Copy
+        + Zero
+        + One
+        + Add<Output = K>
+        + Sub<Output = K>
+        + Mul<Output = K>
+        + Div<Output = K>
+        + Magnitude<Output = f32>,
+{
+    let n = diagonal.len();
+    check_diagonal(lower, -1, n)?;
+    check_diagonal(upper, 1, n)?;
+    check_rhs(rhs, n)?;
+
+    if n >= 3 {
+        if let Some(x) = sherman_morrison(lower, diagonal, upper, rhs) {
+            return Ok(Vector::from(x));
+        }
+    }
+
+    // Below three unknowns the corners overlap the band, so entries are summed
+    let matrix = Banded::from_fn(n, n.saturating_sub(1), n.saturating_sub(1), |i, j| {
+        let mut entry = K::zero();
+        if j == i {
+            entry = entry + diagonal[i];
+        }
+        if j == (i + n - 1) % n {
+            entry = entry + lower[i];
+        }
+        if j == (i + 1) % n {
+            entry = entry + upper[i];
+        }
+        entry
+    });
+    solve_pivoted(&matrix, rhs)
+}
+
+/// Solves a cyclic tridiagonal system with `n >= 3` by writing it as a
+/// tridiagonal matrix plus the rank-one update `u·vᵀ`, with
+/// `u = [γ, 0, …, 0, α]` and `v = [1, 0, …, 0, β/γ]`.
+///
+/// Returns `None` when a pivot or the correction denominator vanishes.
+fn sherman_morrison<K>(
+    lower: &Vector<K>,
+    diagonal: &Vector<K>,
+    upper: &Vector<K>,
+    rhs: &Vector<K>,
+) -> Option<Vec<K>>
+where
+    K: Copy + Zero + One + Add<Output = K> + Sub<Output = K> + Mul<Output = K> + Div<Output = K>,
+{
+    let n = diagonal.len();
+    let alpha = upper[n - 1];
+    let beta = lower[0];
+    // γ = -A[0][0] avoids cancellation in the first pivot
+    let gamma = if diagonal[0].is_zero() {
+        K::zero() - K::one()
+    } else {
+        K::zero() - diagonal[0]
+    };
+
+    let mut modified = diagonal.to_vec();
+    modified[0] = modified[0] - gamma;
+    modified[n - 1] = modified[n - 1] - alpha * beta / gamma;
+
+    let sub = &lower.as_slice()[1..];
+    let sup = &upper.as_slice()[..n - 1];
+    let y = thomas(sub, &modified, sup, rhs.as_slice())?;
+
+    let mut u = vec![K::zero(); n];
+    u[0] = gamma;
+    u[n - 1] = alpha;
+    let z = thomas(sub, &modified, sup, &u)?;
+
+    let denominator = K::one() + z[0] + beta * z[n - 1] / gamma;
+    if denominator.is_zero() {
+        return None;
+    }
+    let factor = (y[0] + beta * y[n - 1] / gamma) / denominator;
+
+    Some(
+        y.iter()
+            .zip(&z)
+            .map(|(&y_i, &z_i)| y_i - factor * z_i)
+            .collect(),
+    )
+}
+
+/// Solves the banded system `A·x = rhs` by banded LU with partial pivoting.
+///
+/// `diagonals` lists the diagonals of `A` from the lowest subdiagonal to the
+/// highest superdiagonal, and `lower` is the number of subdiagonals, so
+/// `diagonals[lower]` is the main diagonal. The diagonal at offset `k` holds
+/// the `n - |k|` entries `A[i][i + k]` in order of increasing row.
+///
+/// # Errors
+///
+/// * `BandedSystemError::MissingMainDiagonal` - If `lower` is not smaller
+///   than the number of diagonals
+/// * `BandedSystemError::DiagonalLength` - If a diagonal does not have
+///   `n - |k|` entries
+/// * `BandedSystemError::RhsLength` - If `rhs` does not match the main
+///   diagonal
+/// * `BandedSystemError::Singular` - If the system has no unique solution
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::{solve_banded, Vector};
+///
+/// // One subdiagonal, main diagonal, two superdiagonals
+/// let diagonals = [
+///     Vector::from([1.0, 1.0, 1.0]),
+///     Vector::from([4.0, 4.0, 4.0, 4.0]),
+///     Vector::from([1.0, 1.0, 1.0]),
+///     Vector::from([1.0, 1.0]),
+/// ];
+/// let x = solve_banded(&diagonals, 1, &Vector::from([6.0, 7.0, 6.0, 5.0])).unwrap();
+///
+/// assert!(x.iter().all(|&v| (v - 1.0_f64).abs() < 1e-12));
+/// ```
+pub fn solve_banded<K>(
+    diagonals: &[Vector<K>],
+    lower: usize,
+    rhs: &Vector<K>,
+) -> Result<Vector<K>, BandedSystemError>
+where
+    K: Copy + Zero + Sub<Output = K> + Mul<Output = K> + Div<Output = K> + Magnitude<Output = f32>,
+{
+    if lower >= diagonals.len() {
+        return Err(BandedSystemError::MissingMainDiagonal {
+            lower,
+            count: diagonals.len(),
+        });
+    }
+
+    let n = diagonals[lower].len();
+    for (index, diagonal) in diagonals.iter().enumerate() {
+        let offset = index as isize - lower as isize;
+        check_diagonal(diagonal, offset, n.saturating_sub(offset.unsigned_abs()))?;
+    }
+    check_rhs(rhs, n)?;
+
+    let upper = diagonals.len() - lower - 1;
+    // Entry (i, j) sits at index min(i, j) of the diagonal at offset j - i
+    let matrix = Banded::from_fn(n, lower, upper, |i, j| diagonals[j + lower - i][i.min(j)]);
+    solve_pivoted(&matrix, rhs)
+}
@@ -1,4 +1,8 @@
-use crate::traits::{Conjugate, Magnitude, Negative, One, Zero};
+use crate::{
+    errors::ComplexConversionError,
+    traits::{Conjugate, Magnitude, Negative, One, RealPart, Zero},
+};
+use std::cmp::Ordering;
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
 /// Represents a complex number in the form `a + bi`.
@@ -80,6 +84,56 @@ impl Complex {
             i: -self.i,
         }
     }
+
+    /// Compares two complex numbers by their magnitude.
+    ///
+    /// Complex numbers have no natural ordering, so this comparison must be
+    /// requested explicitly. Numbers with the same modulus compare as equal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::cmp::Ordering;
+    /// use linear_algebra_42::Complex;
+    ///
+    /// let z1 = Complex::new(3.0, 4.0);
+    /// let z2 = Complex::new(5.0, 0.0);
+    /// let z3 = Complex::new(1.0, 1.0);
+    ///
+    /// assert_eq!(z1.cmp_by_norm(&z2), Ordering::Equal);
+    /// assert_eq!(z1.cmp_by_norm(&z3), Ordering::Greater);
+    /// ```
+    pub fn cmp_by_norm(&self, other: &Self) -> Ordering {
+        self.magnitude().total_cmp(&other.magnitude())
+    }
+
+    /// Converts the complex number into a real number.
+    ///
+    /// The conversion only succeeds when the absolute value of the imaginary
+    /// part is within `tolerance`; otherwise the imaginary part would be lost.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ComplexConversionError::NonZeroImaginary` if `|imaginary| > tolerance`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Complex;
+    ///
+    /// assert_eq!(Complex::new(2.0, 1e-8).try_into_real(1e-6).unwrap(), 2.0);
+    /// assert!(Complex::new(2.0, 1.0).try_into_real(1e-6).is_err());
+    /// ```
+    pub fn try_into_real(&self, tolerance: f32) -> Result<f32, ComplexConversionError> {
+        if self.i.abs() > tolerance {
+            return Err(ComplexConversionError::NonZeroImaginary {
+                imaginary: self.i,
+                tolerance,
+            });
+        }
+
+        Ok(self.r)
+    }
 }
 
 impl Zero for Complex {
@@ -90,6 +144,10 @@ impl Zero for Complex {
     fn is_zero(&self) -> bool {
         self.r == 0. && self.i == 0.
     }
+
+    fn is_negligible(&self) -> bool {
+        self.magnitude() < 1e-10
+    }
 }
 
 impl One for Complex {
@@ -112,6 +170,14 @@ impl Magnitude for Complex {
     }
 }
 
+impl RealPart for Complex {
+    type Output = f32;
+
+    fn real_part(&self) -> Self::Output {
+        self.r
+    }
+}
+
 impl Conjugate for Complex {
     fn conjugate(&self) -> Self {
         Complex {
@@ -187,15 +253,3 @@ impl Neg for Complex {
         }
     }
 }
-
-impl PartialOrd for Complex {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.magnitude().partial_cmp(&other.magnitude())
-    }
-}
-
-impl From<Complex> for f32 {
-    fn from(val: Complex) -> Self {
-        val.r
-    }
-}
@@ -1,7 +1,11 @@
 use std::fmt::{Display, Formatter, Result};
 
 use crate::{
-    Complex, InterpolationError, LinearCombinationError, Matrix, MatrixInverseError, Vector,
+    traits::{DirectedRounding, Integer},
+    BandedSystemError, CheckedInverseError, Complex, ComplexConversionError, Dual,
+    EliminationTrace, InterpolationError, Interval, LinearCombinationError, LinearSystemError,
+    Matrix, MatrixFunctionError, MatrixInverseError, MatrixShapeError, ModP, PackedMatrixError,
+    ParseError, Quaternion, QuaternionError, Rational, RowOperation, TransformError, Vector,
 };
 
 impl<K> Display for Vector<K>
@@ -54,6 +58,44 @@ where
     }
 }
 
+impl<K> Display for RowOperation<K>
+where
+    K: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            RowOperation::Swap { i, j } => write!(f, "L{} ↔ L{}", i + 1, j + 1),
+            RowOperation::Scale { row, factor } => {
+                write!(f, "L{0} ← ({factor})·L{0}", row + 1)
+            }
+            RowOperation::AddMultiple {
+                target,
+                source,
+                factor,
+            } => write!(f, "L{0} ← L{0} + ({factor})·L{1}", target + 1, source + 1),
+        }
+    }
+}
+
+impl<K> Display for EliminationTrace<K>
+where
+    K: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "Matriz inicial:\n{}", self.initial())?;
+        for (i, step) in self.steps().iter().enumerate() {
+            write!(
+                f,
+                "\n\nPasso {}: {}\n{}",
+                i + 1,
+                step.operation(),
+                step.after()
+            )?;
+        }
+        Ok(())
+    }
+}
+
 impl Display for LinearCombinationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
@@ -89,6 +131,174 @@ impl Display for MatrixInverseError {
     }
 }
 
+impl Display for CheckedInverseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            CheckedInverseError::Inverse(err) => write!(f, "{err}"),
+            CheckedInverseError::IllConditioned { condition } => {
+                write!(
+                    f,
+                    "Matriz é mal condicionada (número de condição {condition}) e a inversa não é confiável"
+                )
+            }
+        }
+    }
+}
+
+impl Display for LinearSystemError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            LinearSystemError::NotSquare { rows, columns } => {
+                write!(
+                    f,
+                    "Matriz deve ser quadrada para resolver o sistema: encontrada {rows}x{columns}",
+                )
+            }
+            LinearSystemError::RhsLength { expected, found } => {
+                write!(
+                    f,
+                    "O lado direito possui tamanho incorreto: esperado {expected}, encontrado {found}"
+                )
+            }
+            LinearSystemError::Singular => {
+                write!(f, "O sistema é singular e não possui solução única")
+            }
+        }
+    }
+}
+
+impl Display for MatrixShapeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            MatrixShapeError::RowsMismatch { expected, found } => {
+                write!(
+                    f,
+                    "As matrizes possuem números de linhas diferentes: esperado {expected}, encontrado {found}"
+                )
+            }
+            MatrixShapeError::ColumnsMismatch { expected, found } => {
+                write!(
+                    f,
+                    "As matrizes possuem números de colunas diferentes: esperado {expected}, encontrado {found}"
+                )
+            }
+            MatrixShapeError::IndexOutOfBounds { index, len } => {
+                write!(f, "Índice {index} fora dos limites: tamanho {len}")
+            }
+            MatrixShapeError::InvalidPermutation { index } => {
+                write!(f, "Permutação inválida: índice {index} repetido")
+            }
+            MatrixShapeError::ZeroScaleFactor => {
+                write!(
+                    f,
+                    "Fator de escala nulo: a matriz elementar não seria invertível"
+                )
+            }
+            MatrixShapeError::SameRow { index } => {
+                write!(
+                    f,
+                    "Linha {index} somada a si mesma: origem e destino devem ser distintos"
+                )
+            }
+            MatrixShapeError::BlockOutOfBounds {
+                row,
+                col,
+                height,
+                width,
+                rows,
+                columns,
+            } => {
+                write!(
+                    f,
+                    "Bloco {height}x{width} na posição ({row}, {col}) excede a matriz {rows}x{columns}"
+                )
+            }
+        }
+    }
+}
+
+impl Display for PackedMatrixError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            PackedMatrixError::NotSquare { rows, columns } => {
+                write!(
+                    f,
+                    "Matriz deve ser quadrada para armazenamento compacto: encontrada {rows}x{columns}",
+                )
+            }
+            PackedMatrixError::OutsideStructure { row, col } => {
+                write!(
+                    f,
+                    "Entrada não nula na posição ({row}, {col}) fora da estrutura compacta"
+                )
+            }
+            PackedMatrixError::NotSymmetric { row, col } => {
+                write!(
+                    f,
+                    "Matriz não é simétrica: as entradas ({row}, {col}) e ({col}, {row}) diferem"
+                )
+            }
+        }
+    }
+}
+
+impl Display for BandedSystemError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            BandedSystemError::DiagonalLength {
+                offset,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "A diagonal de deslocamento {offset} possui tamanho incorreto: esperado {expected}, encontrado {found}"
+                )
+            }
+            BandedSystemError::RhsLength { expected, found } => {
+                write!(
+                    f,
+                    "O lado direito possui tamanho incorreto: esperado {expected}, encontrado {found}"
+                )
+            }
+            BandedSystemError::MissingMainDiagonal { lower, count } => {
+                write!(
+                    f,
+                    "A diagonal principal não está entre as {count} diagonais com {lower} subdiagonais"
+                )
+            }
+            BandedSystemError::Singular => {
+                write!(f, "O sistema é singular e não possui solução única")
+            }
+        }
+    }
+}
+
+impl Display for MatrixFunctionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            MatrixFunctionError::NotSquare { rows, columns } => {
+                write!(
+                    f,
+                    "Matriz deve ser quadrada para calcular funções de matriz: encontrada {rows}x{columns}",
+                )
+            }
+            MatrixFunctionError::Singular => {
+                write!(f, "Matriz é singular e a função não está definida")
+            }
+            MatrixFunctionError::NotConverged { iterations } => {
+                write!(
+                    f,
+                    "O método iterativo não convergiu após {iterations} iterações"
+                )
+            }
+            MatrixFunctionError::Overflow => {
+                write!(f, "Resultado não finito na precisão de saída")
+            }
+        }
+    }
+}
+
 impl Display for InterpolationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
@@ -99,6 +309,131 @@ impl Display for InterpolationError {
     }
 }
 
+impl Display for ComplexConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ComplexConversionError::NonZeroImaginary {
+                imaginary,
+                tolerance,
+            } => {
+                write!(
+                    f,
+                    "Parte imaginária ({imaginary}) excede a tolerância ({tolerance}) para conversão em número real"
+                )
+            }
+        }
+    }
+}
+
+impl Display for QuaternionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            QuaternionError::ZeroNorm => {
+                write!(
+                    f,
+                    "Quatérnio ou eixo com norma zero não pode ser normalizado"
+                )
+            }
+            QuaternionError::InvalidVectorLength { len } => {
+                write!(f, "O vetor deve ter 3 elementos: encontrado {len}")
+            }
+            QuaternionError::InvalidMatrixDimensions { rows, columns } => {
+                write!(
+                    f,
+                    "Matriz de rotação deve ser 3x3 ou 4x4: encontrada {rows}x{columns}"
+                )
+            }
+        }
+    }
+}
+
+impl Display for TransformError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            TransformError::InvalidVectorLength { len } => {
+                write!(f, "O vetor deve ter 3 elementos: encontrado {len}")
+            }
+            TransformError::ZeroAxis => {
+                write!(f, "O eixo de rotação não pode ter norma zero")
+            }
+            TransformError::DegenerateView => {
+                write!(
+                    f,
+                    "Visão degenerada: olho e alvo coincidem ou o vetor up é paralelo à direção de visão"
+                )
+            }
+            TransformError::InvalidFrustum => {
+                write!(f, "Volume de projeção inválido")
+            }
+            TransformError::InvalidHomogeneousLength { len } => {
+                write!(
+                    f,
+                    "O vetor homogêneo deve ter 4 elementos: encontrado {len}"
+                )
+            }
+            TransformError::InvalidMatrixShape { rows, columns } => {
+                write!(
+                    f,
+                    "A matriz de transformação deve ser 4x4: encontrada {rows}x{columns}"
+                )
+            }
+            TransformError::PointAtInfinity => {
+                write!(f, "O ponto está no infinito (w = 0)")
+            }
+            TransformError::SingularTransform => {
+                write!(f, "A parte linear da transformação é singular")
+            }
+            TransformError::NotAffine => {
+                write!(
+                    f,
+                    "A matriz não é afim: a última coluna deve ser [0, 0, 0, 1]"
+                )
+            }
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ParseError::UnexpectedCharacter {
+                line,
+                column,
+                found,
+            } => {
+                write!(
+                    f,
+                    "Caractere inesperado '{found}' na linha {line}, coluna {column}"
+                )
+            }
+            ParseError::UnexpectedEnd { line, column } => {
+                write!(
+                    f,
+                    "Fim inesperado do texto na linha {line}, coluna {column}"
+                )
+            }
+            ParseError::InvalidNumber { line, column, text } => {
+                write!(
+                    f,
+                    "Número inválido '{text}' na linha {line}, coluna {column}"
+                )
+            }
+            ParseError::RaggedRow {
+                line,
+                column,
+                row,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "A linha {row} da matriz tem {found} elementos, esperados {expected} (linha {line}, coluna {column})"
+                )
+            }
+        }
+    }
+}
+
 impl Display for Complex {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         let real = self.real();
@@ -127,3 +462,56 @@ impl Display for Complex {
         }
     }
 }
+
+impl<I> Display for Rational<I>
+where
+    I: Integer + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator())
+        } else {
+            write!(f, "{}/{}", self.numerator(), self.denominator())
+        }
+    }
+}
+
+impl<const P: u64> Display for ModP<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+impl<T> Display for Quaternion<T>
+where
+    T: Copy + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "{} + {}i + {}j + {}k",
+            self.w(),
+            self.x(),
+            self.y(),
+            self.z()
+        )
+    }
+}
+
+impl<T> Display for Dual<T>
+where
+    T: Copy + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{} + {}ε", self.value(), self.derivative())
+    }
+}
+
+impl<T> Display for Interval<T>
+where
+    T: DirectedRounding + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "[{}, {}]", self.lo(), self.hi())
+    }
+}
@@ -0,0 +1,373 @@
+//! # Dual Numbers
+//!
+//! This module provides dual numbers for forward-mode automatic differentiation,
+//! and a helper computing Jacobians of functions written against the generic
+//! `Vector<K>` and `Matrix<K>` APIs.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{
+    traits::{Conjugate, Float, Magnitude, Negative, One, Zero},
+    Matrix, Vector,
+};
+
+/// Represents a dual number `a + bε`, where `ε² = 0`.
+///
+/// Evaluating a function on `x + 1ε` yields `f(x) + f'(x)ε`: the `value`
+/// carries the result and the `derivative` carries the exact derivative,
+/// without finite-difference truncation errors.
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::Dual;
+///
+/// // f(x) = x² + 3x, f'(x) = 2x + 3
+/// let x = Dual::variable(2.0);
+/// let y = x * x + Dual::constant(3.0) * x;
+///
+/// assert_eq!(y.value(), 10.0);
+/// assert_eq!(y.derivative(), 7.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dual<T> {
+    value: T,
+    derivative: T,
+}
+
+impl<T> Dual<T>
+where
+    T: Copy,
+{
+    /// Creates a new dual number `value + derivative·ε`.
+    pub fn new(value: T, derivative: T) -> Self {
+        Dual { value, derivative }
+    }
+
+    /// Returns the real part (the value of the function).
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Returns the infinitesimal part (the derivative of the function).
+    pub fn derivative(&self) -> T {
+        self.derivative
+    }
+}
+
+impl<T> Dual<T>
+where
+    T: Copy + Zero + One,
+{
+    /// Creates a constant, whose derivative is zero.
+    pub fn constant(value: T) -> Self {
+        Dual::new(value, T::zero())
+    }
+
+    /// Creates the independent variable, whose derivative is one.
+    pub fn variable(value: T) -> Self {
+        Dual::new(value, T::one())
+    }
+}
+
+impl<T> Dual<T>
+where
+    T: Float,
+{
+    /// Raises the dual number to an integer power.
+    pub fn powi(&self, exponent: i32) -> Self {
+        let mut result = Self::one();
+        let mut base = if exponent < 0 {
+            Self::one() / *self
+        } else {
+            *self
+        };
+        let mut exponent = exponent.unsigned_abs();
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        result
+    }
+}
+
+/// Computes the Jacobian matrix of `f` at the point `x` by forward-mode
+/// automatic differentiation.
+///
+/// The function is evaluated once per input variable, each time seeding one
+/// coordinate with derivative one. The result is the `m×n` matrix with
+/// `J[i][j] = ∂fᵢ/∂xⱼ`, where `m` is the output length and `n = x.len()`.
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::{jacobian, Dual, Matrix, Vector};
+///
+/// // f(x, y) = [x·y, x + y²]
+/// let f = |v: &Vector<Dual<f64>>| Vector::from([v[0] * v[1], v[0] + v[1] * v[1]]);
+///
+/// let j = jacobian(f, &Vector::from([3.0, 2.0]));
+/// assert_eq!(j, Matrix::from([[2.0, 3.0], [1.0, 4.0]]));
+/// ```
+pub fn jacobian<T, F>(f: F, x: &Vector<T>) -> Matrix<T>
+where
+    T: Copy + Zero + One,
+    F: Fn(&Vector<Dual<T>>) -> Vector<Dual<T>>,
+{
+    let n = x.len();
+    if n == 0 {
+        // Nothing to seed, but the output length still sets the row count
+        let rows = f(&Vector::from(Vec::new())).len();
+        return Matrix::from_rows(vec![Vec::new(); rows]);
+    }
+
+    let mut data: Vec<Vec<T>> = Vec::new();
+
+    for j in 0..n {
+        let seeded: Vec<Dual<T>> = x
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| {
+                if i == j {
+                    Dual::variable(value)
+                } else {
+                    Dual::constant(value)
+                }
+            })
+            .collect();
+
+        let output = f(&Vector::from(seeded));
+        if j == 0 {
+            data = vec![Vec::with_capacity(n); output.len()];
+        }
+        debug_assert_eq!(
+            output.len(),
+            data.len(),
+            "Function output length must not depend on the seeded variable"
+        );
+
+        for (row, result) in data.iter_mut().zip(output.iter()) {
+            row.push(result.derivative);
+        }
+    }
+
+    Matrix::from_rows(data)
+}
+
+impl<T> From<T> for Dual<T>
+where
+    T: Copy + Zero + One,
+{
+    fn from(value: T) -> Self {
+        Dual::constant(value)
+    }
+}
+
+impl<T> Zero for Dual<T>
+where
+    T: Copy + Zero,
+{
+    fn zero() -> Self {
+        Dual::new(T::zero(), T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value.is_zero() && self.derivative.is_zero()
+    }
+
+    /// `0 + bε` has no inverse whatever `b` is, so only the value is checked.
+    fn may_be_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    fn is_negligible(&self) -> bool {
+        self.value.is_negligible()
+    }
+}
+
+impl<T> One for Dual<T>
+where
+    T: Copy + Zero + One,
+{
+    fn one() -> Self {
+        Dual::constant(T::one())
+    }
+}
+
+impl<T> Negative for Dual<T>
+where
+    T: Copy + Zero + Negative,
+{
+    fn negative_one() -> Self {
+        Dual::new(T::negative_one(), T::zero())
+    }
+}
+
+impl<T> Magnitude for Dual<T>
+where
+    T: Magnitude,
+{
+    type Output = T::Output;
+
+    /// Returns the magnitude of the value, ignoring the derivative.
+    fn magnitude(&self) -> Self::Output {
+        self.value.magnitude()
+    }
+}
+
+impl<T> Conjugate for Dual<T>
+where
+    T: Conjugate,
+{
+    fn conjugate(&self) -> Self {
+        Dual {
+            value: self.value.conjugate(),
+            derivative: self.derivative.conjugate(),
+        }
+    }
+}
+
+impl<T> Add for Dual<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Dual::new(self.value + other.value, self.derivative + other.derivative)
+    }
+}
+
+impl<T> Sub for Dual<T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Dual::new(self.value - other.value, self.derivative - other.derivative)
+    }
+}
+
+impl<T> Mul for Dual<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Dual::new(
+            self.value * other.value,
+            self.value * other.derivative + self.derivative * other.value,
+        )
+    }
+}
+
+impl<T> Div for Dual<T>
+where
+    T: Copy + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        Dual::new(
+            self.value / other.value,
+            (self.derivative * other.value - self.value * other.derivative)
+                / (other.value * other.value),
+        )
+    }
+}
+
+impl<T> Neg for Dual<T>
+where
+    T: Copy + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Dual::new(-self.value, -self.derivative)
+    }
+}
+
+impl<T> PartialOrd for Dual<T>
+where
+    T: PartialOrd,
+{
+    /// Dual numbers are ordered by their value only.
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T> Float for Dual<T>
+where
+    T: Float,
+{
+    fn from_f64(value: f64) -> Self {
+        Dual::constant(T::from_f64(value))
+    }
+
+    fn to_f64(self) -> f64 {
+        self.value.to_f64()
+    }
+
+    fn epsilon() -> Self {
+        Dual::constant(T::epsilon())
+    }
+
+    fn infinity() -> Self {
+        Dual::constant(T::infinity())
+    }
+
+    fn abs(self) -> Self {
+        if self.value < T::zero() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    fn sqrt(self) -> Self {
+        let root = self.value.sqrt();
+        Dual::new(root, self.derivative / (T::from_f64(2.0) * root))
+    }
+
+    fn sin(self) -> Self {
+        Dual::new(self.value.sin(), self.derivative * self.value.cos())
+    }
+
+    fn cos(self) -> Self {
+        Dual::new(self.value.cos(), -self.derivative * self.value.sin())
+    }
+
+    fn tan(self) -> Self {
+        let cos = self.value.cos();
+        Dual::new(self.value.tan(), self.derivative / (cos * cos))
+    }
+
+    fn acos(self) -> Self {
+        let denominator = (T::one() - self.value * self.value).sqrt();
+        Dual::new(self.value.acos(), -self.derivative / denominator)
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        let denominator = self.value * self.value + other.value * other.value;
+        Dual::new(
+            self.value.atan2(other.value),
+            (other.value * self.derivative - self.value * other.derivative) / denominator,
+        )
+    }
+
+    fn exp(self) -> Self {
+        let exp = self.value.exp();
+        Dual::new(exp, self.derivative * exp)
+    }
+
+    fn ln(self) -> Self {
+        Dual::new(self.value.ln(), self.derivative / self.value)
+    }
+}
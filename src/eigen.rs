@@ -0,0 +1,316 @@
+//! # Eigenvalues
+//!
+//! This module computes the eigenvalues of real square matrices. The matrix
+//! is reduced to upper Hessenberg form by stabilized elimination, then the
+//! Francis double-shift QR iteration deflates it one real eigenvalue or one
+//! complex conjugate pair at a time, so complex eigenvalues are found
+//! without complex arithmetic.
+
+use crate::{errors::MatrixFunctionError, traits::Float, Complex, Matrix};
+
+/// Maximum number of QR iterations spent on a single eigenvalue.
+const MAX_QR_ITERATIONS: usize = 30;
+
+/// Returns `|a|` with the sign of `b`.
+fn with_sign<K>(a: K, b: K) -> K
+where
+    K: Float,
+{
+    if b < K::zero() {
+        -a.abs()
+    } else {
+        a.abs()
+    }
+}
+
+/// Reduces `a` to upper Hessenberg form by Gaussian elimination with
+/// pivoting, preserving its eigenvalues. Indices start at 1.
+// Rows and columns are addressed together, so index loops read best
+#[allow(clippy::needless_range_loop)]
+fn reduce_to_hessenberg<K>(a: &mut [Vec<K>], n: usize)
+where
+    K: Float,
+{
+    for m in 2..n {
+        let mut pivot = K::zero();
+        let mut pivot_row = m;
+        for j in m..=n {
+            if a[j][m - 1].abs() > pivot.abs() {
+                pivot = a[j][m - 1];
+                pivot_row = j;
+            }
+        }
+        if pivot_row != m {
+            for j in (m - 1)..=n {
+                let tmp = a[pivot_row][j];
+                a[pivot_row][j] = a[m][j];
+                a[m][j] = tmp;
+            }
+            for row in a.iter_mut().skip(1) {
+                row.swap(pivot_row, m);
+            }
+        }
+        if pivot.is_zero() {
+            continue;
+        }
+
+        for i in (m + 1)..=n {
+            let y = a[i][m - 1];
+            if y.is_zero() {
+                continue;
+            }
+            let y = y / pivot;
+            a[i][m - 1] = K::zero();
+            for j in m..=n {
+                a[i][j] = a[i][j] - y * a[m][j];
+            }
+            for j in 1..=n {
+                a[j][m] = a[j][m] + y * a[j][i];
+            }
+        }
+    }
+}
+
+/// Finds all eigenvalues of the upper Hessenberg matrix `a` with the
+/// Francis double-shift QR iteration, destroying `a`. Indices start at 1.
+#[allow(clippy::needless_range_loop)]
+fn hessenberg_eigenvalues<K>(a: &mut [Vec<K>], n: usize) -> Result<Vec<(K, K)>, MatrixFunctionError>
+where
+    K: Float,
+{
+    let (zero, two) = (K::zero(), K::from_f64(2.0));
+    let mut values = vec![(zero, zero); n + 1];
+
+    let mut norm = zero;
+    for i in 1..=n {
+        for j in i.max(2) - 1..=n {
+            norm = norm + a[i][j].abs();
+        }
+    }
+
+    let mut nn = n;
+    // Accumulated exceptional shifts
+    let mut shift = zero;
+    while nn >= 1 {
+        let mut iterations = 0;
+        loop {
+            // Look for a negligible subdiagonal element to split the matrix
+            let mut l = nn;
+            while l >= 2 {
+                let mut s = a[l - 1][l - 1].abs() + a[l][l].abs();
+                if s.is_zero() {
+                    s = norm;
+                }
+                if a[l][l - 1].abs() + s == s {
+                    a[l][l - 1] = zero;
+                    break;
+                }
+                l -= 1;
+            }
+
+            let mut x = a[nn][nn];
+            if l == nn {
+                // One real root found
+                values[nn] = (x + shift, zero);
+                nn -= 1;
+                break;
+            }
+
+            let mut y = a[nn - 1][nn - 1];
+            let mut w = a[nn][nn - 1] * a[nn - 1][nn];
+            if l == nn - 1 {
+                // Two roots found, from the trailing 2×2 block
+                let p = (y - x) / two;
+                let q = p * p + w;
+                let z = q.abs().sqrt();
+                x = x + shift;
+                if q >= zero {
+                    let z = p + with_sign(z, p);
+                    values[nn - 1] = (x + z, zero);
+                    values[nn] = if z.is_zero() {
+                        (x + z, zero)
+                    } else {
+                        (x - w / z, zero)
+                    };
+                } else {
+                    values[nn - 1] = (x + p, -z);
+                    values[nn] = (x + p, z);
+                }
+                nn -= 2;
+                break;
+            }
+
+            if iterations == MAX_QR_ITERATIONS {
+                return Err(MatrixFunctionError::NotConverged { iterations });
+            }
+            if iterations == 10 || iterations == 20 {
+                // Exceptional shift to break cycles
+                shift = shift + x;
+                for i in 1..=nn {
+                    a[i][i] = a[i][i] - x;
+                }
+                let s = a[nn][nn - 1].abs() + a[nn - 1][nn - 2].abs();
+                x = K::from_f64(0.75) * s;
+                y = x;
+                w = K::from_f64(-0.4375) * s * s;
+            }
+            iterations += 1;
+
+            // Look for two consecutive small subdiagonal elements
+            let (mut p, mut q, mut r);
+            let mut m = nn - 2;
+            loop {
+                let z = a[m][m];
+                let rr = x - z;
+                let ss = y - z;
+                p = (rr * ss - w) / a[m + 1][m] + a[m][m + 1];
+                q = a[m + 1][m + 1] - z - rr - ss;
+                r = a[m + 2][m + 1];
+                let s = p.abs() + q.abs() + r.abs();
+                p = p / s;
+                q = q / s;
+                r = r / s;
+                if m == l {
+                    break;
+                }
+                let u = a[m][m - 1].abs() * (q.abs() + r.abs());
+                let v = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+                if u + v == v {
+                    break;
+                }
+                m -= 1;
+            }
+
+            for i in (m + 2)..=nn {
+                a[i][i - 2] = zero;
+                if i != m + 2 {
+                    a[i][i - 3] = zero;
+                }
+            }
+
+            // Double QR step on rows l..nn and columns m..nn
+            for k in m..nn {
+                if k != m {
+                    p = a[k][k - 1];
+                    q = a[k + 1][k - 1];
+                    r = if k != nn - 1 { a[k + 2][k - 1] } else { zero };
+                    x = p.abs() + q.abs() + r.abs();
+                    if !x.is_zero() {
+                        p = p / x;
+                        q = q / x;
+                        r = r / x;
+                    }
+                }
+
+                let s = with_sign((p * p + q * q + r * r).sqrt(), p);
+                if s.is_zero() {
+                    continue;
+                }
+                if k == m {
+                    if l != m {
+                        a[k][k - 1] = -a[k][k - 1];
+                    }
+                } else {
+                    a[k][k - 1] = -s * x;
+                }
+                p = p + s;
+                x = p / s;
+                y = q / s;
+                let z = r / s;
+                q = q / p;
+                r = r / p;
+
+                for j in k..=nn {
+                    let mut p = a[k][j] + q * a[k + 1][j];
+                    if k != nn - 1 {
+                        p = p + r * a[k + 2][j];
+                        a[k + 2][j] = a[k + 2][j] - p * z;
+                    }
+                    a[k + 1][j] = a[k + 1][j] - p * y;
+                    a[k][j] = a[k][j] - p * x;
+                }
+
+                for i in l..=nn.min(k + 3) {
+                    let mut p = x * a[i][k] + y * a[i][k + 1];
+                    if k != nn - 1 {
+                        p = p + z * a[i][k + 2];
+                        a[i][k + 2] = a[i][k + 2] - p * r;
+                    }
+                    a[i][k + 1] = a[i][k + 1] - p * q;
+                    a[i][k] = a[i][k] - p;
+                }
+            }
+        }
+    }
+
+    Ok(values.split_off(1))
+}
+
+impl<K> Matrix<K>
+where
+    K: Float,
+{
+    /// Computes the eigenvalues of a real square matrix, including complex
+    /// conjugate pairs.
+    ///
+    /// Eigenvalues are sorted by descending real part, and the member of a
+    /// conjugate pair with positive imaginary part comes first. Repeated
+    /// eigenvalues appear with their algebraic multiplicity. They are
+    /// computed in `K` and then rounded to `f32`, the precision of the
+    /// crate's `Complex`, so `f64` input loses precision in the result.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixFunctionError::NotSquare` - If the matrix is not square
+    /// * `MatrixFunctionError::NotConverged` - If the QR iteration stalls on
+    ///   an eigenvalue, which is rare outside of non-finite entries
+    /// * `MatrixFunctionError::Overflow` - If an eigenvalue is not finite once
+    ///   rounded to `f32`, as with entries beyond the `f32` range
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::{Complex, Matrix};
+    ///
+    /// // A quarter turn has eigenvalues ±i
+    /// let rotation = Matrix::from([[0.0, -1.0], [1.0, 0.0]]);
+    ///
+    /// assert_eq!(
+    ///     rotation.eigenvalues().unwrap(),
+    ///     vec![Complex::new(0.0, 1.0), Complex::new(0.0, -1.0)]
+    /// );
+    /// ```
+    pub fn eigenvalues(&self) -> Result<Vec<Complex>, MatrixFunctionError> {
+        let (rows, columns) = self.shape();
+        if !self.is_square() {
+            return Err(MatrixFunctionError::NotSquare { rows, columns });
+        }
+
+        // Indices start at 1 in the iteration, as in its classic statement
+        let n = rows;
+        let mut a = vec![vec![K::zero(); n + 1]; n + 1];
+        for (i, row) in self.iter().enumerate() {
+            a[i + 1][1..].copy_from_slice(row);
+        }
+
+        reduce_to_hessenberg(&mut a, n);
+        let mut values = hessenberg_eigenvalues(&mut a, n)?;
+
+        values.sort_by(|x, y| {
+            y.0.partial_cmp(&x.0)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(y.1.partial_cmp(&x.1).unwrap_or(std::cmp::Ordering::Equal))
+        });
+        values
+            .into_iter()
+            .map(|(re, im)| {
+                let value = Complex::new(re.to_f64() as f32, im.to_f64() as f32);
+                if value.real().is_finite() && value.imaginary().is_finite() {
+                    Ok(value)
+                } else {
+                    Err(MatrixFunctionError::Overflow)
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,456 @@
+//! # Traced Gaussian Elimination
+//!
+//! This module provides variants of `row_echelon`, `inverse` and `determinant`
+//! that record every elementary row operation they perform, together with the
+//! matrix before and after it. The resulting trace can be printed with
+//! `Display` or rendered as LaTeX, to show step by step how a result is derived.
+
+use std::fmt::{Display, Write};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{
+    errors::{MatrixInverseError, MatrixShapeError},
+    matrix::find_pivot,
+    traits::{Magnitude, Negative, One, Zero},
+    Matrix,
+};
+
+/// An elementary row operation.
+///
+/// Row indices are zero-based; they are displayed one-based (`L1`, `L2`, …)
+/// as is customary on paper.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RowOperation<K> {
+    /// Exchange rows `i` and `j`.
+    Swap {
+        /// First row
+        i: usize,
+        /// Second row
+        j: usize,
+    },
+    /// Multiply a row by a non-zero factor.
+    Scale {
+        /// Row being scaled
+        row: usize,
+        /// Scaling factor
+        factor: K,
+    },
+    /// Add a multiple of the `source` row to the `target` row.
+    AddMultiple {
+        /// Row being modified
+        target: usize,
+        /// Row whose multiple is added
+        source: usize,
+        /// Multiplier applied to the source row
+        factor: K,
+    },
+}
+
+impl<K> RowOperation<K>
+where
+    K: Copy + Add<Output = K> + Mul<Output = K>,
+{
+    /// Applies the operation to `matrix` in place.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::IndexOutOfBounds` - If a row index is not a row of `matrix`
+    pub fn apply(&self, matrix: &mut Matrix<K>) -> Result<(), MatrixShapeError> {
+        match *self {
+            RowOperation::Swap { i, j } => matrix.swap_rows(i, j),
+            RowOperation::Scale { row, factor } => matrix.scale_row(row, factor),
+            RowOperation::AddMultiple {
+                target,
+                source,
+                factor,
+            } => matrix.add_row_multiple(target, source, factor),
+        }
+    }
+}
+
+impl<K> RowOperation<K>
+where
+    K: Display,
+{
+    /// Renders the operation in LaTeX, e.g. `L_{2} \leftarrow L_{2} + (3) L_{1}`.
+    pub fn to_latex(&self) -> String {
+        match self {
+            RowOperation::Swap { i, j } => {
+                format!("L_{{{}}} \\leftrightarrow L_{{{}}}", i + 1, j + 1)
+            }
+            RowOperation::Scale { row, factor } => {
+                format!("L_{{{0}}} \\leftarrow ({factor}) L_{{{0}}}", row + 1)
+            }
+            RowOperation::AddMultiple {
+                target,
+                source,
+                factor,
+            } => format!(
+                "L_{{{0}}} \\leftarrow L_{{{0}}} + ({factor}) L_{{{1}}}",
+                target + 1,
+                source + 1
+            ),
+        }
+    }
+}
+
+/// One recorded row operation with the matrix before and after it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EliminationStep<K> {
+    operation: RowOperation<K>,
+    before: Matrix<K>,
+    after: Matrix<K>,
+}
+
+impl<K> EliminationStep<K> {
+    /// Returns the row operation performed in this step.
+    pub fn operation(&self) -> &RowOperation<K> {
+        &self.operation
+    }
+
+    /// Returns the matrix before the operation.
+    pub fn before(&self) -> &Matrix<K> {
+        &self.before
+    }
+
+    /// Returns the matrix after the operation.
+    pub fn after(&self) -> &Matrix<K> {
+        &self.after
+    }
+}
+
+/// The sequence of row operations performed by a traced elimination.
+///
+/// For `inverse_traced` the operations act on the augmented matrix `[A | I]`;
+/// `augmented_columns` then reports where the bar separating both halves lies.
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::{Matrix, RowOperation};
+///
+/// let m = Matrix::from([[0.0, 2.0], [1.0, 1.0]]);
+/// let (echelon, trace) = m.row_echelon_traced();
+///
+/// assert_eq!(echelon, m.row_echelon());
+/// assert_eq!(trace.steps()[0].operation(), &RowOperation::Swap { i: 0, j: 1 });
+/// assert_eq!(trace.result(), &echelon);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct EliminationTrace<K> {
+    initial: Matrix<K>,
+    steps: Vec<EliminationStep<K>>,
+    augmented_columns: Option<usize>,
+}
+
+impl<K> EliminationTrace<K> {
+    /// Returns the matrix the elimination started from.
+    pub fn initial(&self) -> &Matrix<K> {
+        &self.initial
+    }
+
+    /// Returns the recorded steps, in order.
+    pub fn steps(&self) -> &[EliminationStep<K>] {
+        &self.steps
+    }
+
+    /// Returns the matrix after the last step.
+    pub fn result(&self) -> &Matrix<K> {
+        self.steps.last().map_or(&self.initial, |step| &step.after)
+    }
+
+    /// Returns the number of columns left of the augmentation bar, if the
+    /// trace acts on an augmented matrix.
+    pub fn augmented_columns(&self) -> Option<usize> {
+        self.augmented_columns
+    }
+
+    /// Returns the number of recorded steps.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Checks if no row operation was needed.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+impl<K> EliminationTrace<K>
+where
+    K: Copy + Add<Output = K> + Mul<Output = K>,
+{
+    fn new(initial: Matrix<K>, augmented_columns: Option<usize>) -> Self {
+        EliminationTrace {
+            initial,
+            steps: Vec::new(),
+            augmented_columns,
+        }
+    }
+
+    /// Applies `operation` to the current matrix and records it.
+    fn record(&mut self, operation: RowOperation<K>) {
+        let before = self.result().clone();
+        let mut after = before.clone();
+        operation
+            .apply(&mut after)
+            .expect("Elimination only addresses existing rows");
+
+        self.steps.push(EliminationStep {
+            operation,
+            before,
+            after,
+        });
+    }
+}
+
+impl<K> EliminationTrace<K>
+where
+    K: Display,
+{
+    /// Renders the whole trace as a LaTeX `aligned` environment, one arrow
+    /// labelled with the row operation per step.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let (det, trace) = Matrix::from([[2.0, 4.0], [1.0, 3.0]]).determinant_traced();
+    /// let latex = trace.to_latex();
+    ///
+    /// assert_eq!(det, 2.0);
+    /// assert!(latex.starts_with("\\begin{aligned}"));
+    /// assert!(latex.contains("\\xrightarrow{L_{2} \\leftarrow L_{2} + (-0.5) L_{1}}"));
+    /// ```
+    pub fn to_latex(&self) -> String {
+        let mut latex = String::from("\\begin{aligned}\n");
+        let _ = writeln!(
+            latex,
+            "& {}",
+            matrix_to_latex(&self.initial, self.augmented_columns)
+        );
+        for step in &self.steps {
+            let _ = writeln!(
+                latex,
+                "\\\\ \\xrightarrow{{{}}} & {}",
+                step.operation.to_latex(),
+                matrix_to_latex(&step.after, self.augmented_columns)
+            );
+        }
+        latex.push_str("\\end{aligned}");
+        latex
+    }
+}
+
+/// Renders a matrix as a LaTeX bracketed array, with a vertical bar before
+/// column `split` when given.
+fn matrix_to_latex<K>(matrix: &Matrix<K>, split: Option<usize>) -> String
+where
+    K: Display,
+{
+    let mut spec = String::new();
+    for col in 0..matrix.columns() {
+        if split == Some(col) {
+            spec.push('|');
+        }
+        spec.push('c');
+    }
+
+    let rows: Vec<String> = matrix
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|val| val.to_string())
+                .collect::<Vec<_>>()
+                .join(" & ")
+        })
+        .collect();
+
+    format!(
+        "\\left[\\begin{{array}}{{{spec}}} {} \\end{{array}}\\right]",
+        rows.join(" \\\\ ")
+    )
+}
+
+impl<K> Matrix<K>
+where
+    K: Copy
+        + PartialEq
+        + Zero
+        + One
+        + Add<Output = K>
+        + Sub<Output = K>
+        + Mul<Output = K>
+        + Div<Output = K>
+        + Neg<Output = K>
+        + Magnitude<Output = f32>,
+{
+    /// Computes the reduced row echelon form like `row_echelon`, recording
+    /// every row operation.
+    pub fn row_echelon_traced(&self) -> (Self, EliminationTrace<K>) {
+        let mut trace = EliminationTrace::new(self.clone(), None);
+        gauss_jordan(&mut trace, self.columns());
+
+        (trace.result().clone(), trace)
+    }
+
+    /// Inverts the matrix like `inverse`, recording every row operation
+    /// performed on the augmented matrix `[A | I]`.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixInverseError::NotSquare` - If the matrix is not square
+    /// * `MatrixInverseError::Singular` - If the matrix is singular
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::{Matrix, Rational};
+    ///
+    /// let r = |n| Rational::from_integer(n);
+    /// let m = Matrix::from([[r(2), r(1)], [r(1), r(1)]]);
+    ///
+    /// let (inverse, trace) = m.inverse_traced().unwrap();
+    ///
+    /// assert_eq!(inverse, Matrix::from([[r(1), r(-1)], [r(-1), r(2)]]));
+    /// assert_eq!(trace.augmented_columns(), Some(2));
+    /// ```
+    pub fn inverse_traced(&self) -> Result<(Self, EliminationTrace<K>), MatrixInverseError> {
+        if !self.is_square() {
+            return Err(MatrixInverseError::NotSquare {
+                rows: self.rows(),
+                columns: self.columns(),
+            });
+        }
+
+        let n = self.rows();
+        let augmented = Matrix::hstack(&[self, &Matrix::identity(n)])
+            .expect("Identity has as many rows as a square matrix");
+        let mut trace = EliminationTrace::new(augmented, Some(n));
+
+        let pivots = gauss_jordan(&mut trace, n);
+        if pivots < n {
+            return Err(MatrixInverseError::Singular);
+        }
+
+        let (_, inverse) = trace
+            .result()
+            .split_at_col(n)
+            .expect("Augmented matrix has 2n columns");
+        Ok((inverse, trace))
+    }
+
+    /// Computes the determinant by reduction to upper triangular form,
+    /// recording every row operation.
+    ///
+    /// Only row exchanges, which flip the sign, and additions of multiples of
+    /// rows, which preserve the determinant, are used. The determinant is the
+    /// product of the diagonal of the final matrix, negated once per exchange.
+    /// Non-square matrices yield zero, like `determinant`.
+    pub fn determinant_traced(&self) -> (K, EliminationTrace<K>)
+    where
+        K: Negative,
+    {
+        let mut trace = EliminationTrace::new(self.clone(), None);
+        if !self.is_square() {
+            return (K::zero(), trace);
+        }
+
+        let n = self.rows();
+        let mut sign = K::one();
+        for col in 0..n {
+            let Some(pivot_row) = find_pivot(trace.result().iter().map(|r| r[col]), col) else {
+                return (K::zero(), trace);
+            };
+            if pivot_row != col {
+                trace.record(RowOperation::Swap {
+                    i: col,
+                    j: pivot_row,
+                });
+                sign = sign * K::negative_one();
+            }
+
+            eliminate_below(&mut trace, col, col);
+        }
+
+        let result = trace.result();
+        let det = (0..n).fold(sign, |acc, i| acc * result[i][i]);
+        (det, trace)
+    }
+}
+
+/// Reduces the first `pivot_columns` columns of the traced matrix to reduced
+/// row echelon form, with the same pivoting as `row_echelon`, and returns the
+/// number of pivots found.
+fn gauss_jordan<K>(trace: &mut EliminationTrace<K>, pivot_columns: usize) -> usize
+where
+    K: Copy
+        + PartialEq
+        + Zero
+        + One
+        + Add<Output = K>
+        + Mul<Output = K>
+        + Div<Output = K>
+        + Neg<Output = K>
+        + Magnitude<Output = f32>,
+{
+    let rows = trace.result().rows();
+    let mut row = 0;
+
+    for col in 0..pivot_columns {
+        if row >= rows {
+            break;
+        }
+
+        let Some(pivot_row) = find_pivot(trace.result().iter().map(|r| r[col]), row) else {
+            continue;
+        };
+        if pivot_row != row {
+            trace.record(RowOperation::Swap {
+                i: row,
+                j: pivot_row,
+            });
+        }
+
+        let pivot = trace.result()[row][col];
+        if pivot != K::one() {
+            trace.record(RowOperation::Scale {
+                row,
+                factor: K::one() / pivot,
+            });
+        }
+
+        for target in 0..rows {
+            let factor = trace.result()[target][col];
+            if target != row && !factor.is_zero() {
+                trace.record(RowOperation::AddMultiple {
+                    target,
+                    source: row,
+                    factor: -factor,
+                });
+            }
+        }
+
+        row += 1;
+    }
+    row
+}
+
+/// Clears the entries below the pivot at `(row, col)`.
+fn eliminate_below<K>(trace: &mut EliminationTrace<K>, row: usize, col: usize)
+where
+    K: Copy + Zero + Add<Output = K> + Mul<Output = K> + Div<Output = K> + Neg<Output = K>,
+{
+    let pivot = trace.result()[row][col];
+    for target in (row + 1)..trace.result().rows() {
+        let entry = trace.result()[target][col];
+        if !entry.is_zero() {
+            trace.record(RowOperation::AddMultiple {
+                target,
+                source: row,
+                factor: -(entry / pivot),
+            });
+        }
+    }
+}
@@ -65,3 +65,379 @@ pub enum MatrixInverseError {
 }
 
 impl Error for MatrixInverseError {}
+
+/// Errors related to inversion with a conditioning check.
+///
+/// This error type occurs when `Matrix::inverse_checked` cannot invert a
+/// matrix, or when the inverse exists but is not reliable.
+#[derive(Debug)]
+pub enum CheckedInverseError {
+    /// Error when the matrix has no inverse.
+    Inverse(MatrixInverseError),
+    /// Error when the matrix is too ill-conditioned to invert reliably.
+    ///
+    /// The inverse exists but rounding errors may dominate the result.
+    IllConditioned {
+        /// The 1-norm condition number of the matrix
+        condition: f32,
+    },
+}
+
+impl Error for CheckedInverseError {}
+
+impl From<MatrixInverseError> for CheckedInverseError {
+    fn from(err: MatrixInverseError) -> Self {
+        CheckedInverseError::Inverse(err)
+    }
+}
+
+/// Errors related to solving dense linear systems.
+///
+/// This error type occurs when `Matrix::solve` is given a system that is not
+/// square, a right-hand side of the wrong length, or a singular matrix.
+#[derive(Debug)]
+pub enum LinearSystemError {
+    /// Error when the coefficient matrix is not square.
+    NotSquare {
+        /// Number of rows in the matrix
+        rows: usize,
+        /// Number of columns in the matrix
+        columns: usize,
+    },
+    /// Error when the right-hand side does not match the system size.
+    RhsLength {
+        /// Expected number of entries
+        expected: usize,
+        /// Found number of entries
+        found: usize,
+    },
+    /// Error when the system is singular and has no unique solution.
+    Singular,
+}
+
+impl Error for LinearSystemError {}
+
+/// Errors related to matrix shapes and indices.
+///
+/// This error type occurs when blocks cannot be assembled because their
+/// dimensions do not line up, or when an index or block lies outside a matrix.
+#[derive(Debug)]
+pub enum MatrixShapeError {
+    /// Error when matrices placed side by side differ in height.
+    RowsMismatch {
+        /// Expected number of rows
+        expected: usize,
+        /// Found number of rows
+        found: usize,
+    },
+    /// Error when matrices stacked on top of each other differ in width.
+    ColumnsMismatch {
+        /// Expected number of columns
+        expected: usize,
+        /// Found number of columns
+        found: usize,
+    },
+    /// Error when a row or column index lies outside the matrix.
+    IndexOutOfBounds {
+        /// The invalid index
+        index: usize,
+        /// Number of rows or columns available
+        len: usize,
+    },
+    /// Error when an index list is not a permutation.
+    ///
+    /// Every index from `0` to `len - 1` must appear exactly once.
+    InvalidPermutation {
+        /// The index that appears more than once
+        index: usize,
+    },
+    /// Error when an elementary scaling matrix is built with a zero factor.
+    ///
+    /// Elementary matrices are invertible, and scaling a row by zero is not.
+    ZeroScaleFactor,
+    /// Error when a multiple of a row would be added to the row itself.
+    ///
+    /// That only scales the row, and by `1 + factor`, which may be zero.
+    SameRow {
+        /// The row given as both target and source
+        index: usize,
+    },
+    /// Error when a requested block extends past the matrix edges.
+    BlockOutOfBounds {
+        /// Row of the top-left entry of the block
+        row: usize,
+        /// Column of the top-left entry of the block
+        col: usize,
+        /// Number of rows of the block
+        height: usize,
+        /// Number of columns of the block
+        width: usize,
+        /// Number of rows in the matrix
+        rows: usize,
+        /// Number of columns in the matrix
+        columns: usize,
+    },
+}
+
+impl Error for MatrixShapeError {}
+
+/// Errors related to packed matrix storage.
+///
+/// This error type occurs when a dense matrix is converted into a packed type
+/// whose zero pattern or symmetry the matrix does not have.
+#[derive(Debug)]
+pub enum PackedMatrixError {
+    /// Error when the matrix is not square.
+    NotSquare {
+        /// Number of rows in the matrix
+        rows: usize,
+        /// Number of columns in the matrix
+        columns: usize,
+    },
+    /// Error when an entry the packed type cannot store is non-zero.
+    OutsideStructure {
+        /// Row of the offending entry
+        row: usize,
+        /// Column of the offending entry
+        col: usize,
+    },
+    /// Error when an entry differs from its mirror across the diagonal.
+    NotSymmetric {
+        /// Row of the offending entry
+        row: usize,
+        /// Column of the offending entry
+        col: usize,
+    },
+}
+
+impl Error for PackedMatrixError {}
+
+/// Errors related to tridiagonal and banded linear systems.
+///
+/// This error type occurs when the diagonals passed to a banded solver do not
+/// describe a square system, or when the system has no unique solution.
+#[derive(Debug)]
+pub enum BandedSystemError {
+    /// Error when a diagonal has the wrong number of entries.
+    DiagonalLength {
+        /// Offset of the diagonal, negative below the main diagonal
+        offset: isize,
+        /// Expected number of entries
+        expected: usize,
+        /// Found number of entries
+        found: usize,
+    },
+    /// Error when the right-hand side does not match the system size.
+    RhsLength {
+        /// Expected number of entries
+        expected: usize,
+        /// Found number of entries
+        found: usize,
+    },
+    /// Error when the main diagonal is not among the given diagonals.
+    ///
+    /// Occurs when the number of subdiagonals is not smaller than the number
+    /// of diagonals.
+    MissingMainDiagonal {
+        /// Number of subdiagonals requested
+        lower: usize,
+        /// Number of diagonals given
+        count: usize,
+    },
+    /// Error when the system is singular and has no unique solution.
+    Singular,
+}
+
+impl Error for BandedSystemError {}
+
+/// Errors related to matrix functions.
+///
+/// This error type occurs when the exponential, logarithm, square root or
+/// a power of a matrix cannot be computed.
+#[derive(Debug)]
+pub enum MatrixFunctionError {
+    /// Error when the matrix is not square.
+    ///
+    /// Matrix functions are only defined for square matrices.
+    NotSquare {
+        /// Number of rows in the matrix
+        rows: usize,
+        /// Number of columns in the matrix
+        columns: usize,
+    },
+    /// Error when a matrix that must be inverted is singular.
+    ///
+    /// The logarithm, square root and negative powers of a singular matrix
+    /// are not defined.
+    Singular,
+    /// Error when an iterative method does not converge.
+    ///
+    /// This happens for real matrices with eigenvalues on the negative real
+    /// axis, which have no real principal square root or logarithm.
+    NotConverged {
+        /// Number of iterations performed
+        iterations: usize,
+    },
+    /// Error when a result is not finite in the output type.
+    ///
+    /// Eigenvalues are returned in single precision, so `f64` entries far
+    /// outside the `f32` range give infinite or undefined values.
+    Overflow,
+}
+
+impl Error for MatrixFunctionError {}
+
+/// Errors related to complex number conversions.
+///
+/// This error type occurs when a complex number cannot be represented
+/// as a real number without losing information.
+#[derive(Debug)]
+pub enum ComplexConversionError {
+    /// Error when the imaginary part is larger than the allowed tolerance.
+    ///
+    /// Converting such a value to a real number would silently drop
+    /// its imaginary component.
+    NonZeroImaginary {
+        /// The imaginary part of the complex number
+        imaginary: f32,
+        /// The tolerance used for the conversion
+        tolerance: f32,
+    },
+}
+
+impl Error for ComplexConversionError {}
+
+/// Errors related to quaternion operations.
+///
+/// This error type occurs when a quaternion cannot be normalized or
+/// inverted, or when a conversion receives incompatible input.
+#[derive(Debug)]
+pub enum QuaternionError {
+    /// Error when a quaternion or axis has zero length.
+    ///
+    /// The zero quaternion cannot be normalized or inverted, and the
+    /// zero vector does not define a rotation axis.
+    ZeroNorm,
+    /// Error when a vector is not three-dimensional.
+    InvalidVectorLength {
+        /// Number of elements in the vector provided
+        len: usize,
+    },
+    /// Error when a matrix is neither 3×3 nor 4×4.
+    InvalidMatrixDimensions {
+        /// Number of rows in the matrix
+        rows: usize,
+        /// Number of columns in the matrix
+        columns: usize,
+    },
+}
+
+impl Error for QuaternionError {}
+
+/// Errors related to 3D transform matrices.
+///
+/// This error type occurs when the parameters of a rotation, view or
+/// projection matrix do not describe a valid transform.
+#[derive(Debug)]
+pub enum TransformError {
+    /// Error when a vector is not three-dimensional.
+    InvalidVectorLength {
+        /// Length of the given vector
+        len: usize,
+    },
+    /// Error when a rotation axis has zero length.
+    ZeroAxis,
+    /// Error when the eye and target of a view coincide, or the up vector is
+    /// parallel to the viewing direction.
+    DegenerateView,
+    /// Error when the projection volume is empty or inverted.
+    ///
+    /// Occurs for non-positive near planes or aspect ratios, a far plane not
+    /// beyond the near plane, or a field of view outside `(0, π)`.
+    InvalidFrustum,
+    /// Error when a vector in homogeneous coordinates is not four-dimensional.
+    InvalidHomogeneousLength {
+        /// Length of the given vector
+        len: usize,
+    },
+    /// Error when a transform matrix is not 4×4.
+    InvalidMatrixShape {
+        /// Number of rows in the matrix
+        rows: usize,
+        /// Number of columns in the matrix
+        columns: usize,
+    },
+    /// Error when a homogeneous point has `w = 0` and cannot be divided back
+    /// into three dimensions.
+    PointAtInfinity,
+    /// Error when the linear part of a transform is singular, so normals
+    /// have no inverse-transpose.
+    SingularTransform,
+    /// Error when a 4×4 matrix is not affine.
+    ///
+    /// In the crate's row-vector layout the affine row `[0, 0, 0, 1]` of the
+    /// textbook form is the last column.
+    NotAffine,
+}
+
+impl Error for TransformError {}
+
+/// Errors related to parsing vectors, matrices and complex numbers from text.
+///
+/// Every variant carries the 1-based line and column where the problem was
+/// found, counted in characters.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// Error when a character cannot appear at its position.
+    UnexpectedCharacter {
+        /// Line of the character
+        line: usize,
+        /// Column of the character
+        column: usize,
+        /// The character found
+        found: char,
+    },
+    /// Error when the text ends before the value is complete.
+    UnexpectedEnd {
+        /// Line where the text ends
+        line: usize,
+        /// Column where the text ends
+        column: usize,
+    },
+    /// Error when an entry cannot be parsed as the element type.
+    InvalidNumber {
+        /// Line of the entry
+        line: usize,
+        /// Column where the entry starts
+        column: usize,
+        /// The text of the entry
+        text: String,
+    },
+    /// Error when a matrix row has a different length than the first row.
+    RaggedRow {
+        /// Line where the row starts
+        line: usize,
+        /// Column where the row starts
+        column: usize,
+        /// Index of the row, starting at 1
+        row: usize,
+        /// Number of entries in the first row
+        expected: usize,
+        /// Number of entries in this row
+        found: usize,
+    },
+}
+
+impl ParseError {
+    /// Returns the 1-based `(line, column)` where the error was found.
+    pub fn position(&self) -> (usize, usize) {
+        match *self {
+            ParseError::UnexpectedCharacter { line, column, .. }
+            | ParseError::UnexpectedEnd { line, column }
+            | ParseError::InvalidNumber { line, column, .. }
+            | ParseError::RaggedRow { line, column, .. } => (line, column),
+        }
+    }
+}
+
+impl Error for ParseError {}
@@ -0,0 +1,277 @@
+//! # Prime Fields
+//!
+//! This module provides arithmetic modulo a prime number, the finite field GF(p),
+//! allowing exact linear algebra for coding theory and cryptographic test vectors.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::traits::{Conjugate, Magnitude, Negative, One, Zero};
+
+/// Represents an element of the finite field GF(P) for a prime modulus `P`.
+///
+/// Values are always stored reduced to the range `0..P`. Every non-zero element
+/// has a multiplicative inverse, so `Matrix::inverse`, `rank`, `row_echelon` and
+/// `null_space` work exactly modulo the prime.
+///
+/// The primality of `P` is verified at compile time: using a composite modulus
+/// fails to build.
+///
+/// ```compile_fail
+/// use linear_algebra_42::ModP;
+///
+/// let x = ModP::<8>::new(3);
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::{Matrix, ModP};
+///
+/// type F7 = ModP<7>;
+///
+/// let a = F7::new(3);
+/// let b = F7::new(5);
+/// assert_eq!(a + b, F7::new(1));
+/// assert_eq!(a * b, F7::new(1));
+/// assert_eq!(a.inverse(), Some(F7::new(5)));
+///
+/// let m = Matrix::from([[F7::new(1), F7::new(2)], [F7::new(3), F7::new(4)]]);
+/// let inv = m.inverse().unwrap();
+/// assert_eq!(m.mul_mat(&inv), Matrix::from([[F7::new(1), F7::new(0)], [F7::new(0), F7::new(1)]]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModP<const P: u64> {
+    value: u64,
+}
+
+impl<const P: u64> ModP<P> {
+    const PRIME_CHECK: () = assert!(is_prime(P), "ModP modulus must be a prime number");
+
+    /// Creates a new field element, reducing `value` modulo `P`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::ModP;
+    ///
+    /// assert_eq!(ModP::<5>::new(12).value(), 2);
+    /// ```
+    pub fn new(value: u64) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::PRIME_CHECK;
+
+        ModP { value: value % P }
+    }
+
+    /// Creates a field element from a signed integer, mapping negatives to `P - |value|`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::ModP;
+    ///
+    /// assert_eq!(ModP::<5>::from_i64(-1).value(), 4);
+    /// ```
+    pub fn from_i64(value: i64) -> Self {
+        let reduced = (value as i128).rem_euclid(P as i128);
+        Self::new(reduced as u64)
+    }
+
+    /// Returns the canonical representative of the element, in the range `0..P`.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Returns the modulus `P` of the field.
+    pub fn modulus() -> u64 {
+        P
+    }
+
+    /// Raises the element to the power `exponent` by repeated squaring.
+    pub fn pow(&self, exponent: u64) -> Self {
+        let mut result = Self::one();
+        let mut base = *self;
+        let mut exponent = exponent;
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// Returns the multiplicative inverse, or `None` for the zero element.
+    ///
+    /// Uses Fermat's little theorem: `a^(P-2) = a^-1 (mod P)`.
+    pub fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+
+        Some(self.pow(P - 2))
+    }
+}
+
+/// Deterministic Miller–Rabin primality test valid for every `u64`.
+const fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    let mut i = 0;
+    while i < BASES.len() {
+        if n == BASES[i] {
+            return true;
+        }
+        if n % BASES[i] == 0 {
+            return false;
+        }
+        i += 1;
+    }
+
+    let mut d = n - 1;
+    let mut s = 0;
+    while d % 2 == 0 {
+        d /= 2;
+        s += 1;
+    }
+
+    let mut i = 0;
+    while i < BASES.len() {
+        let mut x = pow_mod(BASES[i], d, n);
+        if x != 1 && x != n - 1 {
+            let mut r = 1;
+            while r < s && x != n - 1 {
+                x = mul_mod(x, x, n);
+                r += 1;
+            }
+            if x != n - 1 {
+                return false;
+            }
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+const fn pow_mod(base: u64, exponent: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    let mut base = base % m;
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exponent >>= 1;
+    }
+    result
+}
+
+impl<const P: u64> From<u64> for ModP<P> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const P: u64> Zero for ModP<P> {
+    fn zero() -> Self {
+        Self::new(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+}
+
+impl<const P: u64> One for ModP<P> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<const P: u64> Negative for ModP<P> {
+    fn negative_one() -> Self {
+        Self::new(P - 1)
+    }
+}
+
+impl<const P: u64> Magnitude for ModP<P> {
+    type Output = f32;
+
+    /// Returns the canonical representative as a float.
+    ///
+    /// A finite field has no absolute value; this is only meaningful as
+    /// "zero vs. non-zero", which is all that pivot selection needs.
+    fn magnitude(&self) -> Self::Output {
+        self.value as f32
+    }
+}
+
+impl<const P: u64> Conjugate for ModP<P> {
+    fn conjugate(&self) -> Self {
+        *self
+    }
+}
+
+impl<const P: u64> Add for ModP<P> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        let sum = (self.value as u128 + other.value as u128) % P as u128;
+        ModP { value: sum as u64 }
+    }
+}
+
+impl<const P: u64> Sub for ModP<P> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self + (-other)
+    }
+}
+
+impl<const P: u64> Mul for ModP<P> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        ModP {
+            value: mul_mod(self.value, other.value, P),
+        }
+    }
+}
+
+impl<const P: u64> Div for ModP<P> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        match other.inverse() {
+            Some(inverse) => ModP {
+                value: mul_mod(self.value, inverse.value, P),
+            },
+            None => panic!("Division by zero in GF({P})"),
+        }
+    }
+}
+
+impl<const P: u64> Neg for ModP<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        if self.value == 0 {
+            self
+        } else {
+            ModP {
+                value: P - self.value,
+            }
+        }
+    }
+}
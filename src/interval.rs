@@ -0,0 +1,349 @@
+//! # Interval Arithmetic
+//!
+//! This module provides an interval scalar type with outward rounding, giving
+//! guaranteed enclosures of the exact results of linear algebra routines.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::traits::{Conjugate, DirectedRounding, Magnitude, Negative, One, RealPart, Zero};
+
+/// Represents a closed interval `[lo, hi]` of real numbers.
+///
+/// Every arithmetic operation rounds its lower bound down and its upper bound
+/// up, so the exact result of the operation on any real numbers taken from the
+/// operands is always contained in the resulting interval. Running `dot`,
+/// `mul_mat`, `determinant` or `solve` on intervals therefore yields rigorous
+/// bounds for the exact real-number answer.
+///
+/// Division by an interval that contains zero yields the whole real line.
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::{Interval, Vector};
+///
+/// let u = Vector::from([Interval::point(0.1), Interval::point(0.2)]);
+/// let v = Vector::from([Interval::point(3.0), Interval::point(3.0)]);
+///
+/// let dot = u.dot(&v);
+/// // Plain floating-point evaluation lands inside the rigorous bounds
+/// assert!(dot.contains(0.1 * 3.0 + 0.2 * 3.0));
+/// assert!(dot.width() < 1e-15);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval<T> {
+    lo: T,
+    hi: T,
+}
+
+impl<T> Interval<T>
+where
+    T: DirectedRounding,
+{
+    /// Creates a new interval `[lo, hi]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo > hi` or if either bound is NaN.
+    pub fn new(lo: T, hi: T) -> Self {
+        assert!(lo <= hi, "Interval lower bound must not exceed upper bound");
+
+        Interval { lo, hi }
+    }
+
+    /// Creates the degenerate interval `[value, value]`.
+    pub fn point(value: T) -> Self {
+        Interval {
+            lo: value,
+            hi: value,
+        }
+    }
+
+    /// Creates the interval `[center - radius, center + radius]`, rounded outward.
+    ///
+    /// Useful to account for measurement or representation errors of inputs.
+    pub fn with_radius(center: T, radius: T) -> Self {
+        let radius = radius.abs();
+        Interval {
+            lo: (center - radius).next_down(),
+            hi: (center + radius).next_up(),
+        }
+    }
+
+    /// Returns the whole real line `[-∞, +∞]`.
+    pub fn entire() -> Self {
+        Interval {
+            lo: -T::infinity(),
+            hi: T::infinity(),
+        }
+    }
+
+    /// Returns the lower bound.
+    pub fn lo(&self) -> T {
+        self.lo
+    }
+
+    /// Returns the upper bound.
+    pub fn hi(&self) -> T {
+        self.hi
+    }
+
+    /// Returns the width `hi - lo`, rounded up.
+    pub fn width(&self) -> T {
+        (self.hi - self.lo).next_up()
+    }
+
+    /// Returns the midpoint of the interval.
+    pub fn midpoint(&self) -> T {
+        if self.lo == -T::infinity() || self.hi == T::infinity() {
+            return T::zero();
+        }
+
+        self.lo / T::from_f64(2.0) + self.hi / T::from_f64(2.0)
+    }
+
+    /// Checks if `value` lies inside the interval.
+    pub fn contains(&self, value: T) -> bool {
+        self.lo <= value && value <= self.hi
+    }
+
+    /// Checks if `other` is entirely contained in this interval.
+    pub fn encloses(&self, other: &Self) -> bool {
+        self.lo <= other.lo && other.hi <= self.hi
+    }
+
+    /// Checks if zero lies inside the interval.
+    pub fn contains_zero(&self) -> bool {
+        self.contains(T::zero())
+    }
+
+    /// Returns the smallest interval containing both intervals.
+    pub fn hull(&self, other: &Self) -> Self {
+        Interval {
+            lo: if self.lo < other.lo {
+                self.lo
+            } else {
+                other.lo
+            },
+            hi: if self.hi > other.hi {
+                self.hi
+            } else {
+                other.hi
+            },
+        }
+    }
+
+    /// Returns the mignitude `min |x|` over the interval.
+    pub fn mignitude(&self) -> T {
+        if self.contains_zero() {
+            T::zero()
+        } else if self.lo > T::zero() {
+            self.lo
+        } else {
+            -self.hi
+        }
+    }
+}
+
+/// Product of two bounds where `0 · ∞` is taken as zero.
+fn bound_mul<T>(a: T, b: T) -> T
+where
+    T: DirectedRounding,
+{
+    if a.is_zero() || b.is_zero() {
+        T::zero()
+    } else {
+        a * b
+    }
+}
+
+/// Returns the outward-rounded hull of four candidate bounds.
+fn outward_hull<T>(candidates: [T; 4]) -> Interval<T>
+where
+    T: DirectedRounding,
+{
+    let mut lo = candidates[0];
+    let mut hi = candidates[0];
+    for &value in &candidates[1..] {
+        if value < lo {
+            lo = value;
+        }
+        if value > hi {
+            hi = value;
+        }
+    }
+
+    Interval {
+        lo: lo.next_down(),
+        hi: hi.next_up(),
+    }
+}
+
+impl<T> From<T> for Interval<T>
+where
+    T: DirectedRounding,
+{
+    fn from(value: T) -> Self {
+        Interval::point(value)
+    }
+}
+
+impl<T> Zero for Interval<T>
+where
+    T: DirectedRounding,
+{
+    fn zero() -> Self {
+        Interval::point(T::zero())
+    }
+
+    /// Only the degenerate interval `[0, 0]` is zero.
+    fn is_zero(&self) -> bool {
+        self.lo.is_zero() && self.hi.is_zero()
+    }
+
+    /// An interval containing zero may be zero, so it is never a pivot.
+    fn may_be_zero(&self) -> bool {
+        self.contains_zero()
+    }
+
+    fn is_negligible(&self) -> bool {
+        self.lo.is_negligible() && self.hi.is_negligible()
+    }
+}
+
+impl<T> One for Interval<T>
+where
+    T: DirectedRounding,
+{
+    fn one() -> Self {
+        Interval::point(T::one())
+    }
+}
+
+impl<T> Negative for Interval<T>
+where
+    T: DirectedRounding,
+{
+    fn negative_one() -> Self {
+        Interval::point(T::negative_one())
+    }
+}
+
+impl<T> Magnitude for Interval<T>
+where
+    T: DirectedRounding,
+{
+    type Output = f32;
+
+    /// Returns the magnitude `max |x|` over the interval, rounded up.
+    fn magnitude(&self) -> Self::Output {
+        let magnitude = if -self.lo > self.hi {
+            -self.lo
+        } else {
+            self.hi
+        };
+        DirectedRounding::next_up(magnitude.to_f64() as f32)
+    }
+}
+
+impl<T> RealPart for Interval<T>
+where
+    T: DirectedRounding,
+{
+    type Output = f32;
+
+    fn real_part(&self) -> Self::Output {
+        self.midpoint().to_f64() as f32
+    }
+}
+
+impl<T> Conjugate for Interval<T>
+where
+    T: DirectedRounding,
+{
+    fn conjugate(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Add for Interval<T>
+where
+    T: DirectedRounding,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Interval {
+            lo: (self.lo + other.lo).next_down(),
+            hi: (self.hi + other.hi).next_up(),
+        }
+    }
+}
+
+impl<T> Sub for Interval<T>
+where
+    T: DirectedRounding,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Interval {
+            lo: (self.lo - other.hi).next_down(),
+            hi: (self.hi - other.lo).next_up(),
+        }
+    }
+}
+
+impl<T> Mul for Interval<T>
+where
+    T: DirectedRounding,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+
+        outward_hull([
+            bound_mul(self.lo, other.lo),
+            bound_mul(self.lo, other.hi),
+            bound_mul(self.hi, other.lo),
+            bound_mul(self.hi, other.hi),
+        ])
+    }
+}
+
+impl<T> Div for Interval<T>
+where
+    T: DirectedRounding,
+{
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        if other.contains_zero() {
+            return Self::entire();
+        }
+
+        outward_hull([
+            self.lo / other.lo,
+            self.lo / other.hi,
+            self.hi / other.lo,
+            self.hi / other.hi,
+        ])
+    }
+}
+
+impl<T> Neg for Interval<T>
+where
+    T: DirectedRounding,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Interval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
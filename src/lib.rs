@@ -7,10 +7,23 @@
 //!
 //! - **Vectors**: Basic operations like addition, scalar multiplication, dot product, and norm
 //! - **Matrices**: Matrix operations including multiplication, transposition, and inversion
+//! - **Elimination Traces**: Step-by-step row operations of Gaussian elimination, as text or LaTeX
+//! - **Eigenvalues**: Real and complex eigenvalues of real square matrices by Francis QR iteration
+//! - **Matrix Functions**: Exponential, logarithm, square root and powers of square matrices
+//! - **Matrix Properties**: Tolerance-aware predicates such as symmetric, unitary or positive definite
+//! - **Packed Matrices**: Diagonal, triangular, symmetric and banded storage with fast products and solves
+//! - **Banded Systems**: Thomas algorithm, cyclic tridiagonal and banded LU solvers working on diagonals
 //! - **Complex Numbers**: Complete support for complex arithmetic with conjugate operations
+//! - **Rational Numbers**: Exact fractions for rounding-free Gaussian elimination
+//! - **Prime Fields**: Modular arithmetic over GF(p) for exact modular linear algebra
 //! - **Interpolation**: Linear interpolation functions
+//! - **Interval Arithmetic**: Outward-rounded intervals giving verified bounds
 //! - **Cross Products**: 3D cross product calculations
+//! - **Quaternions**: Rotations with slerp and rotation matrix / axis-angle conversions
+//! - **Transforms**: Translation, rotation, scaling, look-at and projection matrices with explicit handedness and depth range
+//! - **Parsing**: `FromStr` for vectors, matrices and complex numbers, reading back their `Display` output
 //! - **Linear Combinations**: Linear combination operations for vectors
+//! - **Automatic Differentiation**: Dual numbers and Jacobians of generic vector functions
 //!
 //! ## Usage Examples
 //!
@@ -68,22 +81,49 @@
 //! ```
 
 pub mod angle_cos;
+pub mod banded_system;
 pub mod complex;
 pub mod cross_product;
 pub mod display;
+pub mod dual;
+pub mod eigen;
+pub mod elimination;
 pub mod errors;
+pub mod finite_field;
 pub mod interpolate;
+pub mod interval;
 pub mod linear_combination;
 pub mod matrix;
+pub mod matrix_function;
+pub mod matrix_properties;
+pub mod packed;
+pub mod parse;
+pub mod quaternion;
+pub mod rational;
 pub mod traits;
+pub mod transform;
 pub mod vector;
 
 pub use angle_cos::angle_cos;
+pub use banded_system::{solve_banded, solve_cyclic_tridiagonal, solve_tridiagonal};
 pub use complex::Complex;
 pub use cross_product::cross_product;
-pub use errors::{InterpolationError, LinearCombinationError, MatrixInverseError};
+pub use dual::{jacobian, Dual};
+pub use elimination::{EliminationStep, EliminationTrace, RowOperation};
+pub use errors::{
+    BandedSystemError, CheckedInverseError, ComplexConversionError, InterpolationError,
+    LinearCombinationError, LinearSystemError, MatrixFunctionError, MatrixInverseError,
+    MatrixShapeError, PackedMatrixError, ParseError, QuaternionError, TransformError,
+};
+pub use finite_field::ModP;
 pub use interpolate::lerp;
+pub use interval::Interval;
 pub use linear_combination::linear_combination;
 pub use matrix::Matrix;
-pub use traits::{Conjugate, Magnitude, Negative, One, Zero};
+pub use packed::{Banded, Diagonal, LowerTriangular, Symmetric, UpperTriangular};
+pub use quaternion::Quaternion;
+pub use rational::Rational;
+pub use traits::{
+    Conjugate, DirectedRounding, Float, Integer, Magnitude, Negative, One, RealPart, Zero,
+};
 pub use vector::Vector;
@@ -2,8 +2,8 @@ use core::slice::{Iter, IterMut};
 use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub};
 
 use crate::{
-    errors::MatrixInverseError,
-    traits::{Conjugate, Magnitude, Negative, One, Zero},
+    errors::{CheckedInverseError, LinearSystemError, MatrixInverseError, MatrixShapeError},
+    traits::{from_integer, Conjugate, Float, Magnitude, Negative, One, Zero},
     Vector,
 };
 
@@ -101,6 +101,21 @@ impl<K> Matrix<K> {
     pub fn into_inner(self) -> Vec<Vec<K>> {
         self.data
     }
+
+    /// Builds a matrix from rows that are known to have the same length.
+    pub(crate) fn from_rows(data: Vec<Vec<K>>) -> Self {
+        let columns = data.first().map_or(0, |row| row.len());
+        debug_assert!(
+            data.iter().all(|row| row.len() == columns),
+            "All matrix rows must have the same length"
+        );
+
+        Self {
+            rows: data.len(),
+            columns,
+            data,
+        }
+    }
 }
 
 impl<K, const R: usize, const C: usize> From<[[K; C]; R]> for Matrix<K> {
@@ -174,6 +189,222 @@ where
     }
 }
 
+impl<K> Matrix<K> {
+    /// Builds a `rows×columns` matrix whose entry `(i, j)` is `f(i, j)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let m = Matrix::from_fn(2, 3, |i, j| 10 * i + j);
+    ///
+    /// assert_eq!(m, Matrix::from([[0, 1, 2], [10, 11, 12]]));
+    /// ```
+    pub fn from_fn(rows: usize, columns: usize, mut f: impl FnMut(usize, usize) -> K) -> Self {
+        let data = (0..rows)
+            .map(|i| (0..columns).map(|j| f(i, j)).collect())
+            .collect();
+
+        Self {
+            rows,
+            columns,
+            data,
+        }
+    }
+}
+
+impl<K> Matrix<K>
+where
+    K: Clone,
+{
+    /// Builds a `rows×columns` matrix with every entry equal to `value`.
+    pub fn filled(rows: usize, columns: usize, value: K) -> Self {
+        Self {
+            rows,
+            columns,
+            data: vec![vec![value; columns]; rows],
+        }
+    }
+}
+
+impl<K> Matrix<K>
+where
+    K: Copy + Zero + One,
+{
+    /// Builds the `n×n` identity matrix.
+    pub fn identity(n: usize) -> Self {
+        Self::from_fn(n, n, |i, j| if i == j { K::one() } else { K::zero() })
+    }
+}
+
+impl<K> Matrix<K>
+where
+    K: Copy + Zero,
+{
+    /// Builds the square matrix with `diagonal` on its main diagonal and
+    /// zeros elsewhere.
+    pub fn from_diagonal(diagonal: &Vector<K>) -> Self {
+        let n = diagonal.len();
+        Self::from_fn(n, n, |i, j| if i == j { diagonal[i] } else { K::zero() })
+    }
+
+    /// Builds the Toeplitz matrix with first column `column` and first row
+    /// `row`, constant along each diagonal.
+    ///
+    /// The top-left entry is taken from `column`; `row[0]` is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::{Matrix, Vector};
+    ///
+    /// let t = Matrix::toeplitz(&Vector::from([1, 2, 3]), &Vector::from([0, 4, 5]));
+    ///
+    /// assert_eq!(t, Matrix::from([[1, 4, 5], [2, 1, 4], [3, 2, 1]]));
+    /// ```
+    pub fn toeplitz(column: &Vector<K>, row: &Vector<K>) -> Self {
+        Self::from_fn(column.len(), row.len(), |i, j| {
+            if i >= j {
+                column[i - j]
+            } else {
+                row[j - i]
+            }
+        })
+    }
+
+    /// Builds the Hankel matrix with first column `column` and last row
+    /// `row`, constant along each anti-diagonal.
+    ///
+    /// The bottom-left entry is taken from `column`; `row[0]` is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::{Matrix, Vector};
+    ///
+    /// let h = Matrix::hankel(&Vector::from([1, 2, 3]), &Vector::from([0, 4, 5]));
+    ///
+    /// assert_eq!(h, Matrix::from([[1, 2, 3], [2, 3, 4], [3, 4, 5]]));
+    /// ```
+    pub fn hankel(column: &Vector<K>, row: &Vector<K>) -> Self {
+        let rows = column.len();
+        Self::from_fn(rows, row.len(), |i, j| {
+            if i + j < rows {
+                column[i + j]
+            } else {
+                row[i + j + 1 - rows]
+            }
+        })
+    }
+
+    /// Builds the circulant matrix with first column `column`, where each
+    /// column is the previous one rotated down by one entry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::{Matrix, Vector};
+    ///
+    /// let c = Matrix::circulant(&Vector::from([1, 2, 3]));
+    ///
+    /// assert_eq!(c, Matrix::from([[1, 3, 2], [2, 1, 3], [3, 2, 1]]));
+    /// ```
+    pub fn circulant(column: &Vector<K>) -> Self {
+        let n = column.len();
+        Self::from_fn(n, n, |i, j| column[(i + n - j) % n])
+    }
+}
+
+impl<K> Matrix<K>
+where
+    K: Copy + One + Mul<Output = K>,
+{
+    /// Builds the Vandermonde matrix with `columns` columns, whose row `i`
+    /// holds the increasing powers `1, xᵢ, xᵢ², …` of `points[i]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::{Matrix, Vector};
+    ///
+    /// let v = Matrix::vandermonde(&Vector::from([1, 2, 3]), 3);
+    ///
+    /// assert_eq!(v, Matrix::from([[1, 1, 1], [1, 2, 4], [1, 3, 9]]));
+    /// ```
+    pub fn vandermonde(points: &Vector<K>, columns: usize) -> Self {
+        let data = points
+            .iter()
+            .map(|&x| {
+                let mut power = K::one();
+                (0..columns)
+                    .map(|_| {
+                        let current = power;
+                        power = power * x;
+                        current
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            rows: points.len(),
+            columns,
+            data,
+        }
+    }
+}
+
+impl<K> Matrix<K>
+where
+    K: Copy + Zero + One + Add<Output = K> + Div<Output = K>,
+{
+    /// Builds the `n×n` Hilbert matrix with entries `1 / (i + j + 1)`.
+    ///
+    /// Hilbert matrices are notoriously ill-conditioned, which makes them a
+    /// classic stress test; with `Rational` entries they are exact.
+    pub fn hilbert(n: usize) -> Self {
+        Self::from_fn(n, n, |i, j| K::one() / from_integer((i + j + 1) as u64))
+    }
+}
+
+impl<K> Matrix<K>
+where
+    K: Copy + Zero + One + Neg<Output = K>,
+{
+    /// Builds the companion matrix of the monic polynomial
+    /// `xⁿ + cₙ₋₁xⁿ⁻¹ + … + c₁x + c₀`, given `coefficients = [c₀, …, cₙ₋₁]`.
+    ///
+    /// The matrix has ones on the subdiagonal and `-cᵢ` in its last column,
+    /// so its characteristic polynomial, and hence its eigenvalues, are those
+    /// of the polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::{Matrix, Vector};
+    ///
+    /// // x² - 3x + 2 = (x - 1)(x - 2)
+    /// let c = Matrix::companion(&Vector::from([2.0, -3.0]));
+    ///
+    /// assert_eq!(c, Matrix::from([[0.0, -2.0], [1.0, 3.0]]));
+    /// assert_eq!(c.trace(), 3.0);
+    /// assert_eq!(c.determinant(), 2.0);
+    /// ```
+    pub fn companion(coefficients: &Vector<K>) -> Self {
+        let n = coefficients.len();
+        Self::from_fn(n, n, |i, j| {
+            if j == n - 1 {
+                -coefficients[i]
+            } else if i == j + 1 {
+                K::one()
+            } else {
+                K::zero()
+            }
+        })
+    }
+}
+
 impl<K> Matrix<K>
 where
     K: Copy + Add<Output = K>,
@@ -272,6 +503,95 @@ where
     }
 }
 
+impl<K> Matrix<K>
+where
+    K: Copy + Mul<Output = K>,
+{
+    /// Computes the element-wise (Hadamard) product.
+    pub fn hadamard(&self, other: &Self) -> Self {
+        debug_assert_eq!(self.shape(), other.shape(), "Matrix dimensions must match");
+
+        let mut data = Vec::with_capacity(self.rows);
+        for (row_a, row_b) in self.data.iter().zip(&other.data) {
+            let mut new_row = Vec::with_capacity(self.columns);
+            for (&column_a, &column_b) in row_a.iter().zip(row_b) {
+                new_row.push(column_a * column_b);
+            }
+            data.push(new_row);
+        }
+
+        Self {
+            columns: self.columns,
+            rows: self.rows,
+            data,
+        }
+    }
+
+    /// Computes the Kronecker product `A ⊗ B`.
+    ///
+    /// For an `m×n` matrix `A` and a `p×q` matrix `B` the result is the
+    /// `mp×nq` block matrix whose block `(i, j)` is `A[i][j]·B`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2]]);
+    /// let b = Matrix::from([[0, 1], [1, 0]]);
+    ///
+    /// assert_eq!(a.kronecker(&b), Matrix::from([[0, 1, 0, 2], [1, 0, 2, 0]]));
+    /// ```
+    pub fn kronecker(&self, other: &Self) -> Self {
+        let rows = self.rows * other.rows;
+        let columns = self.columns * other.columns;
+
+        let mut data = Vec::with_capacity(rows);
+        for row_a in &self.data {
+            for row_b in &other.data {
+                let mut new_row = Vec::with_capacity(columns);
+                for &a in row_a {
+                    for &b in row_b {
+                        new_row.push(a * b);
+                    }
+                }
+                data.push(new_row);
+            }
+        }
+
+        Self {
+            rows,
+            columns,
+            data,
+        }
+    }
+}
+
+impl<K> Matrix<K>
+where
+    K: Copy + Div<Output = K>,
+{
+    /// Computes the element-wise quotient.
+    pub fn hadamard_div(&self, other: &Self) -> Self {
+        debug_assert_eq!(self.shape(), other.shape(), "Matrix dimensions must match");
+
+        let mut data = Vec::with_capacity(self.rows);
+        for (row_a, row_b) in self.data.iter().zip(&other.data) {
+            let mut new_row = Vec::with_capacity(self.columns);
+            for (&column_a, &column_b) in row_a.iter().zip(row_b) {
+                new_row.push(column_a / column_b);
+            }
+            data.push(new_row);
+        }
+
+        Self {
+            columns: self.columns,
+            rows: self.rows,
+            data,
+        }
+    }
+}
+
 impl<K> Add for Matrix<K>
 where
     K: Copy + Add<Output = K>,
@@ -387,10 +707,10 @@ where
         + Mul<Output = K>
         + Zero
         + One
-        + PartialEq
         + Div<Output = K>
         + Neg<Output = K>
-        + Add<Output = K>,
+        + Add<Output = K>
+        + Magnitude<Output = f32>,
 {
     pub fn row_echelon(&self) -> Self {
         let mut rows: Vec<Vector<K>> = self
@@ -399,30 +719,31 @@ where
             .map(|row| Vector::from(row.as_slice()))
             .collect();
 
-        for row in 0..self.rows {
-            match rows[row]
-                .as_slice()
-                .iter()
-                .enumerate()
-                .skip(row)
-                .find_map(|(i, &val)| if val != K::zero() { Some(i) } else { None })
-            {
-                Some(pivot_idx) => {
-                    let pivot = rows[row][pivot_idx];
-                    rows[row].scl(K::one() / pivot);
-
-                    for i in 0..self.rows {
-                        let factor = rows[i][pivot_idx];
-                        if i == row || factor == K::zero() {
-                            continue;
-                        }
+        let mut row = 0;
+        for col in 0..self.columns {
+            if row >= self.rows {
+                break;
+            }
 
-                        let scaled = rows[row].scl_new(-factor);
-                        rows[i].add_inline(&scaled);
-                    }
-                }
-                None => break,
+            let Some(pivot_row) = find_pivot(rows.iter().map(|r| r[col]), row) else {
+                continue;
             };
+            rows.swap(row, pivot_row);
+
+            let pivot = rows[row][col];
+            rows[row].scl(K::one() / pivot);
+
+            for i in 0..self.rows {
+                let factor = rows[i][col];
+                if i == row || factor.is_zero() {
+                    continue;
+                }
+
+                let scaled = rows[row].scl_new(-factor);
+                rows[i].add_inline(&scaled);
+            }
+
+            row += 1;
         }
 
         Self {
@@ -431,6 +752,44 @@ where
             data: rows.into_iter().map(|v| v.to_vec()).collect(),
         }
     }
+
+    /// Computes a basis of the null space (kernel) of the matrix.
+    ///
+    /// Returns one vector per free column of the reduced row echelon form;
+    /// an empty result means the only solution of `A·x = 0` is `x = 0`.
+    /// With exact scalars such as `Rational` or `ModP` the basis is exact.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::{Matrix, Vector};
+    ///
+    /// let m = Matrix::from([[1.0, 2.0], [2.0, 4.0]]);
+    /// let basis = m.null_space();
+    ///
+    /// assert_eq!(basis, vec![Vector::from([-2.0, 1.0])]);
+    /// ```
+    pub fn null_space(&self) -> Vec<Vector<K>> {
+        let echelon = self.row_echelon();
+
+        let mut pivot_columns = Vec::with_capacity(self.rows);
+        for row in echelon.iter() {
+            if let Some(col) = row.iter().position(|val| !val.is_zero()) {
+                pivot_columns.push(col);
+            }
+        }
+
+        let mut basis = Vec::new();
+        for free in (0..self.columns).filter(|col| !pivot_columns.contains(col)) {
+            let mut vector = vec![K::zero(); self.columns];
+            vector[free] = K::one();
+            for (row, &pivot) in pivot_columns.iter().enumerate() {
+                vector[pivot] = -echelon[row][free];
+            }
+            basis.push(Vector::from(vector));
+        }
+        basis
+    }
 }
 
 impl<K> Matrix<K>
@@ -465,40 +824,668 @@ where
 
 impl<K> Matrix<K>
 where
-    K: Copy + Add<Output = K> + Sub<Output = K> + Mul<Output = K> + Zero + One + Negative,
+    K: Copy,
 {
-    pub fn determinant(&self) -> K {
-        if !self.is_square() {
-            return K::zero();
-        }
-
-        match self.rows {
-            1 => self[0][0],
-            2 => (self[0][0] * self[1][1]) - (self[0][1] * self[1][0]),
-            3 => {
-                let a = self[0][0] * self[1][1] * self[2][2];
-                let b = self[0][1] * self[1][2] * self[2][0];
-                let c = self[0][2] * self[1][0] * self[2][1];
-                let d = self[0][2] * self[1][1] * self[2][0];
-                let e = self[0][1] * self[1][0] * self[2][2];
-                let f = self[0][0] * self[1][2] * self[2][1];
-
-                (a + b + c) - (d + e + f)
+    /// Concatenates matrices side by side.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::RowsMismatch` - If the matrices do not all have
+    ///   the same number of rows
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2], [3, 4]]);
+    /// let b = Matrix::from([[5], [6]]);
+    ///
+    /// assert_eq!(Matrix::hstack(&[&a, &b]).unwrap(), Matrix::from([[1, 2, 5], [3, 4, 6]]));
+    /// ```
+    pub fn hstack(blocks: &[&Self]) -> Result<Self, MatrixShapeError> {
+        let rows = blocks.first().map_or(0, |block| block.rows);
+        let mut data = vec![Vec::new(); rows];
+        let mut columns = 0;
+
+        for block in blocks {
+            if block.rows != rows {
+                return Err(MatrixShapeError::RowsMismatch {
+                    expected: rows,
+                    found: block.rows,
+                });
             }
-            4 => {
-                let mut det = K::zero();
-                for col in 0..4 {
-                    let minor = self.sub_matrix(0, col);
-                    let sign = if col % 2 == 0 {
-                        K::one()
-                    } else {
-                        K::negative_one()
-                    };
-                    det = det + sign * self[0][col] * minor.determinant();
-                }
-                det
+            for (row, block_row) in data.iter_mut().zip(&block.data) {
+                row.extend_from_slice(block_row);
+            }
+            columns += block.columns;
+        }
+
+        Ok(Self {
+            rows,
+            columns,
+            data,
+        })
+    }
+
+    /// Concatenates matrices on top of each other.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::ColumnsMismatch` - If the matrices do not all
+    ///   have the same number of columns
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2]]);
+    /// let b = Matrix::from([[3, 4], [5, 6]]);
+    ///
+    /// assert_eq!(Matrix::vstack(&[&a, &b]).unwrap(), Matrix::from([[1, 2], [3, 4], [5, 6]]));
+    /// ```
+    pub fn vstack(blocks: &[&Self]) -> Result<Self, MatrixShapeError> {
+        let columns = blocks.first().map_or(0, |block| block.columns);
+        let mut data = Vec::new();
+
+        for block in blocks {
+            if block.columns != columns {
+                return Err(MatrixShapeError::ColumnsMismatch {
+                    expected: columns,
+                    found: block.columns,
+                });
+            }
+            data.extend(block.data.iter().cloned());
+        }
+
+        Ok(Self {
+            rows: data.len(),
+            columns,
+            data,
+        })
+    }
+
+    /// Assembles a matrix from a grid of blocks.
+    ///
+    /// All blocks in a block row must have the same number of rows, and all
+    /// blocks in a block column the same number of columns.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::RowsMismatch` - If the blocks of a block row
+    ///   differ in height
+    /// * `MatrixShapeError::ColumnsMismatch` - If the blocks of a block column
+    ///   differ in width
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// // KKT system [[H, Aᵀ], [A, 0]]
+    /// let h = Matrix::from([[2.0, 0.0], [0.0, 2.0]]);
+    /// let a = Matrix::from([[1.0, 1.0]]);
+    /// let zero = Matrix::zeros(1, 1);
+    ///
+    /// let kkt = Matrix::from_blocks([[&h, &a.transpose()], [&a, &zero]]).unwrap();
+    ///
+    /// assert_eq!(kkt, Matrix::from([[2.0, 0.0, 1.0], [0.0, 2.0, 1.0], [1.0, 1.0, 0.0]]));
+    /// ```
+    pub fn from_blocks<const R: usize, const C: usize>(
+        blocks: [[&Self; C]; R],
+    ) -> Result<Self, MatrixShapeError> {
+        if let Some(first_row) = blocks.first() {
+            for block_row in &blocks[1..] {
+                for (block, reference) in block_row.iter().zip(first_row) {
+                    if block.columns != reference.columns {
+                        return Err(MatrixShapeError::ColumnsMismatch {
+                            expected: reference.columns,
+                            found: block.columns,
+                        });
+                    }
+                }
+            }
+        }
+
+        let strips = blocks
+            .iter()
+            .map(|block_row| Self::hstack(block_row))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::vstack(&strips.iter().collect::<Vec<_>>())
+    }
+
+    /// Splits the matrix into the rows before `row` and the rows from `row` on.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::IndexOutOfBounds` - If `row` exceeds the number of rows
+    pub fn split_at_row(&self, row: usize) -> Result<(Self, Self), MatrixShapeError> {
+        if row > self.rows {
+            return Err(MatrixShapeError::IndexOutOfBounds {
+                index: row,
+                len: self.rows,
+            });
+        }
+
+        let top = self.block(0, 0, row, self.columns)?;
+        let bottom = self.block(row, 0, self.rows - row, self.columns)?;
+        Ok((top, bottom))
+    }
+
+    /// Splits the matrix into the columns before `col` and the columns from `col` on.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::IndexOutOfBounds` - If `col` exceeds the number of columns
+    pub fn split_at_col(&self, col: usize) -> Result<(Self, Self), MatrixShapeError> {
+        if col > self.columns {
+            return Err(MatrixShapeError::IndexOutOfBounds {
+                index: col,
+                len: self.columns,
+            });
+        }
+
+        let left = self.block(0, 0, self.rows, col)?;
+        let right = self.block(0, col, self.rows, self.columns - col)?;
+        Ok((left, right))
+    }
+
+    /// Extracts the `height×width` block whose top-left entry is `(row, col)`.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::BlockOutOfBounds` - If the block does not fit in the matrix
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let m = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    ///
+    /// assert_eq!(m.block(1, 1, 2, 2).unwrap(), Matrix::from([[5, 6], [8, 9]]));
+    /// assert!(m.block(2, 2, 2, 1).is_err());
+    /// ```
+    pub fn block(
+        &self,
+        row: usize,
+        col: usize,
+        height: usize,
+        width: usize,
+    ) -> Result<Self, MatrixShapeError> {
+        let fits_rows = row.checked_add(height).is_some_and(|end| end <= self.rows);
+        let fits_cols = col
+            .checked_add(width)
+            .is_some_and(|end| end <= self.columns);
+        if !fits_rows || !fits_cols {
+            return Err(MatrixShapeError::BlockOutOfBounds {
+                row,
+                col,
+                height,
+                width,
+                rows: self.rows,
+                columns: self.columns,
+            });
+        }
+
+        let data = self.data[row..row + height]
+            .iter()
+            .map(|r| r[col..col + width].to_vec())
+            .collect();
+        Ok(Self {
+            rows: height,
+            columns: width,
+            data,
+        })
+    }
+}
+
+impl<K> Matrix<K>
+where
+    K: Copy + Zero,
+{
+    /// Builds the block diagonal matrix with the given blocks on its diagonal
+    /// and zeros elsewhere. Blocks need not be square.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2]]);
+    /// let b = Matrix::from([[3], [4]]);
+    ///
+    /// assert_eq!(
+    ///     Matrix::block_diag(&[&a, &b]),
+    ///     Matrix::from([[1, 2, 0], [0, 0, 3], [0, 0, 4]])
+    /// );
+    /// ```
+    pub fn block_diag(blocks: &[&Self]) -> Self {
+        let rows = blocks.iter().map(|block| block.rows).sum();
+        let columns = blocks.iter().map(|block| block.columns).sum();
+        let mut result = Self::zeros(rows, columns);
+
+        let (mut row, mut col) = (0, 0);
+        for block in blocks {
+            for (i, block_row) in block.data.iter().enumerate() {
+                result.data[row + i][col..col + block.columns].copy_from_slice(block_row);
+            }
+            row += block.rows;
+            col += block.columns;
+        }
+        result
+    }
+}
+
+impl<K> Matrix<K>
+where
+    K: Copy,
+{
+    /// Swaps rows `i` and `j` in place.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::IndexOutOfBounds` - If either index is not a row
+    pub fn swap_rows(&mut self, i: usize, j: usize) -> Result<(), MatrixShapeError> {
+        check_index(i, self.rows)?;
+        check_index(j, self.rows)?;
+
+        self.data.swap(i, j);
+        Ok(())
+    }
+
+    /// Swaps columns `i` and `j` in place.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::IndexOutOfBounds` - If either index is not a column
+    pub fn swap_cols(&mut self, i: usize, j: usize) -> Result<(), MatrixShapeError> {
+        check_index(i, self.columns)?;
+        check_index(j, self.columns)?;
+
+        for row in &mut self.data {
+            row.swap(i, j);
+        }
+        Ok(())
+    }
+
+    /// Inserts `row` so that it becomes row `index`, shifting later rows down.
+    ///
+    /// Any row length is accepted by an empty `0×0` matrix.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::IndexOutOfBounds` - If `index` exceeds the number of rows
+    /// * `MatrixShapeError::ColumnsMismatch` - If the row length differs from
+    ///   the number of columns
+    pub fn insert_row(&mut self, index: usize, row: &Vector<K>) -> Result<(), MatrixShapeError> {
+        check_index(index, self.rows + 1)?;
+        if self.rows == 0 && self.columns == 0 {
+            self.columns = row.len();
+        }
+        if row.len() != self.columns {
+            return Err(MatrixShapeError::ColumnsMismatch {
+                expected: self.columns,
+                found: row.len(),
+            });
+        }
+
+        self.data.insert(index, row.to_vec());
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// Inserts `col` so that it becomes column `index`, shifting later columns right.
+    ///
+    /// Any column length is accepted by an empty `0×0` matrix.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::IndexOutOfBounds` - If `index` exceeds the number of columns
+    /// * `MatrixShapeError::RowsMismatch` - If the column length differs from
+    ///   the number of rows
+    pub fn insert_col(&mut self, index: usize, col: &Vector<K>) -> Result<(), MatrixShapeError> {
+        check_index(index, self.columns + 1)?;
+        if self.rows == 0 && self.columns == 0 {
+            self.rows = col.len();
+            self.data = vec![Vec::with_capacity(1); col.len()];
+        }
+        if col.len() != self.rows {
+            return Err(MatrixShapeError::RowsMismatch {
+                expected: self.rows,
+                found: col.len(),
+            });
+        }
+
+        for (row, &val) in self.data.iter_mut().zip(col.iter()) {
+            row.insert(index, val);
+        }
+        self.columns += 1;
+        Ok(())
+    }
+
+    /// Removes row `index` and returns it.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::IndexOutOfBounds` - If `index` is not a row
+    pub fn remove_row(&mut self, index: usize) -> Result<Vector<K>, MatrixShapeError> {
+        check_index(index, self.rows)?;
+
+        self.rows -= 1;
+        Ok(Vector::from(self.data.remove(index)))
+    }
+
+    /// Removes column `index` and returns it.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::IndexOutOfBounds` - If `index` is not a column
+    pub fn remove_col(&mut self, index: usize) -> Result<Vector<K>, MatrixShapeError> {
+        check_index(index, self.columns)?;
+
+        self.columns -= 1;
+        let removed: Vec<K> = self.data.iter_mut().map(|row| row.remove(index)).collect();
+        Ok(Vector::from(removed))
+    }
+
+    /// Builds the matrix made of the given rows, in the given order.
+    ///
+    /// Rows may be repeated or omitted.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::IndexOutOfBounds` - If an index is not a row
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let m = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+    ///
+    /// assert_eq!(m.select_rows(&[2, 0]).unwrap(), Matrix::from([[5, 6], [1, 2]]));
+    /// ```
+    pub fn select_rows(&self, indices: &[usize]) -> Result<Self, MatrixShapeError> {
+        let mut data = Vec::with_capacity(indices.len());
+        for &index in indices {
+            check_index(index, self.rows)?;
+            data.push(self.data[index].clone());
+        }
+
+        Ok(Self {
+            rows: indices.len(),
+            columns: self.columns,
+            data,
+        })
+    }
+
+    /// Builds the matrix made of the given columns, in the given order.
+    ///
+    /// Columns may be repeated or omitted.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::IndexOutOfBounds` - If an index is not a column
+    pub fn select_cols(&self, indices: &[usize]) -> Result<Self, MatrixShapeError> {
+        for &index in indices {
+            check_index(index, self.columns)?;
+        }
+
+        let data = self
+            .data
+            .iter()
+            .map(|row| indices.iter().map(|&index| row[index]).collect())
+            .collect();
+        Ok(Self {
+            rows: self.rows,
+            columns: indices.len(),
+            data,
+        })
+    }
+}
+
+impl<K> Matrix<K>
+where
+    K: Copy + Mul<Output = K>,
+{
+    /// Multiplies row `index` by `factor` in place.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::IndexOutOfBounds` - If `index` is not a row
+    pub fn scale_row(&mut self, index: usize, factor: K) -> Result<(), MatrixShapeError> {
+        check_index(index, self.rows)?;
+
+        for val in &mut self.data[index] {
+            *val = *val * factor;
+        }
+        Ok(())
+    }
+}
+
+impl<K> Matrix<K>
+where
+    K: Copy + Add<Output = K> + Mul<Output = K>,
+{
+    /// Adds `factor` times row `source` to row `target` in place.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::IndexOutOfBounds` - If either index is not a row
+    /// * `MatrixShapeError::SameRow` - If `target` and `source` are the same row
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let mut m = Matrix::from([[1, 2], [3, 4]]);
+    /// m.add_row_multiple(1, 0, -3).unwrap();
+    ///
+    /// assert_eq!(m, Matrix::from([[1, 2], [0, -2]]));
+    /// ```
+    pub fn add_row_multiple(
+        &mut self,
+        target: usize,
+        source: usize,
+        factor: K,
+    ) -> Result<(), MatrixShapeError> {
+        check_index(target, self.rows)?;
+        check_index(source, self.rows)?;
+        if target == source {
+            return Err(MatrixShapeError::SameRow { index: target });
+        }
+
+        let source_row = self.data[source].clone();
+        for (val, &src) in self.data[target].iter_mut().zip(&source_row) {
+            *val = *val + factor * src;
+        }
+        Ok(())
+    }
+}
+
+/// Constructors for elementary and permutation matrices.
+///
+/// Left-multiplying by one of these matrices applies the corresponding row
+/// operation: `E.mul_mat(&a)` equals `a` after the operation.
+impl<K> Matrix<K>
+where
+    K: Copy + Zero + One,
+{
+    /// Builds the `n×n` elementary matrix that swaps rows `i` and `j`.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::IndexOutOfBounds` - If either index is not below `n`
+    pub fn elementary_swap(n: usize, i: usize, j: usize) -> Result<Self, MatrixShapeError> {
+        let mut matrix = Self::identity(n);
+        matrix.swap_rows(i, j)?;
+        Ok(matrix)
+    }
+
+    /// Builds the `n×n` elementary matrix that multiplies row `index` by `factor`.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::IndexOutOfBounds` - If `index` is not below `n`
+    /// * `MatrixShapeError::ZeroScaleFactor` - If `factor` is zero, as
+    ///   scaling a row by zero cannot be undone
+    pub fn elementary_scale(n: usize, index: usize, factor: K) -> Result<Self, MatrixShapeError>
+    where
+        K: Mul<Output = K>,
+    {
+        if factor.is_zero() {
+            return Err(MatrixShapeError::ZeroScaleFactor);
+        }
+
+        let mut matrix = Self::identity(n);
+        matrix.scale_row(index, factor)?;
+        Ok(matrix)
+    }
+
+    /// Builds the `n×n` elementary matrix that adds `factor` times row
+    /// `source` to row `target`.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::IndexOutOfBounds` - If either index is not below `n`
+    /// * `MatrixShapeError::SameRow` - If `target` equals `source`, which
+    ///   would not give an elementary matrix
+    pub fn elementary_add(
+        n: usize,
+        target: usize,
+        source: usize,
+        factor: K,
+    ) -> Result<Self, MatrixShapeError>
+    where
+        K: Add<Output = K> + Mul<Output = K>,
+    {
+        let mut matrix = Self::identity(n);
+        matrix.add_row_multiple(target, source, factor)?;
+        Ok(matrix)
+    }
+
+    /// Builds the permutation matrix `P` whose row `i` is row `permutation[i]`
+    /// of the identity, so that `P.mul_mat(&a)` equals `a.select_rows(permutation)`.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixShapeError::IndexOutOfBounds` - If an entry is not below the length
+    /// * `MatrixShapeError::InvalidPermutation` - If an entry is repeated
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let p: Matrix<i32> = Matrix::permutation(&[1, 2, 0]).unwrap();
+    /// let a = Matrix::from([[1], [2], [3]]);
+    ///
+    /// assert_eq!(p.mul_mat(&a), Matrix::from([[2], [3], [1]]));
+    /// ```
+    pub fn permutation(permutation: &[usize]) -> Result<Self, MatrixShapeError> {
+        let n = permutation.len();
+        let mut seen = vec![false; n];
+        for &index in permutation {
+            check_index(index, n)?;
+            if seen[index] {
+                return Err(MatrixShapeError::InvalidPermutation { index });
+            }
+            seen[index] = true;
+        }
+
+        Self::identity(n).select_rows(permutation)
+    }
+}
+
+/// Checks that `index` addresses one of `len` rows or columns.
+fn check_index(index: usize, len: usize) -> Result<(), MatrixShapeError> {
+    if index < len {
+        Ok(())
+    } else {
+        Err(MatrixShapeError::IndexOutOfBounds { index, len })
+    }
+}
+
+impl<K> Matrix<K>
+where
+    K: Copy
+        + Add<Output = K>
+        + Sub<Output = K>
+        + Mul<Output = K>
+        + Div<Output = K>
+        + Zero
+        + One
+        + Negative
+        + Magnitude<Output = f32>,
+{
+    /// Returns the determinant, or zero for a matrix that is not square.
+    ///
+    /// Matrices up to 3x3 use the closed forms. Larger ones use Bareiss'
+    /// fraction-free elimination with partial pivoting, which takes O(n³)
+    /// operations and only divides exactly, so integer matrices keep exact
+    /// determinants. The empty matrix has determinant one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let a = Matrix::from([[2, 0, 0, 0], [0, 3, 0, 0], [0, 0, 4, 1], [0, 0, 1, 1]]);
+    /// assert_eq!(a.determinant(), 18);
+    /// ```
+    pub fn determinant(&self) -> K {
+        if !self.is_square() {
+            return K::zero();
+        }
+
+        match self.rows {
+            0 => K::one(),
+            1 => self[0][0],
+            2 => (self[0][0] * self[1][1]) - (self[0][1] * self[1][0]),
+            3 => {
+                let a = self[0][0] * self[1][1] * self[2][2];
+                let b = self[0][1] * self[1][2] * self[2][0];
+                let c = self[0][2] * self[1][0] * self[2][1];
+                let d = self[0][2] * self[1][1] * self[2][0];
+                let e = self[0][1] * self[1][0] * self[2][2];
+                let f = self[0][0] * self[1][2] * self[2][1];
+
+                (a + b + c) - (d + e + f)
+            }
+            n => {
+                let mut a = self.data.clone();
+                let mut sign = K::one();
+                let mut previous = K::one();
+
+                for k in 0..n - 1 {
+                    // When every candidate may be zero, as for intervals around
+                    // zero, any entry that is not exactly zero still gives a
+                    // valid, if wide, result
+                    let pivot_row = find_pivot(a.iter().map(|row| row[k]), k)
+                        .or_else(|| (k..n).find(|&i| !a[i][k].is_zero()));
+                    let Some(pivot_row) = pivot_row else {
+                        return K::zero();
+                    };
+                    if pivot_row != k {
+                        a.swap(pivot_row, k);
+                        sign = sign * K::negative_one();
+                    }
+
+                    // Every entry of the trailing block is a minor of the
+                    // original matrix, so the division is exact
+                    for i in k + 1..n {
+                        for j in k + 1..n {
+                            a[i][j] = (a[i][j] * a[k][k] - a[i][k] * a[k][j]) / previous;
+                        }
+                    }
+                    previous = a[k][k];
+                }
+
+                sign * a[n - 1][n - 1]
             }
-            _ => K::zero(),
         }
     }
 }
@@ -512,7 +1499,7 @@ where
         + Sub<Output = K>
         + Div<Output = K>
         + Mul<Output = K>
-        + PartialEq,
+        + Magnitude<Output = f32>,
 {
     pub fn inverse(&self) -> Result<Self, MatrixInverseError> {
         if !self.is_square() {
@@ -524,17 +1511,17 @@ where
 
         let n = self.rows;
         let mut a = self.data.clone();
-        let mut inv = vec![vec![K::zero(); n]; n];
-        for (i, row) in inv.iter_mut().enumerate().take(n) {
-            row[i] = K::one();
-        }
+        let mut inv = Self::identity(n).data;
 
         for i in 0..n {
-            let pivot = a[i][i];
-            if pivot == K::zero() {
-                return Err(MatrixInverseError::Singular);
-            }
+            let pivot_row = match find_pivot(a.iter().map(|row| row[i]), i) {
+                Some(pivot_row) => pivot_row,
+                None => return Err(MatrixInverseError::Singular),
+            };
+            a.swap(i, pivot_row);
+            inv.swap(i, pivot_row);
 
+            let pivot = a[i][i];
             for j in 0..n {
                 a[i][j] = a[i][j] / pivot;
                 inv[i][j] = inv[i][j] / pivot;
@@ -557,28 +1544,184 @@ where
             data: inv,
         })
     }
+
+    /// Inverts the matrix, rejecting inputs that are too ill-conditioned.
+    ///
+    /// The 1-norm condition number `‖A‖₁·‖A⁻¹‖₁` measures how much relative
+    /// errors in the input can be amplified in the result: a value near
+    /// `1 / ε` means the computed inverse may have no correct digits.
+    ///
+    /// # Errors
+    ///
+    /// * `CheckedInverseError::Inverse` - If `inverse` fails, because the
+    ///   matrix is not square or is singular
+    /// * `CheckedInverseError::IllConditioned` - If the condition number
+    ///   exceeds `max_condition`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let nearly_singular = Matrix::from([[1.0, 1.0], [1.0, 1.0 + 1e-9]]);
+    ///
+    /// assert!(nearly_singular.inverse().is_ok());
+    /// assert!(nearly_singular.inverse_checked(1e6).is_err());
+    /// ```
+    pub fn inverse_checked(&self, max_condition: f32) -> Result<Self, CheckedInverseError> {
+        let inverse = self.inverse()?;
+
+        let condition = self.norm_1() * inverse.norm_1();
+        if condition > max_condition {
+            return Err(CheckedInverseError::IllConditioned { condition });
+        }
+        Ok(inverse)
+    }
 }
 
 impl<K> Matrix<K>
 where
     K: Copy
-        + PartialOrd
+        + Zero
+        + Add<Output = K>
         + Sub<Output = K>
         + Div<Output = K>
         + Mul<Output = K>
         + Magnitude<Output = f32>,
 {
+    /// Solves the square linear system `A·x = b`.
+    ///
+    /// Row `i` of the matrix holds the coefficients of equation `i`, that is
+    /// `Σⱼ A[i][j]·x[j] = b[i]`. This treats `x` as a column, unlike `mul_vec`,
+    /// which computes the row vector product `v·A`; so `a.solve(&b)` undoes
+    /// `a.transpose().mul_vec(&x)`, and `x·A = b` is solved by
+    /// `a.transpose().solve(&b)`.
+    ///
+    /// Uses Gaussian elimination with partial pivoting by magnitude, without
+    /// forming the inverse. Pivots that may be zero, such as intervals
+    /// containing zero, are never divided by.
+    ///
+    /// # Errors
+    ///
+    /// * `LinearSystemError::NotSquare` - If the matrix is not square
+    /// * `LinearSystemError::RhsLength` - If `b` does not have one entry per row
+    /// * `LinearSystemError::Singular` - If the system has no unique solution
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::{Matrix, Vector};
+    ///
+    /// // 2x + y = 5, x + 3y = 10
+    /// let a = Matrix::from([[2.0, 1.0], [1.0, 3.0]]);
+    /// let x = a.solve(&Vector::from([5.0, 10.0])).unwrap();
+    ///
+    /// assert_eq!(x, Vector::from([1.0, 3.0]));
+    /// ```
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, LinearSystemError> {
+        if !self.is_square() {
+            return Err(LinearSystemError::NotSquare {
+                rows: self.rows,
+                columns: self.columns,
+            });
+        }
+        if b.len() != self.rows {
+            return Err(LinearSystemError::RhsLength {
+                expected: self.rows,
+                found: b.len(),
+            });
+        }
+
+        let n = self.rows;
+        let mut a = self.data.clone();
+        let mut rhs = b.to_vec();
+
+        for i in 0..n {
+            let pivot_row = match find_pivot(a.iter().map(|row| row[i]), i) {
+                Some(pivot_row) => pivot_row,
+                None => return Err(LinearSystemError::Singular),
+            };
+            a.swap(i, pivot_row);
+            rhs.swap(i, pivot_row);
+
+            for k in (i + 1)..n {
+                let factor = a[k][i] / a[i][i];
+                if factor.is_zero() {
+                    continue;
+                }
+                let (upper, lower) = a.split_at_mut(k);
+                for (target, &source) in lower[0][i..].iter_mut().zip(&upper[i][i..]) {
+                    *target = *target - factor * source;
+                }
+                rhs[k] = rhs[k] - factor * rhs[i];
+            }
+        }
+
+        let mut x = vec![K::zero(); n];
+        for i in (0..n).rev() {
+            let mut sum = rhs[i];
+            for j in (i + 1)..n {
+                sum = sum - a[i][j] * x[j];
+            }
+            x[i] = sum / a[i][i];
+        }
+
+        Ok(Vector::from(x))
+    }
+}
+
+impl<K> Matrix<K>
+where
+    K: Copy + Zero + Sub<Output = K> + Div<Output = K> + Mul<Output = K> + Magnitude<Output = f32>,
+{
+    /// Returns the rank, reducing the matrix to row echelon form in place.
+    ///
+    /// Pivots for which `Zero::is_negligible` holds count as zero: below
+    /// `1e-10` in magnitude for floating point entries, absorbing rounding,
+    /// and exactly zero for exact scalars such as `Rational` and `ModP`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::{Matrix, Rational};
+    ///
+    /// let mut a = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    /// assert_eq!(a.rank(), 2);
+    ///
+    /// // Exact scalars never round a pivot away
+    /// let tiny = Rational::new(1_i64, 1_000_000_000_000);
+    /// let mut b = Matrix::from([[tiny, tiny], [tiny, tiny + tiny]]);
+    /// assert_eq!(b.rank(), 2);
+    /// ```
     pub fn rank(&mut self) -> usize {
+        self.count_pivots(|pivot| pivot.is_negligible())
+    }
+
+    /// Returns the rank, treating pivots whose magnitude is at most
+    /// `tolerance` as zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let a = Matrix::from([[1.0, 2.0], [1.0, 2.0 + 1e-6]]);
+    /// assert_eq!(a.clone().rank(), 2);
+    /// assert_eq!(a.clone().rank_with_tolerance(1e-4), 1);
+    /// ```
+    pub fn rank_with_tolerance(&mut self, tolerance: f32) -> usize {
+        self.count_pivots(|pivot| pivot.magnitude() <= tolerance)
+    }
+
+    fn count_pivots(&mut self, negligible: impl Fn(K) -> bool) -> usize {
         let mut rank = 0;
         let mut row = 0;
 
         for col in 0..self.columns {
-            let mut pivot_row = row;
-            while pivot_row < self.rows && (self.data[pivot_row][col].magnitude() < 1e-10) {
-                pivot_row += 1;
-            }
+            let pivot_row = find_pivot(self.data.iter().map(|r| r[col]), row)
+                .filter(|&pivot_row| !negligible(self.data[pivot_row][col]));
 
-            if pivot_row < self.rows {
+            if let Some(pivot_row) = pivot_row {
                 if pivot_row != row {
                     self.data.swap(pivot_row, row);
                 }
@@ -603,3 +1746,311 @@ where
         rank
     }
 }
+
+impl<K> Matrix<K>
+where
+    K: Copy + Magnitude<Output = f32>,
+{
+    /// Computes the Frobenius norm, the square root of the sum of the
+    /// squared magnitudes of all entries.
+    pub fn norm_frobenius(&self) -> f32 {
+        let mut sum = 0.;
+        for &val in self.data.iter().flatten() {
+            let magnitude = val.magnitude();
+            sum += magnitude * magnitude;
+        }
+        sum.sqrt()
+    }
+
+    /// Computes the induced 1-norm, the largest absolute column sum.
+    pub fn norm_1(&self) -> f32 {
+        let mut max_val = 0.;
+        for col in 0..self.columns {
+            let sum: f32 = self.data.iter().map(|row| row[col].magnitude()).sum();
+            if sum > max_val {
+                max_val = sum;
+            }
+        }
+        max_val
+    }
+
+    /// Computes the induced ∞-norm, the largest absolute row sum.
+    pub fn norm_inf(&self) -> f32 {
+        let mut max_val = 0.;
+        for row in &self.data {
+            let sum: f32 = row.iter().map(|val| val.magnitude()).sum();
+            if sum > max_val {
+                max_val = sum;
+            }
+        }
+        max_val
+    }
+
+    /// Computes the max-abs norm, the largest magnitude of any entry.
+    ///
+    /// Unlike the other norms it is not submultiplicative.
+    pub fn norm_max(&self) -> f32 {
+        let mut max_val = 0.;
+        for &val in self.data.iter().flatten() {
+            let magnitude = val.magnitude();
+            if magnitude > max_val {
+                max_val = magnitude;
+            }
+        }
+        max_val
+    }
+}
+
+impl<K> Matrix<K>
+where
+    K: Float,
+{
+    /// Computes the singular values of the matrix, in descending order.
+    ///
+    /// Uses the one-sided Jacobi method, which orthogonalizes the columns by
+    /// plane rotations and is accurate even for tiny singular values. An
+    /// `m×n` matrix has `min(m, n)` singular values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let m = Matrix::from([[3.0, 0.0], [4.0, 5.0]]);
+    /// let sigma = m.singular_values();
+    ///
+    /// assert!((sigma[0] - 45.0_f64.sqrt()).abs() < 1e-12);
+    /// assert!((sigma[1] - 5.0_f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn singular_values(&self) -> Vec<K> {
+        // Rotating the shorter dimension keeps the number of pairs minimal
+        let mut columns = if self.rows >= self.columns {
+            self.transpose().data
+        } else {
+            self.data.clone()
+        };
+        let n = columns.len();
+
+        for _ in 0..MAX_JACOBI_SWEEPS {
+            let mut rotated = false;
+
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let (left, right) = columns.split_at_mut(q);
+                    let (u, v) = (&mut left[p], &mut right[0]);
+
+                    let mut alpha = K::zero();
+                    let mut beta = K::zero();
+                    let mut gamma = K::zero();
+                    for (&x, &y) in u.iter().zip(v.iter()) {
+                        alpha = alpha + x * x;
+                        beta = beta + y * y;
+                        gamma = gamma + x * y;
+                    }
+                    if gamma.abs() <= K::epsilon() * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+
+                    let zeta = (beta - alpha) / (K::from_f64(2.0) * gamma);
+                    let t = (K::one() / (zeta.abs() + (K::one() + zeta * zeta).sqrt()))
+                        * if zeta < K::zero() {
+                            K::negative_one()
+                        } else {
+                            K::one()
+                        };
+                    let c = K::one() / (K::one() + t * t).sqrt();
+                    let s = c * t;
+
+                    for (x, y) in u.iter_mut().zip(v.iter_mut()) {
+                        let (a, b) = (*x, *y);
+                        *x = c * a - s * b;
+                        *y = s * a + c * b;
+                    }
+                }
+            }
+
+            if !rotated {
+                break;
+            }
+        }
+
+        let mut values: Vec<K> = columns
+            .iter()
+            .map(|col| {
+                col.iter()
+                    .fold(K::zero(), |acc, &val| acc + val * val)
+                    .sqrt()
+            })
+            .collect();
+        values.sort_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+        values
+    }
+
+    /// Computes the spectral norm, the largest singular value.
+    pub fn norm_2(&self) -> f32 {
+        self.singular_values()
+            .first()
+            .map_or(0., |&sigma| sigma.to_f64() as f32)
+    }
+
+    /// Computes the 2-norm condition number `σ_max / σ_min`.
+    ///
+    /// Returns infinity when the matrix is rank deficient. This is exact up to
+    /// rounding but costs a full SVD; see `condition_number_estimate` for a
+    /// cheaper alternative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let m = Matrix::from([[2.0, 0.0], [0.0, 0.5]]);
+    ///
+    /// assert!((m.condition_number() - 4.0).abs() < 1e-6);
+    /// ```
+    pub fn condition_number(&self) -> f32 {
+        let values = self.singular_values();
+        let (Some(&largest), Some(&smallest)) = (values.first(), values.last()) else {
+            return 1.;
+        };
+
+        if smallest.is_zero() {
+            return f32::INFINITY;
+        }
+        (largest / smallest).to_f64() as f32
+    }
+}
+
+impl<K> Matrix<K>
+where
+    K: Float + Magnitude<Output = f32>,
+{
+    /// Estimates the 1-norm condition number `‖A‖₁·‖A⁻¹‖₁` without forming
+    /// the inverse.
+    ///
+    /// Uses Hager's method as refined by Higham: `‖A⁻¹‖₁` is estimated from a
+    /// few solves with `A` and `Aᵀ`. The result is a lower bound on the true
+    /// condition number that is almost always within a factor of 3 of it.
+    /// Singular matrices yield infinity.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixInverseError::NotSquare` - If the matrix is not square
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let m = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+    /// let exact = m.norm_1() * m.inverse().unwrap().norm_1();
+    ///
+    /// assert!((m.condition_number_estimate().unwrap() - exact).abs() < 1e-4);
+    /// ```
+    pub fn condition_number_estimate(&self) -> Result<f32, MatrixInverseError> {
+        if !self.is_square() {
+            return Err(MatrixInverseError::NotSquare {
+                rows: self.rows,
+                columns: self.columns,
+            });
+        }
+
+        let n = self.rows;
+        if n == 0 {
+            return Ok(1.);
+        }
+        let transposed = self.transpose();
+
+        let mut x = Vector::from(vec![K::from_f64(1.0 / n as f64); n]);
+        let mut estimate = 0.;
+        for iteration in 0..MAX_HAGER_ITERATIONS {
+            let Ok(y) = self.solve(&x) else {
+                return Ok(f32::INFINITY);
+            };
+            let previous = estimate;
+            estimate = y.norm_1();
+            if iteration > 0 && estimate <= previous {
+                estimate = previous;
+                break;
+            }
+
+            let signs: Vec<K> = y
+                .iter()
+                .map(|&val| {
+                    if val < K::zero() {
+                        K::negative_one()
+                    } else {
+                        K::one()
+                    }
+                })
+                .collect();
+            let Ok(z) = transposed.solve(&Vector::from(signs)) else {
+                return Ok(f32::INFINITY);
+            };
+
+            let (best, z_max) = z
+                .iter()
+                .enumerate()
+                .map(|(j, val)| (j, val.magnitude()))
+                .fold((0, 0.), |acc, cur| if cur.1 > acc.1 { cur } else { acc });
+            let z_dot_x = z.dot(&x).to_f64() as f32;
+            if z_max <= z_dot_x {
+                break;
+            }
+
+            x = Vector::from(vec![K::zero(); n]);
+            x[best] = K::one();
+        }
+
+        // Higham's alternating vector catches matrices that fool the power iteration
+        let alternating: Vec<K> = (0..n)
+            .map(|i| {
+                let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
+                let ramp = if n > 1 {
+                    i as f64 / (n - 1) as f64
+                } else {
+                    0.0
+                };
+                K::from_f64(sign * (1.0 + ramp))
+            })
+            .collect();
+        if let Ok(w) = self.solve(&Vector::from(alternating)) {
+            let alternative = 2. * w.norm_1() / (3. * n as f32);
+            if alternative > estimate {
+                estimate = alternative;
+            }
+        }
+
+        Ok(self.norm_1() * estimate)
+    }
+}
+
+/// Upper bound on Jacobi sweeps; convergence is quadratic, so a handful suffice.
+const MAX_JACOBI_SWEEPS: usize = 64;
+
+/// Number of power-iteration steps used by the Hager estimator.
+const MAX_HAGER_ITERATIONS: usize = 5;
+
+/// Selects the pivot for an elimination step among the entries of a column.
+///
+/// Only entries at index `start` or later are considered. The non-zero entry
+/// with the largest magnitude is chosen, so complex scalars are compared by
+/// modulus rather than by any ordering of their components.
+pub(crate) fn find_pivot<K>(column: impl Iterator<Item = K>, start: usize) -> Option<usize>
+where
+    K: Zero + Magnitude<Output = f32>,
+{
+    let mut pivot: Option<(usize, f32)> = None;
+    for (i, val) in column.enumerate().skip(start) {
+        if val.may_be_zero() {
+            continue;
+        }
+
+        let magnitude = val.magnitude();
+        if pivot.map_or(true, |(_, best)| magnitude > best) {
+            pivot = Some((i, magnitude));
+        }
+    }
+    pivot.map(|(i, _)| i)
+}
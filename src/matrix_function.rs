@@ -0,0 +1,306 @@
+//! # Matrix Functions
+//!
+//! This module extends square matrices with the exponential, logarithm,
+//! square root and powers. The routines only rely on field arithmetic and
+//! magnitudes, so they work for real (`f32`, `f64`) and `Complex` entries.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{
+    errors::{MatrixFunctionError, MatrixInverseError},
+    traits::{from_integer, Magnitude, One, Zero},
+    Matrix,
+};
+
+/// Degree of the diagonal Padé approximant used by `exp`.
+const PADE_DEGREE: u64 = 6;
+
+/// Number of odd terms kept in the `atanh` series used by `log`.
+const LOG_SERIES_TERMS: u64 = 12;
+
+/// Maximum number of Denman–Beavers iterations before giving up.
+const MAX_SQRT_ITERATIONS: usize = 100;
+
+/// Maximum number of square roots taken by `log` to approach the identity.
+const MAX_LOG_SQUARE_ROOTS: u32 = 64;
+
+impl<K> Matrix<K>
+where
+    K: Copy
+        + Zero
+        + One
+        + Add<Output = K>
+        + Sub<Output = K>
+        + Mul<Output = K>
+        + Div<Output = K>
+        + Neg<Output = K>
+        + Magnitude<Output = f32>,
+{
+    /// Computes the matrix exponential `e^A = Σ Aᵏ / k!`.
+    ///
+    /// Uses scaling and squaring: the matrix is halved until its 1-norm is at
+    /// most `1/2`, the exponential of the scaled matrix is approximated by the
+    /// `[6/6]` Padé approximant, and the result is squared back.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixFunctionError::NotSquare` - If the matrix is not square
+    /// * `MatrixFunctionError::Singular` - If the Padé denominator cannot be
+    ///   inverted, which only happens for non-finite entries
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// // Nilpotent: e^A = I + A
+    /// let a = Matrix::from([[0.0, 1.0], [0.0, 0.0]]);
+    ///
+    /// assert_eq!(a.exp().unwrap(), Matrix::from([[1.0, 1.0], [0.0, 1.0]]));
+    /// ```
+    pub fn exp(&self) -> Result<Self, MatrixFunctionError> {
+        self.check_square()?;
+
+        let half = K::one() / from_integer::<K>(2);
+        let mut scaled = self.clone();
+        let mut norm = scaled.norm_1();
+        let mut squarings = 0;
+        // Norms are f32, so an overflowed (infinite) norm still terminates
+        while norm > 0.5 && squarings < f32::MAX_EXP {
+            scaled.scl(half);
+            norm /= 2.;
+            squarings += 1;
+        }
+
+        // Coefficients c_k = (2q - k)! q! / ((2q)! k! (q - k)!), built incrementally
+        let identity = Matrix::identity(self.rows());
+        let mut numerator = identity.clone();
+        let mut denominator = identity.clone();
+        let mut power = identity;
+        let mut coefficient = K::one();
+        for k in 1..=PADE_DEGREE {
+            coefficient = coefficient * from_integer(PADE_DEGREE - k + 1)
+                / from_integer(k * (2 * PADE_DEGREE - k + 1));
+            power = power.mul_mat(&scaled);
+
+            let term = power.scl_new(coefficient);
+            numerator = numerator.add_new(&term);
+            if k % 2 == 0 {
+                denominator = denominator.add_new(&term);
+            } else {
+                denominator = denominator.sub_new(&term);
+            }
+        }
+
+        let mut result = denominator.inverse()?.mul_mat(&numerator);
+        for _ in 0..squarings {
+            result = result.mul_mat(&result);
+        }
+        Ok(result)
+    }
+
+    /// Computes the principal square root, the matrix `X` with `X² = A` whose
+    /// eigenvalues have positive real part.
+    ///
+    /// Uses the Denman–Beavers iteration, which converges quadratically when
+    /// `A` has no eigenvalues on the closed negative real axis.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixFunctionError::NotSquare` - If the matrix is not square
+    /// * `MatrixFunctionError::Singular` - If an iterate becomes singular,
+    ///   typically because `A` itself is singular
+    /// * `MatrixFunctionError::NotConverged` - If the iteration stalls, for
+    ///   example on a real matrix with negative eigenvalues
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let a = Matrix::from([[4.0, 0.0], [0.0, 9.0]]);
+    /// let root = a.sqrt().unwrap();
+    ///
+    /// assert!((root[0][0] - 2.0_f64).abs() < 1e-12);
+    /// assert!((root[1][1] - 3.0_f64).abs() < 1e-12);
+    /// ```
+    pub fn sqrt(&self) -> Result<Self, MatrixFunctionError> {
+        self.check_square()?;
+
+        let half = K::one() / from_integer::<K>(2);
+        let mut y = self.clone();
+        let mut z = Matrix::identity(self.rows());
+        let mut previous = f32::INFINITY;
+
+        for _ in 0..MAX_SQRT_ITERATIONS {
+            let mut next_y = y.add_new(&z.inverse()?);
+            let mut next_z = z.add_new(&y.inverse()?);
+            next_y.scl(half);
+            next_z.scl(half);
+
+            let change = next_y.sub_new(&y).norm_1();
+            y = next_y;
+            z = next_z;
+
+            // Quadratic convergence: once the steps stop shrinking, rounding dominates
+            let settled = change >= previous && change < 1e-3 * y.norm_1();
+            if change == 0. || settled {
+                return Ok(y);
+            }
+            previous = change;
+        }
+
+        Err(MatrixFunctionError::NotConverged {
+            iterations: MAX_SQRT_ITERATIONS,
+        })
+    }
+
+    /// Computes the principal logarithm, the inverse of `exp`.
+    ///
+    /// Uses inverse scaling and squaring: square roots are taken until the
+    /// matrix is close to the identity, the logarithm is evaluated with the
+    /// series `log A = 2·Σ Y²ʲ⁺¹ / (2j + 1)` where `Y = (A - I)(A + I)⁻¹`, and
+    /// the result is scaled back by `2ᵏ`.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixFunctionError::NotSquare` - If the matrix is not square
+    /// * `MatrixFunctionError::Singular` - If the matrix is singular
+    /// * `MatrixFunctionError::NotConverged` - If a square root fails to
+    ///   converge, for example on a real matrix with negative eigenvalues
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let a = Matrix::from([[1.0, 0.5], [0.0, 1.0]]);
+    /// let round_trip = a.log().unwrap().exp().unwrap();
+    ///
+    /// assert!(round_trip.sub_new(&a).norm_max() < 1e-6);
+    /// ```
+    pub fn log(&self) -> Result<Self, MatrixFunctionError> {
+        self.check_square()?;
+
+        let identity = Matrix::identity(self.rows());
+        let mut reduced = self.clone();
+        let mut square_roots = 0;
+        while reduced.sub_new(&identity).norm_1() > 0.25 {
+            if square_roots == MAX_LOG_SQUARE_ROOTS {
+                return Err(MatrixFunctionError::NotConverged {
+                    iterations: MAX_LOG_SQUARE_ROOTS as usize,
+                });
+            }
+            reduced = reduced.sqrt()?;
+            square_roots += 1;
+        }
+
+        let y = reduced
+            .sub_new(&identity)
+            .mul_mat(&reduced.add_new(&identity).inverse()?);
+        let y_squared = y.mul_mat(&y);
+        let mut power = y;
+        let mut series = Matrix::zeros(self.rows(), self.columns());
+        for j in 0..LOG_SERIES_TERMS {
+            series = series.add_new(&power.scl_new(K::one() / from_integer(2 * j + 1)));
+            power = power.mul_mat(&y_squared);
+        }
+
+        let mut scale = from_integer::<K>(2);
+        for _ in 0..square_roots {
+            scale = scale + scale;
+        }
+        series.scl(scale);
+        Ok(series)
+    }
+
+    /// Raises the matrix to an integer power by repeated squaring.
+    ///
+    /// `A⁰` is the identity and negative exponents are powers of the inverse.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixFunctionError::NotSquare` - If the matrix is not square
+    /// * `MatrixFunctionError::Singular` - If the exponent is negative and the
+    ///   matrix is singular
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// // Fibonacci numbers
+    /// let a = Matrix::from([[1.0, 1.0], [1.0, 0.0]]);
+    ///
+    /// assert_eq!(a.powi(10).unwrap()[0][1], 55.0);
+    /// ```
+    pub fn powi(&self, exponent: i32) -> Result<Self, MatrixFunctionError> {
+        self.check_square()?;
+
+        let mut base = if exponent < 0 {
+            self.inverse()?
+        } else {
+            self.clone()
+        };
+        let mut result = Matrix::identity(self.rows());
+        let mut exponent = exponent.unsigned_abs();
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul_mat(&base);
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.mul_mat(&base);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Raises the matrix to an arbitrary power, `Aᵖ = e^(p·log A)`.
+    ///
+    /// The exponent is a scalar of the same type as the entries, so real
+    /// matrices take real exponents and complex matrices complex ones.
+    ///
+    /// # Errors
+    ///
+    /// Fails under the same conditions as `log`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let a = Matrix::from([[8.0, 0.0], [0.0, 27.0]]);
+    /// let cube_root = a.powf(1.0 / 3.0).unwrap();
+    ///
+    /// assert!((cube_root[1][1] - 3.0_f64).abs() < 1e-9);
+    /// ```
+    pub fn powf(&self, exponent: K) -> Result<Self, MatrixFunctionError> {
+        let mut log = self.log()?;
+        log.scl(exponent);
+        log.exp()
+    }
+
+    fn check_square(&self) -> Result<(), MatrixFunctionError> {
+        if self.is_square() {
+            Ok(())
+        } else {
+            Err(MatrixFunctionError::NotSquare {
+                rows: self.rows(),
+                columns: self.columns(),
+            })
+        }
+    }
+}
+
+impl From<MatrixInverseError> for MatrixFunctionError {
+    fn from(err: MatrixInverseError) -> Self {
+        match err {
+            MatrixInverseError::NotSquare { rows, columns } => {
+                MatrixFunctionError::NotSquare { rows, columns }
+            }
+            MatrixInverseError::Singular => MatrixFunctionError::Singular,
+        }
+    }
+}
@@ -0,0 +1,272 @@
+//! # Matrix Property Predicates
+//!
+//! This module provides structural and spectral predicates on matrices, such
+//! as symmetry, orthogonality or positive definiteness. Every predicate takes
+//! a `tolerance`: two entries are considered equal when the magnitude of
+//! their difference does not exceed it. Use `0.0` for exact scalars.
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::{
+    traits::{Conjugate, Magnitude, One, RealPart, Zero},
+    Matrix,
+};
+
+/// Checks if `a` and `b` differ by at most `tolerance` in magnitude.
+fn approx_eq<K>(a: K, b: K, tolerance: f32) -> bool
+where
+    K: Sub<Output = K> + Magnitude<Output = f32>,
+{
+    (a - b).magnitude() <= tolerance
+}
+
+/// Checks if two matrices of the same shape agree entry by entry.
+fn all_approx_eq<K>(a: &Matrix<K>, b: &Matrix<K>, tolerance: f32) -> bool
+where
+    K: Copy + Sub<Output = K> + Magnitude<Output = f32>,
+{
+    a.shape() == b.shape()
+        && a.iter().zip(b.iter()).all(|(row_a, row_b)| {
+            row_a
+                .iter()
+                .zip(row_b)
+                .all(|(&x, &y)| approx_eq(x, y, tolerance))
+        })
+}
+
+impl<K> Matrix<K>
+where
+    K: Copy + Sub<Output = K> + Magnitude<Output = f32>,
+{
+    /// Checks if every entry `(i, j)` satisfying `keep(i, j) == false` is
+    /// zero within `tolerance`.
+    fn vanishes_outside(&self, tolerance: f32, keep: impl Fn(usize, usize) -> bool) -> bool {
+        self.iter().enumerate().all(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .all(|(j, val)| keep(i, j) || val.magnitude() <= tolerance)
+        })
+    }
+
+    /// Checks if the matrix is square and equal to its transpose.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let m = Matrix::from([[1.0, 2.0], [2.0 + 1e-9, 3.0]]);
+    ///
+    /// assert!(m.is_symmetric(1e-6));
+    /// assert!(!m.is_symmetric(0.0));
+    /// ```
+    pub fn is_symmetric(&self, tolerance: f32) -> bool {
+        self.is_square() && all_approx_eq(self, &self.transpose(), tolerance)
+    }
+
+    /// Checks if every entry off the main diagonal is zero.
+    pub fn is_diagonal(&self, tolerance: f32) -> bool {
+        self.vanishes_outside(tolerance, |i, j| i == j)
+    }
+
+    /// Checks if every entry below the main diagonal is zero.
+    pub fn is_upper_triangular(&self, tolerance: f32) -> bool {
+        self.vanishes_outside(tolerance, |i, j| i <= j)
+    }
+
+    /// Checks if every entry above the main diagonal is zero.
+    pub fn is_lower_triangular(&self, tolerance: f32) -> bool {
+        self.vanishes_outside(tolerance, |i, j| i >= j)
+    }
+
+    /// Checks if the matrix is square and, in every row, the magnitude of the
+    /// diagonal entry is at least the sum of the magnitudes of the others,
+    /// up to `tolerance`.
+    pub fn is_diagonally_dominant(&self, tolerance: f32) -> bool {
+        self.is_square()
+            && self.iter().enumerate().all(|(i, row)| {
+                let off_diagonal: f32 = row
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .map(|(_, val)| val.magnitude())
+                    .sum();
+                row[i].magnitude() + tolerance >= off_diagonal
+            })
+    }
+
+    /// Checks if the matrix is square and, in every row, the magnitude of the
+    /// diagonal entry exceeds the sum of the magnitudes of the others by more
+    /// than `tolerance`.
+    ///
+    /// Strictly diagonally dominant matrices are always invertible, and
+    /// Gaussian elimination on them needs no pivoting.
+    pub fn is_strictly_diagonally_dominant(&self, tolerance: f32) -> bool {
+        self.is_square()
+            && self.iter().enumerate().all(|(i, row)| {
+                let off_diagonal: f32 = row
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .map(|(_, val)| val.magnitude())
+                    .sum();
+                row[i].magnitude() > off_diagonal + tolerance
+            })
+    }
+}
+
+impl<K> Matrix<K>
+where
+    K: Copy + Sub<Output = K> + Conjugate + Magnitude<Output = f32>,
+{
+    /// Checks if the matrix is square and equal to its conjugate transpose.
+    ///
+    /// For real scalars this is the same as `is_symmetric`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::{Complex, Matrix};
+    ///
+    /// let m = Matrix::from([
+    ///     [Complex::new(2.0, 0.0), Complex::new(1.0, -1.0)],
+    ///     [Complex::new(1.0, 1.0), Complex::new(3.0, 0.0)],
+    /// ]);
+    ///
+    /// assert!(m.is_hermitian(0.0));
+    /// assert!(!m.is_symmetric(0.0));
+    /// ```
+    pub fn is_hermitian(&self, tolerance: f32) -> bool {
+        self.is_square() && all_approx_eq(self, &self.conjugate_transpose(), tolerance)
+    }
+}
+
+impl<K> Matrix<K>
+where
+    K: Copy
+        + Zero
+        + One
+        + Add<Output = K>
+        + Sub<Output = K>
+        + Mul<Output = K>
+        + Magnitude<Output = f32>,
+{
+    /// Checks if the matrix is square with `AᵀA = I`, i.e. its columns are
+    /// orthonormal.
+    pub fn is_orthogonal(&self, tolerance: f32) -> bool {
+        self.is_square()
+            && all_approx_eq(
+                &self.transpose().mul_mat(self),
+                &Matrix::identity(self.rows()),
+                tolerance,
+            )
+    }
+
+    /// Checks if the matrix is square with `A² = A`, as for projections.
+    pub fn is_idempotent(&self, tolerance: f32) -> bool {
+        self.is_square() && all_approx_eq(&self.mul_mat(self), self, tolerance)
+    }
+
+    /// Checks if the matrix is square with `Aᵏ = 0` for some `k`.
+    ///
+    /// An `n×n` nilpotent matrix always satisfies `Aⁿ = 0`, so at most `n`
+    /// powers are computed.
+    pub fn is_nilpotent(&self, tolerance: f32) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        let zero = Matrix::zeros(self.rows(), self.columns());
+        let mut power = self.clone();
+        for _ in 0..self.rows() {
+            if all_approx_eq(&power, &zero, tolerance) {
+                return true;
+            }
+            power = power.mul_mat(self);
+        }
+        all_approx_eq(&power, &zero, tolerance)
+    }
+}
+
+impl<K> Matrix<K>
+where
+    K: Copy
+        + Zero
+        + One
+        + Add<Output = K>
+        + Sub<Output = K>
+        + Mul<Output = K>
+        + Conjugate
+        + Magnitude<Output = f32>,
+{
+    /// Checks if the matrix is square with `AᴴA = I`, i.e. its columns are
+    /// orthonormal under the complex inner product.
+    ///
+    /// For real scalars this is the same as `is_orthogonal`.
+    pub fn is_unitary(&self, tolerance: f32) -> bool {
+        self.is_square()
+            && all_approx_eq(
+                &self.conjugate_transpose().mul_mat(self),
+                &Matrix::identity(self.rows()),
+                tolerance,
+            )
+    }
+}
+
+impl<K> Matrix<K>
+where
+    K: Copy
+        + Zero
+        + Sub<Output = K>
+        + Mul<Output = K>
+        + Div<Output = K>
+        + Conjugate
+        + Magnitude<Output = f32>
+        + RealPart<Output = f32>,
+{
+    /// Checks if the matrix is Hermitian with `xᴴAx > 0` for every `x ≠ 0`.
+    ///
+    /// Gaussian elimination without pivoting is run on the matrix; it is
+    /// positive definite exactly when every pivot is real and positive. Pivots
+    /// not exceeding `tolerance` count as zero, so nearly singular matrices
+    /// are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Matrix;
+    ///
+    /// let spd = Matrix::from([[2.0, -1.0], [-1.0, 2.0]]);
+    /// let indefinite = Matrix::from([[1.0, 2.0], [2.0, 1.0]]);
+    ///
+    /// assert!(spd.is_positive_definite(1e-12));
+    /// assert!(!indefinite.is_positive_definite(1e-12));
+    /// ```
+    pub fn is_positive_definite(&self, tolerance: f32) -> bool {
+        if !self.is_hermitian(tolerance) {
+            return false;
+        }
+
+        let n = self.rows();
+        let mut a: Vec<Vec<K>> = self.iter().cloned().collect();
+        for k in 0..n {
+            let pivot = a[k][k];
+            if pivot.real_part() <= tolerance {
+                return false;
+            }
+
+            let (upper, lower) = a.split_at_mut(k + 1);
+            let pivot_row = &upper[k];
+            for row in lower.iter_mut() {
+                let factor = row[k] / pivot;
+                if factor.is_zero() {
+                    continue;
+                }
+                for (val, &p) in row[k..].iter_mut().zip(&pivot_row[k..]) {
+                    *val = *val - factor * p;
+                }
+            }
+        }
+        true
+    }
+}
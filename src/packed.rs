@@ -0,0 +1,1270 @@
+//! # Packed Matrices
+//!
+//! This module provides square matrices with a known zero pattern that only
+//! store the entries the pattern allows: `Diagonal`, `UpperTriangular`,
+//! `LowerTriangular`, `Symmetric` and `Banded`. Besides saving memory, their
+//! products, solves and determinants skip the structural zeros instead of
+//! running the dense algorithms of `Matrix`.
+//!
+//! `mul_vec` follows the same convention as `Matrix::mul_vec` and `solve` the
+//! same as `Matrix::solve`, so a packed matrix and its dense conversion always
+//! give the same results.
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::{
+    errors::{MatrixInverseError, PackedMatrixError},
+    matrix::find_pivot,
+    traits::{Magnitude, Negative, One, Zero},
+    Matrix, Vector,
+};
+
+/// Returns the size of a square matrix, or an error for rectangular ones.
+fn square_size<K>(matrix: &Matrix<K>) -> Result<usize, PackedMatrixError> {
+    let (rows, columns) = matrix.shape();
+    if rows == columns {
+        Ok(rows)
+    } else {
+        Err(PackedMatrixError::NotSquare { rows, columns })
+    }
+}
+
+/// Checks that every entry `(i, j)` with `keep(i, j) == false` is zero.
+fn check_pattern<K>(
+    matrix: &Matrix<K>,
+    keep: impl Fn(usize, usize) -> bool,
+) -> Result<(), PackedMatrixError>
+where
+    K: Zero,
+{
+    for (i, row) in matrix.iter().enumerate() {
+        for (j, val) in row.iter().enumerate() {
+            if !keep(i, j) && !val.is_zero() {
+                return Err(PackedMatrixError::OutsideStructure { row: i, col: j });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Expands the stored entries `(i, j, value)` of an `n×n` matrix.
+fn dense_from_entries<K>(n: usize, entries: impl Iterator<Item = (usize, usize, K)>) -> Matrix<K>
+where
+    K: Copy + Zero,
+{
+    let mut result = Matrix::zeros(n, n);
+    for (i, j, val) in entries {
+        result[i][j] = val;
+    }
+    result
+}
+
+/// Computes `Matrix::mul_vec` from the stored entries of an `n×n` matrix.
+fn mul_vec_entries<K>(
+    n: usize,
+    entries: impl Iterator<Item = (usize, usize, K)>,
+    vec: &Vector<K>,
+) -> Vector<K>
+where
+    K: Copy + Zero + Add<Output = K> + Mul<Output = K>,
+{
+    debug_assert_eq!(n, vec.len(), "Matrix rows must match vector length");
+
+    let mut result = vec![K::zero(); n];
+    for (i, j, val) in entries {
+        result[j] = result[j] + val * vec[i];
+    }
+    Vector::from(result)
+}
+
+/// Computes `A·B` from the stored entries of an `n×n` matrix `A`.
+fn mul_mat_entries<K>(
+    n: usize,
+    entries: impl Iterator<Item = (usize, usize, K)>,
+    other: &Matrix<K>,
+) -> Matrix<K>
+where
+    K: Copy + Zero + Add<Output = K> + Mul<Output = K>,
+{
+    debug_assert_eq!(
+        n,
+        other.rows(),
+        "Matrix dimensions must be compatible for multiplication"
+    );
+
+    let mut result = Matrix::zeros(n, other.columns());
+    for (i, k, val) in entries {
+        for (acc, &b) in result[i].iter_mut().zip(&other[k]) {
+            *acc = *acc + val * b;
+        }
+    }
+    result
+}
+
+/// A square matrix whose only non-zero entries lie on the main diagonal.
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::{Diagonal, Matrix, Vector};
+///
+/// let d = Diagonal::from(Vector::from([2.0, 4.0]));
+///
+/// assert_eq!(d.determinant(), 8.0);
+/// assert_eq!(d.solve(&Vector::from([1.0, 1.0])).unwrap(), Vector::from([0.5, 0.25]));
+/// assert_eq!(d.to_matrix(), Matrix::from([[2.0, 0.0], [0.0, 4.0]]));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Diagonal<K> {
+    data: Vec<K>,
+}
+
+impl<K> Diagonal<K> {
+    /// Returns the number of rows (and columns) of the matrix.
+    pub fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the diagonal entries.
+    pub fn as_slice(&self) -> &[K] {
+        &self.data
+    }
+
+    fn entries(&self) -> impl Iterator<Item = (usize, usize, K)> + '_
+    where
+        K: Copy,
+    {
+        self.data.iter().enumerate().map(|(i, &val)| (i, i, val))
+    }
+}
+
+impl<K> From<Vector<K>> for Diagonal<K> {
+    fn from(diagonal: Vector<K>) -> Self {
+        Self {
+            data: diagonal.into_inner(),
+        }
+    }
+}
+
+impl<K> Diagonal<K>
+where
+    K: Copy + Zero,
+{
+    /// Returns the entry at row `i` and column `j`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` or `j` is out of bounds.
+    pub fn get(&self, i: usize, j: usize) -> K {
+        assert!(i < self.size() && j < self.size(), "Index out of bounds");
+        if i == j {
+            self.data[i]
+        } else {
+            K::zero()
+        }
+    }
+
+    /// Expands the matrix into dense storage.
+    pub fn to_matrix(&self) -> Matrix<K> {
+        dense_from_entries(self.size(), self.entries())
+    }
+}
+
+impl<K> Diagonal<K>
+where
+    K: Copy + Zero + Add<Output = K> + Mul<Output = K>,
+{
+    /// Multiplies a vector by the matrix, like `Matrix::mul_vec`, in `O(n)`.
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        mul_vec_entries(self.size(), self.entries(), vec)
+    }
+
+    /// Computes `D·B`, scaling each row of `other`.
+    pub fn mul_mat(&self, other: &Matrix<K>) -> Matrix<K> {
+        mul_mat_entries(self.size(), self.entries(), other)
+    }
+}
+
+impl<K> Diagonal<K>
+where
+    K: Copy + One + Mul<Output = K>,
+{
+    /// Computes the determinant, the product of the diagonal entries.
+    pub fn determinant(&self) -> K {
+        self.data.iter().fold(K::one(), |acc, &val| acc * val)
+    }
+}
+
+impl<K> Diagonal<K>
+where
+    K: Copy + Zero + One + Div<Output = K>,
+{
+    /// Solves `D·x = b` by dividing each entry of `b` by the diagonal.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixInverseError::Singular` - If a diagonal entry is zero
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, MatrixInverseError> {
+        debug_assert_eq!(self.size(), b.len(), "Matrix rows must match vector length");
+
+        self.data
+            .iter()
+            .zip(b.iter())
+            .map(|(&d, &val)| {
+                if d.is_zero() {
+                    Err(MatrixInverseError::Singular)
+                } else {
+                    Ok(val / d)
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Vector::from)
+    }
+
+    /// Computes the inverse, which is again diagonal.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixInverseError::Singular` - If a diagonal entry is zero
+    pub fn inverse(&self) -> Result<Self, MatrixInverseError> {
+        let ones = Vector::from(vec![K::one(); self.size()]);
+        self.solve(&ones).map(Self::from)
+    }
+}
+
+impl<K> TryFrom<&Matrix<K>> for Diagonal<K>
+where
+    K: Copy + Zero,
+{
+    type Error = PackedMatrixError;
+
+    fn try_from(matrix: &Matrix<K>) -> Result<Self, Self::Error> {
+        let n = square_size(matrix)?;
+        check_pattern(matrix, |i, j| i == j)?;
+        Ok(Self {
+            data: (0..n).map(|i| matrix[i][i]).collect(),
+        })
+    }
+}
+
+impl<K> From<&Diagonal<K>> for Matrix<K>
+where
+    K: Copy + Zero,
+{
+    fn from(diagonal: &Diagonal<K>) -> Self {
+        diagonal.to_matrix()
+    }
+}
+
+/// A square matrix whose entries below the main diagonal are zero.
+///
+/// The `n(n + 1)/2` entries on and above the diagonal are stored row by row.
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::{Matrix, UpperTriangular, Vector};
+///
+/// let m = Matrix::from([[2.0, 1.0], [0.0, 4.0]]);
+/// let u = UpperTriangular::try_from(&m).unwrap();
+///
+/// assert_eq!(u.determinant(), 8.0);
+/// // 2x + y = 4, 4y = 8
+/// assert_eq!(u.solve(&Vector::from([4.0, 8.0])).unwrap(), Vector::from([1.0, 2.0]));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct UpperTriangular<K> {
+    size: usize,
+    data: Vec<K>,
+}
+
+impl<K> UpperTriangular<K> {
+    /// Creates an `n×n` upper triangular matrix, calling `f(i, j)` for every
+    /// entry with `i <= j`.
+    pub fn from_fn(n: usize, mut f: impl FnMut(usize, usize) -> K) -> Self {
+        let data = (0..n)
+            .flat_map(|i| (i..n).map(move |j| (i, j)))
+            .map(|(i, j)| f(i, j))
+            .collect();
+        Self { size: n, data }
+    }
+
+    /// Returns the number of rows (and columns) of the matrix.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Position of entry `(i, j)`, `i <= j`, in the packed storage.
+    fn index(&self, i: usize, j: usize) -> usize {
+        i * (2 * self.size - i + 1) / 2 + (j - i)
+    }
+
+    fn entries(&self) -> impl Iterator<Item = (usize, usize, K)> + '_
+    where
+        K: Copy,
+    {
+        let n = self.size;
+        (0..n)
+            .flat_map(move |i| (i..n).map(move |j| (i, j)))
+            .zip(&self.data)
+            .map(|((i, j), &val)| (i, j, val))
+    }
+}
+
+impl<K> UpperTriangular<K>
+where
+    K: Copy + Zero,
+{
+    /// Returns the entry at row `i` and column `j`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` or `j` is out of bounds.
+    pub fn get(&self, i: usize, j: usize) -> K {
+        assert!(i < self.size && j < self.size, "Index out of bounds");
+        if i <= j {
+            self.data[self.index(i, j)]
+        } else {
+            K::zero()
+        }
+    }
+
+    /// Expands the matrix into dense storage.
+    pub fn to_matrix(&self) -> Matrix<K> {
+        dense_from_entries(self.size, self.entries())
+    }
+
+    /// Returns the transpose, which is lower triangular.
+    pub fn transpose(&self) -> LowerTriangular<K> {
+        LowerTriangular::from_fn(self.size, |i, j| self.get(j, i))
+    }
+}
+
+impl<K> UpperTriangular<K>
+where
+    K: Copy + Zero + Add<Output = K> + Mul<Output = K>,
+{
+    /// Multiplies a vector by the matrix, like `Matrix::mul_vec`, skipping the
+    /// zeros below the diagonal.
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        mul_vec_entries(self.size, self.entries(), vec)
+    }
+
+    /// Computes `U·B`, skipping the zeros below the diagonal.
+    pub fn mul_mat(&self, other: &Matrix<K>) -> Matrix<K> {
+        mul_mat_entries(self.size, self.entries(), other)
+    }
+}
+
+impl<K> UpperTriangular<K>
+where
+    K: Copy + Zero + One + Mul<Output = K>,
+{
+    /// Computes the determinant, the product of the diagonal entries.
+    pub fn determinant(&self) -> K {
+        (0..self.size).fold(K::one(), |acc, i| acc * self.get(i, i))
+    }
+}
+
+impl<K> UpperTriangular<K>
+where
+    K: Copy + Zero + One + Sub<Output = K> + Mul<Output = K> + Div<Output = K>,
+{
+    /// Solves `U·x = b` by back substitution in `O(n²)`.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixInverseError::Singular` - If a diagonal entry is zero
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, MatrixInverseError> {
+        debug_assert_eq!(self.size, b.len(), "Matrix rows must match vector length");
+
+        let n = self.size;
+        let mut x = vec![K::zero(); n];
+        for i in (0..n).rev() {
+            let pivot = self.get(i, i);
+            if pivot.is_zero() {
+                return Err(MatrixInverseError::Singular);
+            }
+
+            let mut sum = b[i];
+            for (j, &val) in x.iter().enumerate().skip(i + 1) {
+                sum = sum - self.get(i, j) * val;
+            }
+            x[i] = sum / pivot;
+        }
+        Ok(Vector::from(x))
+    }
+
+    /// Computes the inverse, which is again upper triangular.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixInverseError::Singular` - If a diagonal entry is zero
+    pub fn inverse(&self) -> Result<Self, MatrixInverseError> {
+        let n = self.size;
+        if (0..n).any(|i| self.get(i, i).is_zero()) {
+            return Err(MatrixInverseError::Singular);
+        }
+
+        // Column j of the inverse solves U·x = e_j and vanishes below row j
+        let mut inverse = Self::from_fn(n, |_, _| K::zero());
+        for j in 0..n {
+            let index = inverse.index(j, j);
+            inverse.data[index] = K::one() / self.get(j, j);
+            for i in (0..j).rev() {
+                let mut negated_sum = K::zero();
+                for k in i + 1..=j {
+                    negated_sum = negated_sum - self.get(i, k) * inverse.get(k, j);
+                }
+                let index = inverse.index(i, j);
+                inverse.data[index] = negated_sum / self.get(i, i);
+            }
+        }
+        Ok(inverse)
+    }
+}
+
+impl<K> TryFrom<&Matrix<K>> for UpperTriangular<K>
+where
+    K: Copy + Zero,
+{
+    type Error = PackedMatrixError;
+
+    fn try_from(matrix: &Matrix<K>) -> Result<Self, Self::Error> {
+        let n = square_size(matrix)?;
+        check_pattern(matrix, |i, j| i <= j)?;
+        Ok(Self::from_fn(n, |i, j| matrix[i][j]))
+    }
+}
+
+impl<K> From<&UpperTriangular<K>> for Matrix<K>
+where
+    K: Copy + Zero,
+{
+    fn from(upper: &UpperTriangular<K>) -> Self {
+        upper.to_matrix()
+    }
+}
+
+/// A square matrix whose entries above the main diagonal are zero.
+///
+/// The `n(n + 1)/2` entries on and below the diagonal are stored row by row.
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::{LowerTriangular, Vector};
+///
+/// let l = LowerTriangular::from_fn(3, |i, j| (i + j + 1) as f64);
+///
+/// assert_eq!(l.get(0, 2), 0.0);
+/// assert_eq!(l.determinant(), 15.0);
+///
+/// let x = l.solve(&Vector::from([1.0, 2.0, 3.0])).unwrap();
+/// assert_eq!(l.to_matrix().solve(&Vector::from([1.0, 2.0, 3.0])).unwrap(), x);
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct LowerTriangular<K> {
+    size: usize,
+    data: Vec<K>,
+}
+
+impl<K> LowerTriangular<K> {
+    /// Creates an `n×n` lower triangular matrix, calling `f(i, j)` for every
+    /// entry with `i >= j`.
+    pub fn from_fn(n: usize, mut f: impl FnMut(usize, usize) -> K) -> Self {
+        let data = (0..n)
+            .flat_map(|i| (0..=i).map(move |j| (i, j)))
+            .map(|(i, j)| f(i, j))
+            .collect();
+        Self { size: n, data }
+    }
+
+    /// Returns the number of rows (and columns) of the matrix.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Position of entry `(i, j)`, `i >= j`, in the packed storage.
+    fn index(&self, i: usize, j: usize) -> usize {
+        i * (i + 1) / 2 + j
+    }
+
+    fn entries(&self) -> impl Iterator<Item = (usize, usize, K)> + '_
+    where
+        K: Copy,
+    {
+        (0..self.size)
+            .flat_map(|i| (0..=i).map(move |j| (i, j)))
+            .zip(&self.data)
+            .map(|((i, j), &val)| (i, j, val))
+    }
+}
+
+impl<K> LowerTriangular<K>
+where
+    K: Copy + Zero,
+{
+    /// Returns the entry at row `i` and column `j`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` or `j` is out of bounds.
+    pub fn get(&self, i: usize, j: usize) -> K {
+        assert!(i < self.size && j < self.size, "Index out of bounds");
+        if i >= j {
+            self.data[self.index(i, j)]
+        } else {
+            K::zero()
+        }
+    }
+
+    /// Expands the matrix into dense storage.
+    pub fn to_matrix(&self) -> Matrix<K> {
+        dense_from_entries(self.size, self.entries())
+    }
+
+    /// Returns the transpose, which is upper triangular.
+    pub fn transpose(&self) -> UpperTriangular<K> {
+        UpperTriangular::from_fn(self.size, |i, j| self.get(j, i))
+    }
+}
+
+impl<K> LowerTriangular<K>
+where
+    K: Copy + Zero + Add<Output = K> + Mul<Output = K>,
+{
+    /// Multiplies a vector by the matrix, like `Matrix::mul_vec`, skipping the
+    /// zeros above the diagonal.
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        mul_vec_entries(self.size, self.entries(), vec)
+    }
+
+    /// Computes `L·B`, skipping the zeros above the diagonal.
+    pub fn mul_mat(&self, other: &Matrix<K>) -> Matrix<K> {
+        mul_mat_entries(self.size, self.entries(), other)
+    }
+}
+
+impl<K> LowerTriangular<K>
+where
+    K: Copy + Zero + One + Mul<Output = K>,
+{
+    /// Computes the determinant, the product of the diagonal entries.
+    pub fn determinant(&self) -> K {
+        (0..self.size).fold(K::one(), |acc, i| acc * self.get(i, i))
+    }
+}
+
+impl<K> LowerTriangular<K>
+where
+    K: Copy + Zero + One + Sub<Output = K> + Mul<Output = K> + Div<Output = K>,
+{
+    /// Solves `L·x = b` by forward substitution in `O(n²)`.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixInverseError::Singular` - If a diagonal entry is zero
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, MatrixInverseError> {
+        debug_assert_eq!(self.size, b.len(), "Matrix rows must match vector length");
+
+        let n = self.size;
+        let mut x = vec![K::zero(); n];
+        for i in 0..n {
+            let pivot = self.get(i, i);
+            if pivot.is_zero() {
+                return Err(MatrixInverseError::Singular);
+            }
+
+            let mut sum = b[i];
+            for (j, &val) in x.iter().enumerate().take(i) {
+                sum = sum - self.get(i, j) * val;
+            }
+            x[i] = sum / pivot;
+        }
+        Ok(Vector::from(x))
+    }
+
+    /// Computes the inverse, which is again lower triangular.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixInverseError::Singular` - If a diagonal entry is zero
+    pub fn inverse(&self) -> Result<Self, MatrixInverseError> {
+        let n = self.size;
+        if (0..n).any(|i| self.get(i, i).is_zero()) {
+            return Err(MatrixInverseError::Singular);
+        }
+
+        // Column j of the inverse solves L·x = e_j and vanishes above row j
+        let mut inverse = Self::from_fn(n, |_, _| K::zero());
+        for j in 0..n {
+            let index = inverse.index(j, j);
+            inverse.data[index] = K::one() / self.get(j, j);
+            for i in j + 1..n {
+                let mut negated_sum = K::zero();
+                for k in j..i {
+                    negated_sum = negated_sum - self.get(i, k) * inverse.get(k, j);
+                }
+                let index = inverse.index(i, j);
+                inverse.data[index] = negated_sum / self.get(i, i);
+            }
+        }
+        Ok(inverse)
+    }
+}
+
+impl<K> TryFrom<&Matrix<K>> for LowerTriangular<K>
+where
+    K: Copy + Zero,
+{
+    type Error = PackedMatrixError;
+
+    fn try_from(matrix: &Matrix<K>) -> Result<Self, Self::Error> {
+        let n = square_size(matrix)?;
+        check_pattern(matrix, |i, j| i >= j)?;
+        Ok(Self::from_fn(n, |i, j| matrix[i][j]))
+    }
+}
+
+impl<K> From<&LowerTriangular<K>> for Matrix<K>
+where
+    K: Copy + Zero,
+{
+    fn from(lower: &LowerTriangular<K>) -> Self {
+        lower.to_matrix()
+    }
+}
+
+/// A square matrix equal to its transpose.
+///
+/// Only the `n(n + 1)/2` entries on and below the diagonal are stored. Solving
+/// and the determinant factor the stored triangle as `L·D·Lᵀ` with the
+/// Bunch–Kaufman pivoting of LAPACK's `sytrf`, which swaps rows and columns
+/// together so the matrix stays symmetric and packed.
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::{Matrix, Symmetric, Vector};
+///
+/// let s = Symmetric::from_fn(2, |i, j| if i == j { 2.0 } else { 1.0 });
+///
+/// assert_eq!(s.get(0, 1), s.get(1, 0));
+/// assert_eq!(s.determinant(), 3.0);
+/// assert_eq!(s.to_matrix(), Matrix::from([[2.0, 1.0], [1.0, 2.0]]));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Symmetric<K> {
+    size: usize,
+    data: Vec<K>,
+}
+
+impl<K> Symmetric<K> {
+    /// Creates an `n×n` symmetric matrix, calling `f(i, j)` for every entry
+    /// with `i >= j` and mirroring it to `(j, i)`.
+    pub fn from_fn(n: usize, mut f: impl FnMut(usize, usize) -> K) -> Self {
+        let data = (0..n)
+            .flat_map(|i| (0..=i).map(move |j| (i, j)))
+            .map(|(i, j)| f(i, j))
+            .collect();
+        Self { size: n, data }
+    }
+
+    /// Returns the number of rows (and columns) of the matrix.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    fn entries(&self) -> impl Iterator<Item = (usize, usize, K)> + '_
+    where
+        K: Copy,
+    {
+        (0..self.size)
+            .flat_map(|i| (0..=i).map(move |j| (i, j)))
+            .zip(&self.data)
+            .flat_map(|((i, j), &val)| {
+                std::iter::once((i, j, val)).chain((i != j).then_some((j, i, val)))
+            })
+    }
+}
+
+impl<K> Symmetric<K>
+where
+    K: Copy,
+{
+    /// Returns the entry at row `i` and column `j`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` or `j` is out of bounds.
+    pub fn get(&self, i: usize, j: usize) -> K {
+        assert!(i < self.size && j < self.size, "Index out of bounds");
+        self.data[symmetric_index(i, j)]
+    }
+}
+
+/// Position of entry `(i, j)` in the packed lower triangle of a `Symmetric`.
+fn symmetric_index(i: usize, j: usize) -> usize {
+    let (i, j) = if i >= j { (i, j) } else { (j, i) };
+    i * (i + 1) / 2 + j
+}
+
+/// Bunch–Kaufman threshold `(1 + √17) / 8`, which bounds the growth of the
+/// entries when choosing between single and 2x2 pivots.
+const BUNCH_KAUFMAN_ALPHA: f32 = 0.640_388_2;
+
+/// One pivot of the `L·D·Lᵀ` factorization of a `Symmetric` matrix.
+struct LdlPivot {
+    /// First row of the pivot
+    row: usize,
+    /// Row swapped with the last row of the pivot, which may be that row
+    swapped: usize,
+    /// Whether the pivot is a 2x2 block rather than a single entry
+    block: bool,
+}
+
+impl LdlPivot {
+    fn last(&self) -> usize {
+        if self.block {
+            self.row + 1
+        } else {
+            self.row
+        }
+    }
+}
+
+impl<K> Symmetric<K>
+where
+    K: Copy + Zero,
+{
+    /// Expands the matrix into dense storage.
+    pub fn to_matrix(&self) -> Matrix<K> {
+        dense_from_entries(self.size, self.entries())
+    }
+}
+
+impl<K> Symmetric<K>
+where
+    K: Copy + Zero + Add<Output = K> + Mul<Output = K>,
+{
+    /// Multiplies a vector by the matrix, like `Matrix::mul_vec`.
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        mul_vec_entries(self.size, self.entries(), vec)
+    }
+
+    /// Computes `S·B`.
+    pub fn mul_mat(&self, other: &Matrix<K>) -> Matrix<K> {
+        mul_mat_entries(self.size, self.entries(), other)
+    }
+}
+
+impl<K> Symmetric<K>
+where
+    K: Copy + Zero + Sub<Output = K> + Mul<Output = K> + Div<Output = K> + Magnitude<Output = f32>,
+{
+    /// Factors the matrix as `P·S·Pᵀ = L·D·Lᵀ` in a copy of the packed
+    /// triangle, where `D` has 1x1 and 2x2 blocks.
+    ///
+    /// On return, the diagonal blocks hold `D` and the entries below them the
+    /// multipliers of `L`. As in LAPACK, each swap only reorders the rows not
+    /// yet factored, so `solve` applies them in between the columns of `L`.
+    fn factor_ldl(&self) -> Result<(Vec<K>, Vec<LdlPivot>), MatrixInverseError> {
+        let n = self.size;
+        let at = symmetric_index;
+        let mut a = self.data.clone();
+        let mut pivots = Vec::new();
+
+        let mut k = 0;
+        while k < n {
+            if (k..n).all(|i| a[at(i, k)].may_be_zero()) {
+                return Err(MatrixInverseError::Singular);
+            }
+
+            // Largest entry below the diagonal, and the largest off the
+            // diagonal in its row, decide the pivot
+            let diagonal = a[at(k, k)].magnitude();
+            let (largest_row, column_max) =
+                (k + 1..n)
+                    .map(|i| (i, a[at(i, k)].magnitude()))
+                    .fold(
+                        (k, 0.0),
+                        |best, entry| if entry.1 > best.1 { entry } else { best },
+                    );
+            let pivot = if diagonal >= BUNCH_KAUFMAN_ALPHA * column_max {
+                (k, false)
+            } else {
+                let row_max = (k..n)
+                    .filter(|&j| j != largest_row)
+                    .map(|j| a[at(largest_row, j)].magnitude())
+                    .fold(0.0, f32::max);
+                if diagonal * row_max >= BUNCH_KAUFMAN_ALPHA * column_max * column_max {
+                    (k, false)
+                } else if a[at(largest_row, largest_row)].magnitude()
+                    >= BUNCH_KAUFMAN_ALPHA * row_max
+                {
+                    (largest_row, false)
+                } else {
+                    (largest_row, true)
+                }
+            };
+            let pivot = LdlPivot {
+                row: k,
+                swapped: pivot.0,
+                block: pivot.1,
+            };
+
+            // Swap rows and columns together within the unfactored part
+            let (last, other) = (pivot.last(), pivot.swapped);
+            if other != last {
+                for m in (k..n).filter(|&m| m != last && m != other) {
+                    a.swap(at(last, m), at(other, m));
+                }
+                a.swap(at(last, last), at(other, other));
+            }
+
+            // Rows are updated from the bottom up, so the entries of column k
+            // above row i still hold their values when row i reads them
+            if pivot.block {
+                let (d11, d21, d22) = (a[at(k, k)], a[at(k + 1, k)], a[at(k + 1, k + 1)]);
+                let det = d11 * d22 - d21 * d21;
+                if det.may_be_zero() {
+                    return Err(MatrixInverseError::Singular);
+                }
+                for i in (k + 2..n).rev() {
+                    let (c1, c2) = (a[at(i, k)], a[at(i, k + 1)]);
+                    let l1 = (c1 * d22 - c2 * d21) / det;
+                    let l2 = (c2 * d11 - c1 * d21) / det;
+                    for j in k + 2..=i {
+                        a[at(i, j)] = a[at(i, j)] - l1 * a[at(j, k)] - l2 * a[at(j, k + 1)];
+                    }
+                    a[at(i, k)] = l1;
+                    a[at(i, k + 1)] = l2;
+                }
+            } else {
+                let d = a[at(k, k)];
+                if d.may_be_zero() {
+                    return Err(MatrixInverseError::Singular);
+                }
+                for i in (k + 1..n).rev() {
+                    let l = a[at(i, k)] / d;
+                    for j in k + 1..=i {
+                        a[at(i, j)] = a[at(i, j)] - l * a[at(j, k)];
+                    }
+                    a[at(i, k)] = l;
+                }
+            }
+
+            k = pivot.last() + 1;
+            pivots.push(pivot);
+        }
+        Ok((a, pivots))
+    }
+
+    /// Solves `S·x = b` through the symmetric `L·D·Lᵀ` factorization, in
+    /// `O(n³)` operations and the packed `n(n + 1)/2` storage.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixInverseError::Singular` - If the system has no unique solution
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, MatrixInverseError> {
+        debug_assert_eq!(self.size, b.len(), "Matrix rows must match vector length");
+
+        let n = self.size;
+        let at = symmetric_index;
+        let (a, pivots) = self.factor_ldl()?;
+        let mut x = b.to_vec();
+
+        // L·D·y = b, swapping as the factorization did
+        for pivot in &pivots {
+            let (k, last) = (pivot.row, pivot.last());
+            x.swap(last, pivot.swapped);
+            for i in last + 1..n {
+                x[i] = x[i] - a[at(i, k)] * x[k];
+                if pivot.block {
+                    x[i] = x[i] - a[at(i, k + 1)] * x[k + 1];
+                }
+            }
+
+            if pivot.block {
+                let (d11, d21, d22) = (a[at(k, k)], a[at(k + 1, k)], a[at(k + 1, k + 1)]);
+                let det = d11 * d22 - d21 * d21;
+                let (y1, y2) = (x[k], x[k + 1]);
+                x[k] = (y1 * d22 - y2 * d21) / det;
+                x[k + 1] = (y2 * d11 - y1 * d21) / det;
+            } else {
+                x[k] = x[k] / a[at(k, k)];
+            }
+        }
+
+        // Lᵀ·x = y, undoing the swaps in reverse
+        for pivot in pivots.iter().rev() {
+            let (k, last) = (pivot.row, pivot.last());
+            for i in last + 1..n {
+                x[k] = x[k] - a[at(i, k)] * x[i];
+                if pivot.block {
+                    x[k + 1] = x[k + 1] - a[at(i, k + 1)] * x[i];
+                }
+            }
+            x.swap(last, pivot.swapped);
+        }
+
+        Ok(Vector::from(x))
+    }
+}
+
+impl<K> Symmetric<K>
+where
+    K: Copy
+        + Zero
+        + One
+        + Sub<Output = K>
+        + Mul<Output = K>
+        + Div<Output = K>
+        + Magnitude<Output = f32>,
+{
+    /// Computes the determinant as the product of the blocks of `D` in the
+    /// `L·D·Lᵀ` factorization. Swapping a row together with its column
+    /// leaves the determinant unchanged.
+    pub fn determinant(&self) -> K {
+        let at = symmetric_index;
+        let Ok((a, pivots)) = self.factor_ldl() else {
+            return K::zero();
+        };
+
+        pivots.iter().fold(K::one(), |det, pivot| {
+            let k = pivot.row;
+            if pivot.block {
+                det * (a[at(k, k)] * a[at(k + 1, k + 1)] - a[at(k + 1, k)] * a[at(k + 1, k)])
+            } else {
+                det * a[at(k, k)]
+            }
+        })
+    }
+}
+
+impl<K> TryFrom<&Matrix<K>> for Symmetric<K>
+where
+    K: Copy + PartialEq,
+{
+    type Error = PackedMatrixError;
+
+    fn try_from(matrix: &Matrix<K>) -> Result<Self, Self::Error> {
+        let n = square_size(matrix)?;
+        for i in 0..n {
+            for j in 0..i {
+                if matrix[i][j] != matrix[j][i] {
+                    return Err(PackedMatrixError::NotSymmetric { row: i, col: j });
+                }
+            }
+        }
+        Ok(Self::from_fn(n, |i, j| matrix[i][j]))
+    }
+}
+
+impl<K> From<&Symmetric<K>> for Matrix<K>
+where
+    K: Copy + Zero,
+{
+    fn from(symmetric: &Symmetric<K>) -> Self {
+        symmetric.to_matrix()
+    }
+}
+
+/// A square matrix whose non-zero entries lie within `lower` diagonals below
+/// and `upper` diagonals above the main diagonal.
+///
+/// Each row stores the `lower + upper + 1` entries of the band, so a
+/// tridiagonal matrix takes `3n` entries instead of `n²`. Solving runs
+/// Gaussian elimination with partial pivoting inside the band in
+/// `O(n·lower·(lower + upper))`.
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::{Banded, Vector};
+///
+/// // Tridiagonal [-1, 2, -1] stencil
+/// let t = Banded::from_fn(4, 1, 1, |i, j| if i == j { 2.0 } else { -1.0 });
+///
+/// assert_eq!(t.get(0, 2), 0.0);
+/// assert!((t.determinant() - 5.0_f64).abs() < 1e-12);
+///
+/// let b = Vector::from([1.0, 0.0, 0.0, 1.0]);
+/// let x = t.solve(&b).unwrap();
+/// assert!(x.iter().all(|&v| (v - 1.0_f64).abs() < 1e-12));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Banded<K> {
+    size: usize,
+    lower: usize,
+    upper: usize,
+    data: Vec<K>,
+}
+
+impl<K> Banded<K> {
+    /// Returns the number of rows (and columns) of the matrix.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the number of stored diagonals below the main diagonal.
+    pub fn lower_bandwidth(&self) -> usize {
+        self.lower
+    }
+
+    /// Returns the number of stored diagonals above the main diagonal.
+    pub fn upper_bandwidth(&self) -> usize {
+        self.upper
+    }
+
+    /// Checks if entry `(i, j)` lies within the band.
+    fn in_band(&self, i: usize, j: usize) -> bool {
+        j + self.lower >= i && j <= i + self.upper
+    }
+
+    /// Position of in-band entry `(i, j)` in the packed storage.
+    fn index(&self, i: usize, j: usize) -> usize {
+        i * (self.lower + self.upper + 1) + j + self.lower - i
+    }
+
+    /// Columns of row `i` that lie within the band.
+    fn band_columns(&self, i: usize) -> std::ops::Range<usize> {
+        i.saturating_sub(self.lower)..(i + self.upper + 1).min(self.size)
+    }
+
+    fn entries(&self) -> impl Iterator<Item = (usize, usize, K)> + '_
+    where
+        K: Copy,
+    {
+        (0..self.size).flat_map(move |i| {
+            self.band_columns(i)
+                .map(move |j| (i, j, self.data[self.index(i, j)]))
+        })
+    }
+}
+
+impl<K> Banded<K>
+where
+    K: Zero,
+{
+    /// Creates an `n×n` band matrix, calling `f(i, j)` for every entry with
+    /// `i - lower <= j <= i + upper`.
+    pub fn from_fn(
+        n: usize,
+        lower: usize,
+        upper: usize,
+        mut f: impl FnMut(usize, usize) -> K,
+    ) -> Self {
+        let mut data = Vec::with_capacity(n * (lower + upper + 1));
+        for i in 0..n {
+            // Band slots that fall outside the matrix are padded with zeros
+            for j in (i as isize - lower as isize)..=(i + upper) as isize {
+                if j < 0 || j >= n as isize {
+                    data.push(K::zero());
+                } else {
+                    data.push(f(i, j as usize));
+                }
+            }
+        }
+        Self {
+            size: n,
+            lower,
+            upper,
+            data,
+        }
+    }
+}
+
+impl<K> Banded<K>
+where
+    K: Copy + Zero,
+{
+    /// Returns the entry at row `i` and column `j`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` or `j` is out of bounds.
+    pub fn get(&self, i: usize, j: usize) -> K {
+        assert!(i < self.size && j < self.size, "Index out of bounds");
+        if self.in_band(i, j) {
+            self.data[self.index(i, j)]
+        } else {
+            K::zero()
+        }
+    }
+
+    /// Expands the matrix into dense storage.
+    pub fn to_matrix(&self) -> Matrix<K> {
+        dense_from_entries(self.size, self.entries())
+    }
+
+    /// Returns the transpose, whose lower and upper bandwidths are swapped.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.size, self.upper, self.lower, |i, j| self.get(j, i))
+    }
+}
+
+impl<K> Banded<K>
+where
+    K: Copy + Zero + Add<Output = K> + Mul<Output = K>,
+{
+    /// Multiplies a vector by the matrix, like `Matrix::mul_vec`, skipping the
+    /// entries outside the band.
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        mul_vec_entries(self.size, self.entries(), vec)
+    }
+
+    /// Computes `A·B`, skipping the entries outside the band.
+    pub fn mul_mat(&self, other: &Matrix<K>) -> Matrix<K> {
+        mul_mat_entries(self.size, self.entries(), other)
+    }
+}
+
+impl<K> Banded<K>
+where
+    K: Copy + Zero + Sub<Output = K> + Mul<Output = K> + Div<Output = K> + Magnitude<Output = f32>,
+{
+    /// Position of entry `(i, j)` in the working storage of `eliminate`.
+    fn reduced_index(&self, i: usize, j: usize) -> usize {
+        i * (2 * self.lower + self.upper + 1) + j + self.lower - i
+    }
+
+    /// Reduces the matrix to upper triangular form by Gaussian elimination
+    /// with partial pivoting, applying the same row operations to `rhs` unless
+    /// it is empty.
+    ///
+    /// Row swaps widen the upper bandwidth to `lower + upper`, so every row
+    /// of the working storage keeps `2·lower + upper + 1` entries. Returns the
+    /// reduced rows, laid out as in `reduced_index`, and whether an odd number
+    /// of rows were swapped.
+    fn eliminate(&self, rhs: &mut [K]) -> Result<(Vec<K>, bool), MatrixInverseError> {
+        let n = self.size;
+        let width = 2 * self.lower + self.upper + 1;
+        let mut work = vec![K::zero(); n * width];
+        for (i, j, val) in self.entries() {
+            work[self.reduced_index(i, j)] = val;
+        }
+
+        let mut odd_swaps = false;
+        for k in 0..n {
+            let last_row = (k + self.lower).min(n - 1);
+            let last_col = (k + self.lower + self.upper).min(n - 1);
+
+            let column = (k..=last_row).map(|r| work[self.reduced_index(r, k)]);
+            let pivot_row = match find_pivot(column, 0) {
+                Some(offset) => k + offset,
+                None => return Err(MatrixInverseError::Singular),
+            };
+            if pivot_row != k {
+                for j in k..=last_col {
+                    work.swap(self.reduced_index(k, j), self.reduced_index(pivot_row, j));
+                }
+                if !rhs.is_empty() {
+                    rhs.swap(k, pivot_row);
+                }
+                odd_swaps = !odd_swaps;
+            }
+
+            let pivot = work[self.reduced_index(k, k)];
+            for r in k + 1..=last_row {
+                let factor = work[self.reduced_index(r, k)] / pivot;
+                if factor.is_zero() {
+                    continue;
+                }
+                for j in k..=last_col {
+                    let target = self.reduced_index(r, j);
+                    work[target] = work[target] - factor * work[self.reduced_index(k, j)];
+                }
+                if !rhs.is_empty() {
+                    rhs[r] = rhs[r] - factor * rhs[k];
+                }
+            }
+        }
+        Ok((work, odd_swaps))
+    }
+
+    /// Solves `A·x = b` by banded Gaussian elimination with partial pivoting.
+    ///
+    /// # Errors
+    ///
+    /// * `MatrixInverseError::Singular` - If the system has no unique solution
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, MatrixInverseError> {
+        debug_assert_eq!(self.size, b.len(), "Matrix rows must match vector length");
+
+        let n = self.size;
+        let mut rhs = b.to_vec();
+        let (work, _) = self.eliminate(&mut rhs)?;
+
+        let mut x = vec![K::zero(); n];
+        for i in (0..n).rev() {
+            let last_col = (i + self.lower + self.upper).min(n - 1);
+            let mut sum = rhs[i];
+            for j in i + 1..=last_col {
+                sum = sum - work[self.reduced_index(i, j)] * x[j];
+            }
+            x[i] = sum / work[self.reduced_index(i, i)];
+        }
+        Ok(Vector::from(x))
+    }
+}
+
+impl<K> Banded<K>
+where
+    K: Copy
+        + Zero
+        + One
+        + Negative
+        + Sub<Output = K>
+        + Mul<Output = K>
+        + Div<Output = K>
+        + Magnitude<Output = f32>,
+{
+    /// Computes the determinant by banded Gaussian elimination.
+    pub fn determinant(&self) -> K {
+        match self.eliminate(&mut []) {
+            Ok((work, odd_swaps)) => {
+                let sign = if odd_swaps {
+                    K::negative_one()
+                } else {
+                    K::one()
+                };
+                (0..self.size).fold(sign, |acc, i| acc * work[self.reduced_index(i, i)])
+            }
+            Err(_) => K::zero(),
+        }
+    }
+}
+
+impl<K> TryFrom<&Matrix<K>> for Banded<K>
+where
+    K: Copy + Zero,
+{
+    type Error = PackedMatrixError;
+
+    /// Converts a dense matrix, choosing the narrowest band that holds all of
+    /// its non-zero entries.
+    fn try_from(matrix: &Matrix<K>) -> Result<Self, Self::Error> {
+        let n = square_size(matrix)?;
+        let mut lower = 0;
+        let mut upper = 0;
+        for (i, row) in matrix.iter().enumerate() {
+            for (j, val) in row.iter().enumerate() {
+                if !val.is_zero() {
+                    lower = lower.max(i.saturating_sub(j));
+                    upper = upper.max(j.saturating_sub(i));
+                }
+            }
+        }
+        Ok(Self::from_fn(n, lower, upper, |i, j| matrix[i][j]))
+    }
+}
+
+impl<K> From<&Banded<K>> for Matrix<K>
+where
+    K: Copy + Zero,
+{
+    fn from(banded: &Banded<K>) -> Self {
+        banded.to_matrix()
+    }
+}
@@ -0,0 +1,364 @@
+//! # Parsing
+//!
+//! This module implements `FromStr` for `Vector`, `Matrix` and `Complex`,
+//! the inverse of their `Display` implementations.
+//!
+//! Entries are separated by commas and parsed with the element type's own
+//! `FromStr`, so they may contain spaces as in `1 + 2i`. A matrix can be
+//! written in any of these forms:
+//!
+//! * One bracketed row per line, as printed by `Display`: `[1, 2]\n[3, 4]`
+//! * Rows separated by semicolons: `[1, 2; 3, 4]`
+//! * Nested JSON-style arrays: `[[1, 2], [3, 4]]`
+
+use std::str::{Chars, FromStr};
+
+use crate::{errors::ParseError, Complex, Matrix, Vector};
+
+/// Reads characters while tracking their 1-based line and column.
+#[derive(Clone)]
+struct Cursor<'a> {
+    chars: Chars<'a>,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            chars: text.chars(),
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.clone().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    /// Error for the character under the cursor, or for the end of the text.
+    fn unexpected(&self) -> ParseError {
+        match self.peek() {
+            Some(found) => ParseError::UnexpectedCharacter {
+                line: self.line,
+                column: self.column,
+                found,
+            },
+            None => ParseError::UnexpectedEnd {
+                line: self.line,
+                column: self.column,
+            },
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if self.peek() == Some(expected) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    /// Succeeds if only whitespace is left.
+    fn finish(&mut self) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(_) => Err(self.unexpected()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A row of entries with the position where it starts.
+struct Row<K> {
+    entries: Vec<K>,
+    line: usize,
+    column: usize,
+}
+
+/// Parses one entry, which runs up to the next `,`, `;` or `]`.
+fn parse_entry<K>(cursor: &mut Cursor) -> Result<K, ParseError>
+where
+    K: FromStr,
+{
+    cursor.skip_whitespace();
+    let (line, column) = (cursor.line, cursor.column);
+
+    let mut text = String::new();
+    while let Some(c) = cursor.peek() {
+        match c {
+            ',' | ';' | ']' => break,
+            '[' => return Err(cursor.unexpected()),
+            _ => {
+                text.push(c);
+                cursor.bump();
+            }
+        }
+    }
+
+    let text = text.trim_end();
+    if text.is_empty() {
+        return Err(cursor.unexpected());
+    }
+    text.parse().map_err(|_| ParseError::InvalidNumber {
+        line,
+        column,
+        text: text.to_string(),
+    })
+}
+
+/// Parses the contents of a bracket after its `[`, up to and including the
+/// closing `]`. Rows are split at semicolons when `rows` is true.
+fn parse_group<K>(cursor: &mut Cursor, rows: bool) -> Result<Vec<Row<K>>, ParseError>
+where
+    K: FromStr,
+{
+    cursor.skip_whitespace();
+    let mut group = vec![Row {
+        entries: Vec::new(),
+        line: cursor.line,
+        column: cursor.column,
+    }];
+    if cursor.peek() == Some(']') {
+        cursor.bump();
+        return Ok(group);
+    }
+
+    loop {
+        let entry = parse_entry(cursor)?;
+        group.last_mut().unwrap().entries.push(entry);
+
+        match cursor.peek() {
+            Some(',') => {
+                cursor.bump();
+            }
+            Some(';') if rows => {
+                cursor.bump();
+                cursor.skip_whitespace();
+                group.push(Row {
+                    entries: Vec::new(),
+                    line: cursor.line,
+                    column: cursor.column,
+                });
+            }
+            Some(']') => {
+                cursor.bump();
+                return Ok(group);
+            }
+            _ => return Err(cursor.unexpected()),
+        }
+    }
+}
+
+impl<K> FromStr for Vector<K>
+where
+    K: FromStr,
+{
+    type Err = ParseError;
+
+    /// Parses a vector written as `[1, 2, 3]`, the format of its `Display`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` with the line and column of the first problem:
+    /// a missing bracket, an entry `K` cannot parse, or trailing text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::{Complex, Vector};
+    ///
+    /// let v: Vector<f64> = "[1, -2.5, 3e2]".parse().unwrap();
+    /// assert_eq!(v, Vector::from([1.0, -2.5, 300.0]));
+    ///
+    /// let z = Vector::from([Complex::new(1.0, 2.0), Complex::new(0.0, -1.0)]);
+    /// assert_eq!(z.to_string().parse::<Vector<Complex>>().unwrap(), z);
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut cursor = Cursor::new(text);
+        cursor.expect('[')?;
+        let mut group = parse_group(&mut cursor, false)?;
+        cursor.finish()?;
+
+        Ok(Vector::from(group.pop().unwrap().entries))
+    }
+}
+
+impl<K> FromStr for Matrix<K>
+where
+    K: FromStr,
+{
+    type Err = ParseError;
+
+    /// Parses a matrix written one bracketed row per line as printed by its
+    /// `Display`, as `[1, 2; 3, 4]`, or as nested arrays `[[1, 2], [3, 4]]`.
+    ///
+    /// Text with only whitespace, or `[]`, is the empty matrix. Rows split
+    /// by semicolons cannot be followed by more bracketed rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` with the line and column of the first problem.
+    /// A row whose length differs from the first row's is a
+    /// `ParseError::RaggedRow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::{Matrix, ParseError};
+    ///
+    /// let expected = Matrix::from([[1, 2], [3, 4]]);
+    /// assert_eq!("[1, 2; 3, 4]".parse::<Matrix<i32>>().unwrap(), expected);
+    /// assert_eq!("[[1, 2], [3, 4]]".parse::<Matrix<i32>>().unwrap(), expected);
+    /// assert_eq!(expected.to_string().parse::<Matrix<i32>>().unwrap(), expected);
+    ///
+    /// let ragged = "[1, 2]\n[3]".parse::<Matrix<i32>>();
+    /// assert!(matches!(
+    ///     ragged,
+    ///     Err(ParseError::RaggedRow { line: 2, column: 2, row: 2, expected: 2, found: 1 })
+    /// ));
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut cursor = Cursor::new(text);
+        let mut rows = Vec::new();
+
+        cursor.skip_whitespace();
+        if cursor.peek().is_none() {
+            return Ok(Matrix::from_rows(Vec::new()));
+        }
+
+        cursor.expect('[')?;
+        cursor.skip_whitespace();
+        if cursor.peek() == Some(']') {
+            cursor.bump();
+            cursor.finish()?;
+            return Ok(Matrix::from_rows(Vec::new()));
+        }
+
+        if cursor.peek() == Some('[') {
+            // Nested arrays
+            loop {
+                cursor.expect('[')?;
+                rows.extend(parse_group(&mut cursor, false)?);
+                cursor.skip_whitespace();
+                match cursor.peek() {
+                    Some(',') => {
+                        cursor.bump();
+                    }
+                    Some(']') => {
+                        cursor.bump();
+                        break;
+                    }
+                    _ => return Err(cursor.unexpected()),
+                }
+            }
+        } else {
+            // Semicolon separated rows, or the first of several bracketed rows
+            let group = parse_group(&mut cursor, true)?;
+            let semicolons = group.len() > 1;
+            rows.extend(group);
+            cursor.skip_whitespace();
+            while !semicolons && cursor.peek() == Some('[') {
+                cursor.bump();
+                rows.extend(parse_group(&mut cursor, false)?);
+                cursor.skip_whitespace();
+            }
+        }
+        cursor.finish()?;
+
+        let expected = rows[0].entries.len();
+        if let Some((index, row)) = rows
+            .iter()
+            .enumerate()
+            .find(|(_, row)| row.entries.len() != expected)
+        {
+            return Err(ParseError::RaggedRow {
+                line: row.line,
+                column: row.column,
+                row: index + 1,
+                expected,
+                found: row.entries.len(),
+            });
+        }
+
+        Ok(Matrix::from_rows(
+            rows.into_iter().map(|row| row.entries).collect(),
+        ))
+    }
+}
+
+impl FromStr for Complex {
+    type Err = ParseError;
+
+    /// Parses a complex number written `a`, `bi`, `a + bi` or `a - bi`, where
+    /// `b` may be left out for `i` and `-i`, as printed by its `Display`.
+    ///
+    /// Whitespace is ignored, so `1+2i` and `1 + 2i` are the same number.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::InvalidNumber` if either part is not a number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Complex;
+    ///
+    /// assert_eq!("3 - 4i".parse::<Complex>().unwrap(), Complex::new(3.0, -4.0));
+    /// assert_eq!("-i".parse::<Complex>().unwrap(), Complex::new(0.0, -1.0));
+    /// assert_eq!("2.5".parse::<Complex>().unwrap(), Complex::new(2.5, 0.0));
+    /// assert!("1 + 2j".parse::<Complex>().is_err());
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let leading = text.chars().take_while(|c| c.is_whitespace()).count();
+        let invalid = || ParseError::InvalidNumber {
+            line: 1,
+            column: leading + 1,
+            text: text.trim().to_string(),
+        };
+        let compact: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+
+        let Some(body) = compact.strip_suffix('i') else {
+            let real = compact.parse().map_err(|_| invalid())?;
+            return Ok(Complex::new(real, 0.0));
+        };
+
+        // The imaginary part starts at the last sign that is not an exponent's
+        let split = body
+            .char_indices()
+            .rev()
+            .find(|&(k, c)| (c == '+' || c == '-') && k > 0 && !body[..k].ends_with(['e', 'E']))
+            .map_or(0, |(k, _)| k);
+        let (real, imaginary) = body.split_at(split);
+        let imaginary = match imaginary {
+            "" | "+" => "1",
+            "-" => "-1",
+            digits => digits,
+        };
+        let real = if real.is_empty() { "0" } else { real };
+
+        match (real.parse(), imaginary.parse()) {
+            (Ok(real), Ok(imaginary)) => Ok(Complex::new(real, imaginary)),
+            _ => Err(invalid()),
+        }
+    }
+}
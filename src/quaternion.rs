@@ -0,0 +1,507 @@
+//! # Quaternions
+//!
+//! This module provides quaternions and their conversions to rotation matrices
+//! and axis-angle representations.
+//!
+//! Rotation matrices follow the crate's row-vector convention: a vector is
+//! transformed with `matrix.mul_vec(&v)`, which computes `v·M`. The matrices
+//! returned here are therefore the transpose of the textbook column-vector form.
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::{
+    cross_product,
+    errors::{InterpolationError, QuaternionError},
+    traits::{Conjugate, Float, Magnitude, One, Zero},
+    Matrix, Vector,
+};
+
+/// Represents a quaternion `w + xi + yj + zk`.
+///
+/// Unit quaternions represent rotations in three-dimensional space. The
+/// `Quaternion<T>` type implements the Hamilton product through `*`, the
+/// conjugate through the [`Conjugate`] trait, and conversions to and from
+/// rotation matrices and axis-angle pairs.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::f64::consts::FRAC_PI_2;
+/// use linear_algebra_42::{Quaternion, Vector};
+///
+/// let axis = Vector::from([0.0, 0.0, 1.0]);
+/// let q = Quaternion::from_axis_angle(&axis, FRAC_PI_2).unwrap();
+///
+/// let rotated = q.rotate_vector(&Vector::from([1.0, 0.0, 0.0]));
+/// assert!((rotated[0] - 0.0).abs() < 1e-12);
+/// assert!((rotated[1] - 1.0).abs() < 1e-12);
+///
+/// // The rotation matrix applies the same rotation through `mul_vec`
+/// let m = q.to_rotation_matrix3();
+/// let by_matrix = m.mul_vec(&Vector::from([1.0, 0.0, 0.0]));
+/// assert!((by_matrix[1] - 1.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion<T> {
+    w: T,
+    x: T,
+    y: T,
+    z: T,
+}
+
+impl<T> Quaternion<T>
+where
+    T: Copy,
+{
+    /// Creates a new quaternion `w + xi + yj + zk`.
+    ///
+    /// # Arguments
+    ///
+    /// * `w` - The scalar (real) part
+    /// * `x`, `y`, `z` - The components of the vector (imaginary) part
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        Quaternion { w, x, y, z }
+    }
+
+    /// Returns the scalar part `w`.
+    pub fn w(&self) -> T {
+        self.w
+    }
+
+    /// Returns the `i` component.
+    pub fn x(&self) -> T {
+        self.x
+    }
+
+    /// Returns the `j` component.
+    pub fn y(&self) -> T {
+        self.y
+    }
+
+    /// Returns the `k` component.
+    pub fn z(&self) -> T {
+        self.z
+    }
+
+    /// Returns the vector part `[x, y, z]`.
+    pub fn vector_part(&self) -> Vector<T> {
+        Vector::from([self.x, self.y, self.z])
+    }
+}
+
+impl<T> Quaternion<T>
+where
+    T: Copy + Zero + One,
+{
+    /// Returns the identity quaternion `1 + 0i + 0j + 0k` (no rotation).
+    pub fn identity() -> Self {
+        Quaternion::new(T::one(), T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl<T> Quaternion<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    /// Computes the four-dimensional dot product of two quaternions.
+    pub fn dot(&self, other: &Self) -> T {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Returns the squared norm `w² + x² + y² + z²`.
+    pub fn norm_squared(&self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T> Quaternion<T>
+where
+    T: Float,
+{
+    /// Returns the norm (length) of the quaternion.
+    pub fn norm(&self) -> T {
+        self.norm_squared().sqrt()
+    }
+
+    /// Returns the quaternion scaled to unit length.
+    ///
+    /// # Errors
+    ///
+    /// Returns `QuaternionError::ZeroNorm` for the zero quaternion.
+    pub fn normalize(&self) -> Result<Self, QuaternionError> {
+        let norm = self.norm();
+        if norm.is_zero() {
+            return Err(QuaternionError::ZeroNorm);
+        }
+
+        Ok(self.scale(T::one() / norm))
+    }
+
+    /// Returns the multiplicative inverse `q* / |q|²`.
+    ///
+    /// For unit quaternions the inverse equals the conjugate.
+    ///
+    /// # Errors
+    ///
+    /// Returns `QuaternionError::ZeroNorm` for the zero quaternion.
+    pub fn inverse(&self) -> Result<Self, QuaternionError> {
+        let norm_squared = self.norm_squared();
+        if norm_squared.is_zero() {
+            return Err(QuaternionError::ZeroNorm);
+        }
+
+        Ok(self.conjugate().scale(T::one() / norm_squared))
+    }
+
+    /// Multiplies every component by `scalar`.
+    pub fn scale(&self, scalar: T) -> Self {
+        Quaternion::new(
+            self.w * scalar,
+            self.x * scalar,
+            self.y * scalar,
+            self.z * scalar,
+        )
+    }
+
+    /// Creates the unit quaternion rotating by `angle` radians around `axis`.
+    ///
+    /// The axis does not need to be normalized.
+    ///
+    /// # Errors
+    ///
+    /// * `QuaternionError::InvalidVectorLength` - If `axis` does not have 3 elements
+    /// * `QuaternionError::ZeroNorm` - If `axis` is the zero vector
+    pub fn from_axis_angle(axis: &Vector<T>, angle: T) -> Result<Self, QuaternionError> {
+        let axis = unit_axis(axis)?;
+        let half = angle / T::from_f64(2.0);
+        let sin = half.sin();
+
+        Ok(Quaternion::new(
+            half.cos(),
+            axis[0] * sin,
+            axis[1] * sin,
+            axis[2] * sin,
+        ))
+    }
+
+    /// Returns the rotation represented by the quaternion as a unit axis and an
+    /// angle in radians, in the range `[0, 2π]`.
+    ///
+    /// The identity rotation has no defined axis; `[1, 0, 0]` is returned for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `QuaternionError::ZeroNorm` for the zero quaternion.
+    pub fn to_axis_angle(&self) -> Result<(Vector<T>, T), QuaternionError> {
+        let q = self.normalize()?;
+        let sin_half = q.vector_part().dot(&q.vector_part()).sqrt();
+        let angle = T::from_f64(2.0) * sin_half.atan2(q.w);
+
+        if sin_half <= T::epsilon() {
+            return Ok((Vector::from([T::one(), T::zero(), T::zero()]), angle));
+        }
+
+        let axis = Vector::from([q.x / sin_half, q.y / sin_half, q.z / sin_half]);
+        Ok((axis, angle))
+    }
+
+    /// Creates the shortest-arc rotation taking the direction of `from` onto `to`.
+    ///
+    /// The rotation axis is the cross product `from × to`. When the vectors are
+    /// opposite, any axis perpendicular to `from` is used.
+    ///
+    /// # Errors
+    ///
+    /// * `QuaternionError::InvalidVectorLength` - If either vector does not have 3 elements
+    /// * `QuaternionError::ZeroNorm` - If either vector is the zero vector
+    pub fn rotation_between(from: &Vector<T>, to: &Vector<T>) -> Result<Self, QuaternionError> {
+        let from = unit_axis(from)?;
+        let to = unit_axis(to)?;
+
+        let w = T::one() + from.dot(&to);
+        if w <= T::epsilon() {
+            let x_axis = Vector::from([T::one(), T::zero(), T::zero()]);
+            let mut axis = cross_product(&x_axis, &from);
+            if axis.dot(&axis) <= T::epsilon() {
+                let y_axis = Vector::from([T::zero(), T::one(), T::zero()]);
+                axis = cross_product(&y_axis, &from);
+            }
+            let axis = unit_axis(&axis)?;
+            return Ok(Quaternion::new(T::zero(), axis[0], axis[1], axis[2]));
+        }
+
+        let axis = cross_product(&from, &to);
+        Quaternion::new(w, axis[0], axis[1], axis[2]).normalize()
+    }
+
+    /// Rotates a three-dimensional vector by this (unit) quaternion.
+    ///
+    /// Computes `v' = v + w·t + q⃗ × t`, with `t = 2·(q⃗ × v)`, which is
+    /// equivalent to `q·v·q*` but cheaper.
+    pub fn rotate_vector(&self, v: &Vector<T>) -> Vector<T> {
+        debug_assert_eq!(v.len(), 3, "Only three-dimensional vectors can be rotated");
+
+        let q = self.vector_part();
+        let mut t = cross_product(&q, v);
+        t.scl(T::from_f64(2.0));
+
+        let mut result = v.add_new(&t.scl_new(self.w));
+        result.add_inline(&cross_product(&q, &t));
+        result
+    }
+
+    /// Spherical linear interpolation between two unit quaternions.
+    ///
+    /// Interpolates along the shortest arc at constant angular velocity,
+    /// falling back to normalized linear interpolation for nearly identical
+    /// rotations.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InterpolationError::InvalidParameterT` if `t` is not in `[0, 1]`.
+    pub fn slerp(&self, other: &Self, t: T) -> Result<Self, InterpolationError> {
+        if t < T::zero() || t > T::one() {
+            return Err(InterpolationError::InvalidParameterT {
+                t: t.to_f64() as f32,
+            });
+        }
+
+        let mut end = *other;
+        let mut cos_theta = self.dot(other);
+        if cos_theta < T::zero() {
+            end = -end;
+            cos_theta = -cos_theta;
+        }
+
+        let (from_weight, to_weight) = if cos_theta > T::one() - T::from_f64(1e-6) {
+            (T::one() - t, t)
+        } else {
+            let theta = cos_theta.acos();
+            let sin_theta = theta.sin();
+            (
+                ((T::one() - t) * theta).sin() / sin_theta,
+                (t * theta).sin() / sin_theta,
+            )
+        };
+
+        let result = self.scale(from_weight) + end.scale(to_weight);
+        Ok(result.normalize().unwrap_or(result))
+    }
+
+    /// Converts the quaternion into a 3×3 rotation matrix.
+    ///
+    /// The quaternion is normalized first; the zero quaternion yields the identity.
+    pub fn to_rotation_matrix3(&self) -> Matrix<T> {
+        let q = self.normalize().unwrap_or_else(|_| Self::identity());
+        let two = T::from_f64(2.0);
+        let one = T::one();
+
+        let (xx, yy, zz) = (q.x * q.x, q.y * q.y, q.z * q.z);
+        let (xy, xz, yz) = (q.x * q.y, q.x * q.z, q.y * q.z);
+        let (wx, wy, wz) = (q.w * q.x, q.w * q.y, q.w * q.z);
+
+        Matrix::from([
+            [one - two * (yy + zz), two * (xy + wz), two * (xz - wy)],
+            [two * (xy - wz), one - two * (xx + zz), two * (yz + wx)],
+            [two * (xz + wy), two * (yz - wx), one - two * (xx + yy)],
+        ])
+    }
+
+    /// Converts the quaternion into a 4×4 homogeneous rotation matrix.
+    pub fn to_rotation_matrix4(&self) -> Matrix<T> {
+        let r = self.to_rotation_matrix3();
+        let zero = T::zero();
+
+        Matrix::from([
+            [r[0][0], r[0][1], r[0][2], zero],
+            [r[1][0], r[1][1], r[1][2], zero],
+            [r[2][0], r[2][1], r[2][2], zero],
+            [zero, zero, zero, T::one()],
+        ])
+    }
+
+    /// Extracts the rotation quaternion from a 3×3 or 4×4 rotation matrix.
+    ///
+    /// Only the upper-left 3×3 block is read. The matrix is expected to be a
+    /// proper rotation in the crate's row-vector layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns `QuaternionError::InvalidMatrixDimensions` if the matrix is
+    /// neither 3×3 nor 4×4.
+    pub fn from_rotation_matrix(matrix: &Matrix<T>) -> Result<Self, QuaternionError> {
+        let (rows, columns) = matrix.shape();
+        if !matrix.is_square() || !(rows == 3 || rows == 4) {
+            return Err(QuaternionError::InvalidMatrixDimensions { rows, columns });
+        }
+
+        // Textbook (column-vector) entry R[i][j] is stored at matrix[j][i].
+        let r = |i: usize, j: usize| matrix[j][i];
+        let one = T::one();
+        let two = T::from_f64(2.0);
+        let quarter = T::from_f64(0.25);
+        let trace = r(0, 0) + r(1, 1) + r(2, 2);
+
+        let q = if trace > T::zero() {
+            let s = (trace + one).sqrt() * two;
+            Quaternion::new(
+                quarter * s,
+                (r(2, 1) - r(1, 2)) / s,
+                (r(0, 2) - r(2, 0)) / s,
+                (r(1, 0) - r(0, 1)) / s,
+            )
+        } else if r(0, 0) > r(1, 1) && r(0, 0) > r(2, 2) {
+            let s = (one + r(0, 0) - r(1, 1) - r(2, 2)).sqrt() * two;
+            Quaternion::new(
+                (r(2, 1) - r(1, 2)) / s,
+                quarter * s,
+                (r(0, 1) + r(1, 0)) / s,
+                (r(0, 2) + r(2, 0)) / s,
+            )
+        } else if r(1, 1) > r(2, 2) {
+            let s = (one + r(1, 1) - r(0, 0) - r(2, 2)).sqrt() * two;
+            Quaternion::new(
+                (r(0, 2) - r(2, 0)) / s,
+                (r(0, 1) + r(1, 0)) / s,
+                quarter * s,
+                (r(1, 2) + r(2, 1)) / s,
+            )
+        } else {
+            let s = (one + r(2, 2) - r(0, 0) - r(1, 1)).sqrt() * two;
+            Quaternion::new(
+                (r(1, 0) - r(0, 1)) / s,
+                (r(0, 2) + r(2, 0)) / s,
+                (r(1, 2) + r(2, 1)) / s,
+                quarter * s,
+            )
+        };
+
+        q.normalize()
+    }
+}
+
+/// Validates a three-dimensional axis and scales it to unit length.
+fn unit_axis<T>(axis: &Vector<T>) -> Result<Vector<T>, QuaternionError>
+where
+    T: Float,
+{
+    if axis.len() != 3 {
+        return Err(QuaternionError::InvalidVectorLength { len: axis.len() });
+    }
+
+    let norm = axis.dot(axis).sqrt();
+    if norm.is_zero() {
+        return Err(QuaternionError::ZeroNorm);
+    }
+
+    Ok(axis.scl_new(T::one() / norm))
+}
+
+impl<T> Zero for Quaternion<T>
+where
+    T: Copy + Zero,
+{
+    fn zero() -> Self {
+        Quaternion::new(T::zero(), T::zero(), T::zero(), T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.w.is_zero() && self.x.is_zero() && self.y.is_zero() && self.z.is_zero()
+    }
+
+    fn is_negligible(&self) -> bool {
+        self.w.is_negligible()
+            && self.x.is_negligible()
+            && self.y.is_negligible()
+            && self.z.is_negligible()
+    }
+}
+
+impl<T> One for Quaternion<T>
+where
+    T: Copy + Zero + One,
+{
+    fn one() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T> Conjugate for Quaternion<T>
+where
+    T: Copy + Neg<Output = T>,
+{
+    fn conjugate(&self) -> Self {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+impl<T> Magnitude for Quaternion<T>
+where
+    T: Float,
+{
+    type Output = f32;
+
+    fn magnitude(&self) -> Self::Output {
+        self.norm().to_f64() as f32
+    }
+}
+
+impl<T> Add for Quaternion<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Quaternion::new(
+            self.w + other.w,
+            self.x + other.x,
+            self.y + other.y,
+            self.z + other.z,
+        )
+    }
+}
+
+impl<T> Sub for Quaternion<T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Quaternion::new(
+            self.w - other.w,
+            self.x - other.x,
+            self.y - other.y,
+            self.z - other.z,
+        )
+    }
+}
+
+impl<T> Mul for Quaternion<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    /// Hamilton product. Not commutative: `a * b` applies `b` first, then `a`.
+    fn mul(self, other: Self) -> Self::Output {
+        Quaternion::new(
+            self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+            self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+        )
+    }
+}
+
+impl<T> Neg for Quaternion<T>
+where
+    T: Copy + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Quaternion::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
@@ -0,0 +1,424 @@
+//! # Rational Numbers
+//!
+//! This module provides an exact rational scalar type built on the signed
+//! integer types, allowing Gaussian elimination without rounding errors.
+
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::traits::{Conjugate, Integer, Magnitude, Negative, One, RealPart, Zero};
+
+/// Represents an exact rational number `numerator / denominator`.
+///
+/// Values are always kept normalized: the fraction is reduced to lowest
+/// terms and the denominator is strictly positive. Thanks to this, the
+/// derived equality is exact and every value has a single representation.
+///
+/// `Rational<I>` implements the crate's scalar traits, so algorithms such as
+/// `row_echelon`, `inverse`, `determinant` and `rank` produce exact results.
+///
+/// # Overflow
+///
+/// Numerators and denominators grow during elimination, and exactness is
+/// only possible while they fit in `I`. The arithmetic operators panic with
+/// "Rational arithmetic overflowed" when a result or an intermediate product
+/// does not fit, in every build profile, instead of wrapping to a wrong
+/// value. Use `checked_add`, `checked_sub`, `checked_mul` and `checked_div`
+/// to handle overflow as `None`. Comparisons never overflow.
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::{Matrix, Rational};
+///
+/// let r = |n| Rational::from_integer(n);
+/// let m = Matrix::from([[r(2), r(1)], [r(1), r(3)]]);
+///
+/// let inv = m.inverse().unwrap();
+/// assert_eq!(inv[0][0], Rational::new(3, 5));
+/// assert_eq!(inv[0][1], Rational::new(-1, 5));
+/// assert_eq!(m.mul_mat(&inv), Matrix::from([[r(1), r(0)], [r(0), r(1)]]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<I> {
+    numerator: I,
+    denominator: I,
+}
+
+impl<I> Rational<I>
+where
+    I: Integer,
+{
+    /// Creates a new rational number, reducing it to lowest terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `numerator` - The numerator of the fraction
+    /// * `denominator` - The denominator of the fraction (must not be zero)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Rational;
+    ///
+    /// let r = Rational::new(4, -6);
+    /// assert_eq!(r.numerator(), -2);
+    /// assert_eq!(r.denominator(), 3);
+    /// ```
+    pub fn new(numerator: I, denominator: I) -> Self {
+        assert!(
+            !denominator.is_zero(),
+            "Rational denominator must not be zero"
+        );
+
+        Self::reduce(numerator, denominator).expect(OVERFLOW)
+    }
+
+    /// Reduces `numerator / denominator` to lowest terms with a positive
+    /// denominator, or returns `None` if that overflows `I`.
+    fn reduce(numerator: I, denominator: I) -> Option<Self> {
+        let divisor = gcd(numerator, denominator)?;
+        let (mut numerator, mut denominator) = (numerator / divisor, denominator / divisor);
+        if denominator < I::zero() {
+            numerator = numerator.checked_neg()?;
+            denominator = denominator.checked_neg()?;
+        }
+
+        Some(Rational {
+            numerator,
+            denominator,
+        })
+    }
+
+    /// Returns `self + other`, or `None` if the result does not fit in `I`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Rational;
+    ///
+    /// let half = Rational::new(1_i8, 2);
+    /// assert_eq!(half.checked_add(half), Some(Rational::from_integer(1)));
+    /// assert_eq!(Rational::new(1_i8, 127).checked_add(Rational::new(1, 126)), None);
+    /// ```
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let divisor = gcd(self.denominator, other.denominator)?;
+        let lhs_factor = other.denominator / divisor;
+        let rhs_factor = self.denominator / divisor;
+
+        let numerator = self
+            .numerator
+            .checked_mul(lhs_factor)?
+            .checked_add(other.numerator.checked_mul(rhs_factor)?)?;
+        Self::reduce(numerator, self.denominator.checked_mul(lhs_factor)?)
+    }
+
+    /// Returns `self - other`, or `None` if the result does not fit in `I`.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_add(other.checked_neg()?)
+    }
+
+    /// Returns `self * other`, or `None` if the result does not fit in `I`.
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let g1 = gcd(self.numerator, other.denominator)?;
+        let g2 = gcd(other.numerator, self.denominator)?;
+
+        Self::reduce(
+            (self.numerator / g1).checked_mul(other.numerator / g2)?,
+            (self.denominator / g2).checked_mul(other.denominator / g1)?,
+        )
+    }
+
+    /// Returns `self / other`, or `None` if `other` is zero or the result
+    /// does not fit in `I`.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+
+        let g1 = gcd(self.numerator, other.numerator)?;
+        let g2 = gcd(self.denominator, other.denominator)?;
+
+        Self::reduce(
+            (self.numerator / g1).checked_mul(other.denominator / g2)?,
+            (self.denominator / g2).checked_mul(other.numerator / g1)?,
+        )
+    }
+
+    /// Returns `-self`, or `None` if the numerator is `I`'s minimum value.
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Rational {
+            numerator: self.numerator.checked_neg()?,
+            denominator: self.denominator,
+        })
+    }
+
+    /// Creates a rational number representing the integer `value`.
+    pub fn from_integer(value: I) -> Self {
+        Rational {
+            numerator: value,
+            denominator: I::one(),
+        }
+    }
+
+    /// Returns the numerator of the normalized fraction.
+    pub fn numerator(&self) -> I {
+        self.numerator
+    }
+
+    /// Returns the (always positive) denominator of the normalized fraction.
+    pub fn denominator(&self) -> I {
+        self.denominator
+    }
+
+    /// Checks if the value is an integer (denominator equal to one).
+    pub fn is_integer(&self) -> bool {
+        self.denominator == I::one()
+    }
+
+    /// Returns the multiplicative inverse `denominator / numerator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is zero.
+    pub fn recip(&self) -> Self {
+        Rational::new(self.denominator, self.numerator)
+    }
+
+    /// Returns the absolute value of the rational number.
+    pub fn abs(&self) -> Self {
+        if self.numerator < I::zero() {
+            -*self
+        } else {
+            *self
+        }
+    }
+
+    /// Converts the value to the nearest double precision float.
+    pub fn to_f64(&self) -> f64 {
+        self.numerator.to_f64() / self.denominator.to_f64()
+    }
+
+    /// Converts the value to the nearest single precision float.
+    pub fn to_f32(&self) -> f32 {
+        self.to_f64() as f32
+    }
+}
+
+/// Panic message of the arithmetic operators when a value does not fit.
+const OVERFLOW: &str = "Rational arithmetic overflowed";
+
+/// Greatest common divisor of two integers, always positive, or `None` if
+/// it does not fit in `I` (the gcd of `I::MIN` and zero).
+///
+/// Returns one when both values are zero so that normalization never divides by zero.
+fn gcd<I>(a: I, b: I) -> Option<I>
+where
+    I: Integer,
+{
+    let (mut a, mut b) = (a, b);
+    while !b.is_zero() {
+        // `I::MIN % -1` overflows, but any value is a multiple of -1
+        let r = a.checked_rem(b).unwrap_or(I::zero());
+        a = b;
+        b = r;
+    }
+
+    if a.is_zero() {
+        Some(I::one())
+    } else if a < I::zero() {
+        a.checked_neg()
+    } else {
+        Some(a)
+    }
+}
+
+/// Splits `numerator / denominator`, with a positive denominator, into its
+/// floor and a remainder in `0..denominator`, without overflowing.
+fn floor_div<I>(numerator: I, denominator: I) -> (I, I)
+where
+    I: Integer,
+{
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    if remainder < I::zero() {
+        (quotient - I::one(), remainder + denominator)
+    } else {
+        (quotient, remainder)
+    }
+}
+
+impl<I> From<I> for Rational<I>
+where
+    I: Integer,
+{
+    fn from(value: I) -> Self {
+        Rational::from_integer(value)
+    }
+}
+
+impl<I> Zero for Rational<I>
+where
+    I: Integer,
+{
+    fn zero() -> Self {
+        Rational::from_integer(I::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+}
+
+impl<I> One for Rational<I>
+where
+    I: Integer,
+{
+    fn one() -> Self {
+        Rational::from_integer(I::one())
+    }
+}
+
+impl<I> Negative for Rational<I>
+where
+    I: Integer,
+{
+    fn negative_one() -> Self {
+        Rational::from_integer(I::negative_one())
+    }
+}
+
+impl<I> Magnitude for Rational<I>
+where
+    I: Integer,
+{
+    type Output = f32;
+
+    fn magnitude(&self) -> Self::Output {
+        self.to_f32().abs()
+    }
+}
+
+impl<I> RealPart for Rational<I>
+where
+    I: Integer,
+{
+    type Output = f32;
+
+    fn real_part(&self) -> Self::Output {
+        self.to_f32()
+    }
+}
+
+impl<I> Conjugate for Rational<I>
+where
+    I: Integer,
+{
+    fn conjugate(&self) -> Self {
+        *self
+    }
+}
+
+impl<I> Add for Rational<I>
+where
+    I: Integer,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        self.checked_add(other).expect(OVERFLOW)
+    }
+}
+
+impl<I> Sub for Rational<I>
+where
+    I: Integer,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self.checked_sub(other).expect(OVERFLOW)
+    }
+}
+
+impl<I> Mul for Rational<I>
+where
+    I: Integer,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.checked_mul(other).expect(OVERFLOW)
+    }
+}
+
+impl<I> Div for Rational<I>
+where
+    I: Integer,
+{
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        assert!(!other.is_zero(), "Division by zero rational");
+
+        self.checked_div(other).expect(OVERFLOW)
+    }
+}
+
+impl<I> Neg for Rational<I>
+where
+    I: Integer,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect(OVERFLOW)
+    }
+}
+
+impl<I> PartialOrd for Rational<I>
+where
+    I: Integer,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<I> Ord for Rational<I>
+where
+    I: Integer,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        let cross = (
+            self.numerator.checked_mul(other.denominator),
+            other.numerator.checked_mul(self.denominator),
+        );
+        if let (Some(lhs), Some(rhs)) = cross {
+            return lhs.cmp(&rhs);
+        }
+
+        // Compare the continued fraction expansions, which never overflows
+        let (mut a, mut b) = (self.numerator, self.denominator);
+        let (mut c, mut d) = (other.numerator, other.denominator);
+        loop {
+            let (p, r) = floor_div(a, b);
+            let (q, t) = floor_div(c, d);
+            return match (p.cmp(&q), r.is_zero(), t.is_zero()) {
+                (Ordering::Equal, true, true) => Ordering::Equal,
+                (Ordering::Equal, true, false) => Ordering::Less,
+                (Ordering::Equal, false, true) => Ordering::Greater,
+                (Ordering::Equal, false, false) => {
+                    // r/b against t/d orders like d/t against b/r
+                    (a, b, c, d) = (d, t, b, r);
+                    continue;
+                }
+                (ordering, ..) => ordering,
+            };
+        }
+    }
+}
@@ -3,6 +3,8 @@
 //! This module defines essential traits that enable generic operations
 //! over different numeric types in the linear algebra library.
 
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
 /// Trait for types that have an additive zero element.
 ///
 /// This trait defines the additive identity and allows checking
@@ -13,6 +15,26 @@ pub trait Zero {
 
     /// Checks if the value equals the zero element.
     fn is_zero(&self) -> bool;
+
+    /// Checks if the value is, or may be, zero, so that elimination must not
+    /// divide by it.
+    ///
+    /// Defaults to `is_zero`. Types that carry more than one number override
+    /// it, such as `Dual`, whose value alone decides whether it can be
+    /// inverted.
+    fn may_be_zero(&self) -> bool {
+        self.is_zero()
+    }
+
+    /// Checks if the value is zero up to rounding, so that `Matrix::rank`
+    /// does not take it as a pivot.
+    ///
+    /// Defaults to `is_zero`, which is right for exact types such as the
+    /// integers, `Rational` and `ModP`. Floating point types override it to
+    /// treat magnitudes below `1e-10` as zero.
+    fn is_negligible(&self) -> bool {
+        self.is_zero()
+    }
 }
 
 /// Trait for types that have a multiplicative one element.
@@ -43,6 +65,19 @@ pub trait Magnitude {
     fn magnitude(&self) -> Self::Output;
 }
 
+/// Trait for types that have a real component.
+///
+/// This trait makes the projection onto the real line explicit, instead of
+/// relying on lossy `Into<f32>` conversions. For real numbers the real part
+/// is the number itself; for complex numbers it is the real component.
+pub trait RealPart {
+    /// The return type of the real part.
+    type Output;
+
+    /// Returns the real part of the value.
+    fn real_part(&self) -> Self::Output;
+}
+
 /// Trait for types that support conjugation operation.
 ///
 /// This trait is fundamental for working with complex numbers
@@ -71,6 +106,93 @@ pub trait Conjugate {
     fn conjugate(&self) -> Self;
 }
 
+/// Trait for signed primitive integers usable as exact scalar components.
+///
+/// This trait gathers the arithmetic required to build exact number types,
+/// such as [`Rational`](crate::Rational), on top of the signed integer types.
+pub trait Integer:
+    Copy
+    + Ord
+    + Zero
+    + One
+    + Negative
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+    /// Converts the integer to a double precision float.
+    fn to_f64(self) -> f64;
+    /// Returns `self + other`, or `None` on overflow.
+    fn checked_add(self, other: Self) -> Option<Self>;
+    /// Returns `self - other`, or `None` on overflow.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    /// Returns `self * other`, or `None` on overflow.
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    /// Returns `self % other`, or `None` if `other` is zero or on overflow.
+    fn checked_rem(self, other: Self) -> Option<Self>;
+    /// Returns `-self`, or `None` on overflow.
+    fn checked_neg(self) -> Option<Self>;
+}
+
+/// Trait for floating-point scalars.
+///
+/// This trait exposes the elementary functions needed by geometric algorithms
+/// (normalization, rotations, interpolation) generically over `f32` and `f64`.
+pub trait Float:
+    Copy
+    + PartialOrd
+    + Zero
+    + One
+    + Negative
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// Converts a double precision constant into this type.
+    fn from_f64(value: f64) -> Self;
+    /// Converts the value to a double precision float.
+    fn to_f64(self) -> f64;
+    /// Returns the machine epsilon of the type.
+    fn epsilon() -> Self;
+    /// Returns positive infinity.
+    fn infinity() -> Self;
+    /// Returns the absolute value.
+    fn abs(self) -> Self;
+    /// Returns the square root.
+    fn sqrt(self) -> Self;
+    /// Returns the sine (in radians).
+    fn sin(self) -> Self;
+    /// Returns the cosine (in radians).
+    fn cos(self) -> Self;
+    /// Returns the tangent (in radians).
+    fn tan(self) -> Self;
+    /// Returns the arc cosine, in radians.
+    fn acos(self) -> Self;
+    /// Returns the four quadrant arc tangent of `self` (y) and `other` (x), in radians.
+    fn atan2(self, other: Self) -> Self;
+    /// Returns `e^self`.
+    fn exp(self) -> Self;
+    /// Returns the natural logarithm.
+    fn ln(self) -> Self;
+}
+
+/// Trait for floating-point scalars that can step to adjacent representable values.
+///
+/// Stepping one unit in the last place after a round-to-nearest operation gives
+/// a bound that is guaranteed to enclose the exact result, which is how interval
+/// arithmetic achieves outward rounding without changing the FPU rounding mode.
+pub trait DirectedRounding: Float {
+    /// Returns the smallest representable value greater than `self`.
+    fn next_up(self) -> Self;
+    /// Returns the largest representable value less than `self`.
+    fn next_down(self) -> Self;
+}
+
 impl Zero for i8 {
     fn zero() -> Self {
         0
@@ -222,6 +344,9 @@ impl Zero for f32 {
     fn is_zero(&self) -> bool {
         *self == 0.0
     }
+    fn is_negligible(&self) -> bool {
+        self.abs() < 1e-10
+    }
 }
 
 impl One for f32 {
@@ -243,6 +368,9 @@ impl Zero for f64 {
     fn is_zero(&self) -> bool {
         *self == 0.0
     }
+    fn is_negligible(&self) -> bool {
+        self.abs() < 1e-10
+    }
 }
 
 impl One for f64 {
@@ -337,6 +465,296 @@ impl Magnitude for f64 {
     }
 }
 
+impl RealPart for i8 {
+    type Output = f32;
+
+    fn real_part(&self) -> Self::Output {
+        *self as f32
+    }
+}
+
+impl RealPart for i16 {
+    type Output = f32;
+
+    fn real_part(&self) -> Self::Output {
+        *self as f32
+    }
+}
+
+impl RealPart for i32 {
+    type Output = f32;
+
+    fn real_part(&self) -> Self::Output {
+        *self as f32
+    }
+}
+
+impl RealPart for i64 {
+    type Output = f32;
+
+    fn real_part(&self) -> Self::Output {
+        *self as f32
+    }
+}
+
+impl RealPart for u8 {
+    type Output = f32;
+
+    fn real_part(&self) -> Self::Output {
+        *self as f32
+    }
+}
+
+impl RealPart for u16 {
+    type Output = f32;
+
+    fn real_part(&self) -> Self::Output {
+        *self as f32
+    }
+}
+
+impl RealPart for u32 {
+    type Output = f32;
+
+    fn real_part(&self) -> Self::Output {
+        *self as f32
+    }
+}
+
+impl RealPart for u64 {
+    type Output = f32;
+
+    fn real_part(&self) -> Self::Output {
+        *self as f32
+    }
+}
+
+impl RealPart for f32 {
+    type Output = f32;
+
+    fn real_part(&self) -> Self::Output {
+        *self
+    }
+}
+
+impl RealPart for f64 {
+    type Output = f32;
+
+    fn real_part(&self) -> Self::Output {
+        *self as f32
+    }
+}
+
+impl Integer for i8 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+    fn checked_add(self, other: Self) -> Option<Self> {
+        i8::checked_add(self, other)
+    }
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        i8::checked_sub(self, other)
+    }
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        i8::checked_mul(self, other)
+    }
+    fn checked_rem(self, other: Self) -> Option<Self> {
+        i8::checked_rem(self, other)
+    }
+    fn checked_neg(self) -> Option<Self> {
+        i8::checked_neg(self)
+    }
+}
+
+impl Integer for i16 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+    fn checked_add(self, other: Self) -> Option<Self> {
+        i16::checked_add(self, other)
+    }
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        i16::checked_sub(self, other)
+    }
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        i16::checked_mul(self, other)
+    }
+    fn checked_rem(self, other: Self) -> Option<Self> {
+        i16::checked_rem(self, other)
+    }
+    fn checked_neg(self) -> Option<Self> {
+        i16::checked_neg(self)
+    }
+}
+
+impl Integer for i32 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+    fn checked_add(self, other: Self) -> Option<Self> {
+        i32::checked_add(self, other)
+    }
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        i32::checked_sub(self, other)
+    }
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        i32::checked_mul(self, other)
+    }
+    fn checked_rem(self, other: Self) -> Option<Self> {
+        i32::checked_rem(self, other)
+    }
+    fn checked_neg(self) -> Option<Self> {
+        i32::checked_neg(self)
+    }
+}
+
+impl Integer for i64 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+    fn checked_add(self, other: Self) -> Option<Self> {
+        i64::checked_add(self, other)
+    }
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        i64::checked_sub(self, other)
+    }
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        i64::checked_mul(self, other)
+    }
+    fn checked_rem(self, other: Self) -> Option<Self> {
+        i64::checked_rem(self, other)
+    }
+    fn checked_neg(self) -> Option<Self> {
+        i64::checked_neg(self)
+    }
+}
+
+impl Float for f32 {
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+    fn epsilon() -> Self {
+        f32::EPSILON
+    }
+    fn infinity() -> Self {
+        f32::INFINITY
+    }
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+    fn sqrt(self) -> Self {
+        f32::sqrt(self)
+    }
+    fn sin(self) -> Self {
+        f32::sin(self)
+    }
+    fn cos(self) -> Self {
+        f32::cos(self)
+    }
+    fn tan(self) -> Self {
+        f32::tan(self)
+    }
+    fn acos(self) -> Self {
+        f32::acos(self)
+    }
+    fn atan2(self, other: Self) -> Self {
+        f32::atan2(self, other)
+    }
+    fn exp(self) -> Self {
+        f32::exp(self)
+    }
+    fn ln(self) -> Self {
+        f32::ln(self)
+    }
+}
+
+impl Float for f64 {
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+    fn to_f64(self) -> f64 {
+        self
+    }
+    fn epsilon() -> Self {
+        f64::EPSILON
+    }
+    fn infinity() -> Self {
+        f64::INFINITY
+    }
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+    fn sin(self) -> Self {
+        f64::sin(self)
+    }
+    fn cos(self) -> Self {
+        f64::cos(self)
+    }
+    fn tan(self) -> Self {
+        f64::tan(self)
+    }
+    fn acos(self) -> Self {
+        f64::acos(self)
+    }
+    fn atan2(self, other: Self) -> Self {
+        f64::atan2(self, other)
+    }
+    fn exp(self) -> Self {
+        f64::exp(self)
+    }
+    fn ln(self) -> Self {
+        f64::ln(self)
+    }
+}
+
+impl DirectedRounding for f32 {
+    fn next_up(self) -> Self {
+        if self.is_nan() || self == f32::INFINITY {
+            return self;
+        }
+        if self == 0.0 {
+            return f32::from_bits(1);
+        }
+
+        let bits = self.to_bits();
+        if self > 0.0 {
+            f32::from_bits(bits + 1)
+        } else {
+            f32::from_bits(bits - 1)
+        }
+    }
+    fn next_down(self) -> Self {
+        -DirectedRounding::next_up(-self)
+    }
+}
+
+impl DirectedRounding for f64 {
+    fn next_up(self) -> Self {
+        if self.is_nan() || self == f64::INFINITY {
+            return self;
+        }
+        if self == 0.0 {
+            return f64::from_bits(1);
+        }
+
+        let bits = self.to_bits();
+        if self > 0.0 {
+            f64::from_bits(bits + 1)
+        } else {
+            f64::from_bits(bits - 1)
+        }
+    }
+    fn next_down(self) -> Self {
+        -DirectedRounding::next_up(-self)
+    }
+}
+
 impl Conjugate for i8 {
     fn conjugate(&self) -> Self {
         *self
@@ -396,3 +814,19 @@ impl Conjugate for f64 {
         *self
     }
 }
+
+/// Converts a small non-negative integer into a scalar by binary expansion,
+/// which is exact as long as the integer is representable.
+pub(crate) fn from_integer<K>(n: u64) -> K
+where
+    K: Copy + Zero + One + Add<Output = K>,
+{
+    let mut result = K::zero();
+    for bit in (0..u64::BITS - n.leading_zeros()).rev() {
+        result = result + result;
+        if n >> bit & 1 == 1 {
+            result = result + K::one();
+        }
+    }
+    result
+}
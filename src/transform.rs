@@ -0,0 +1,866 @@
+//! # 3D Transforms
+//!
+//! This module builds the 4×4 homogeneous matrices used in 3D graphics:
+//! translation, scaling, rotations, view (`look_at`) and projection
+//! (`perspective`, `orthographic`) matrices.
+//!
+//! Matrices follow the crate's row-vector convention: a point `[x, y, z, 1]`
+//! is transformed with `matrix.mul_vec(&p)`, which computes `p·M`, so the
+//! translation sits in the last row and transforms compose left to right
+//! (`a.mul_mat(&b)` applies `a` first). Every matrix here is the transpose of
+//! its textbook column-vector form; writing it out row by row gives the
+//! column-major layout expected by OpenGL.
+//!
+//! Handedness and depth range are never implied. `Handedness` selects whether
+//! the camera looks down `-z` (right-handed, OpenGL) or `+z` (left-handed,
+//! Direct3D), and `DepthRange` whether clip-space depth spans `[-1, 1]`
+//! (OpenGL) or `[0, 1]` (Vulkan, Direct3D, Metal).
+
+use crate::{cross_product, errors::TransformError, traits::Float, Matrix, Quaternion, Vector};
+
+/// Orientation of the view space coordinate system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Handedness {
+    /// The camera looks down `-z`, with `x` to the right and `y` up.
+    RightHanded,
+    /// The camera looks down `+z`, with `x` to the right and `y` up.
+    LeftHanded,
+}
+
+/// Range of normalized device depth that a projection maps the near and far
+/// planes to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepthRange {
+    /// The near plane maps to `0` and the far plane to `1`.
+    ZeroToOne,
+    /// The near plane maps to `-1` and the far plane to `1`.
+    NegativeOneToOne,
+}
+
+/// Clip space conventions of a projection matrix.
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::transform::{ClipSpace, DepthRange, Handedness};
+///
+/// let opengl = ClipSpace::new(Handedness::RightHanded, DepthRange::NegativeOneToOne);
+///
+/// assert_eq!(opengl.handedness, Handedness::RightHanded);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClipSpace {
+    /// Orientation of the view space the projection reads from
+    pub handedness: Handedness,
+    /// Depth range of the normalized device coordinates
+    pub depth_range: DepthRange,
+}
+
+impl ClipSpace {
+    /// Creates a clip space convention.
+    pub fn new(handedness: Handedness, depth_range: DepthRange) -> Self {
+        Self {
+            handedness,
+            depth_range,
+        }
+    }
+
+    /// Sign of the view space `z` coordinate in front of the camera.
+    fn forward<T: Float>(&self) -> T {
+        match self.handedness {
+            Handedness::RightHanded => T::negative_one(),
+            Handedness::LeftHanded => T::one(),
+        }
+    }
+}
+
+/// Order in which `rotation_euler` applies its three rotations.
+///
+/// `Xyz` rotates about the fixed `x` axis first, then `y`, then `z`; this is
+/// the same as intrinsic rotations about the moving axes in the reverse
+/// order `z`, `y'`, `x''`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EulerOrder {
+    /// `x`, then `y`, then `z`
+    Xyz,
+    /// `x`, then `z`, then `y`
+    Xzy,
+    /// `y`, then `x`, then `z`
+    Yxz,
+    /// `y`, then `z`, then `x`
+    Yzx,
+    /// `z`, then `x`, then `y`
+    Zxy,
+    /// `z`, then `y`, then `x`
+    Zyx,
+}
+
+/// Normalizes a three-dimensional vector, or returns `None` for the zero vector.
+fn normalize<T>(v: &Vector<T>) -> Option<Vector<T>>
+where
+    T: Float,
+{
+    let norm = v.dot(v).sqrt();
+    if norm.is_zero() {
+        None
+    } else {
+        Some(v.scl_new(T::one() / norm))
+    }
+}
+
+fn check_len<T>(v: &Vector<T>) -> Result<(), TransformError> {
+    if v.len() == 3 {
+        Ok(())
+    } else {
+        Err(TransformError::InvalidVectorLength { len: v.len() })
+    }
+}
+
+/// Embeds a 3×3 linear map into a 4×4 homogeneous matrix.
+fn homogeneous<T>(linear: [[T; 3]; 3]) -> Matrix<T>
+where
+    T: Float,
+{
+    let zero = T::zero();
+    Matrix::from_fn(4, 4, |i, j| match (i, j) {
+        (3, 3) => T::one(),
+        (3, _) | (_, 3) => zero,
+        _ => linear[i][j],
+    })
+}
+
+/// Returns the matrix moving points by `(x, y, z)`.
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::{transform, Vector};
+///
+/// let m = transform::translation(1.0, 2.0, 3.0);
+/// let p = m.mul_vec(&Vector::from([1.0, 1.0, 1.0, 1.0]));
+///
+/// assert_eq!(p, Vector::from([2.0, 3.0, 4.0, 1.0]));
+/// ```
+pub fn translation<T>(x: T, y: T, z: T) -> Matrix<T>
+where
+    T: Float,
+{
+    let mut m = Matrix::identity(4);
+    m[3][0] = x;
+    m[3][1] = y;
+    m[3][2] = z;
+    m
+}
+
+/// Returns the matrix scaling the axes by `(x, y, z)`.
+pub fn scaling<T>(x: T, y: T, z: T) -> Matrix<T>
+where
+    T: Float,
+{
+    let zero = T::zero();
+    homogeneous([[x, zero, zero], [zero, y, zero], [zero, zero, z]])
+}
+
+/// Returns the counter-clockwise rotation by `angle` radians about the `x`
+/// axis, turning `y` towards `z`.
+pub fn rotation_x<T>(angle: T) -> Matrix<T>
+where
+    T: Float,
+{
+    let (sin, cos) = (angle.sin(), angle.cos());
+    let (zero, one) = (T::zero(), T::one());
+    homogeneous([[one, zero, zero], [zero, cos, sin], [zero, -sin, cos]])
+}
+
+/// Returns the counter-clockwise rotation by `angle` radians about the `y`
+/// axis, turning `z` towards `x`.
+pub fn rotation_y<T>(angle: T) -> Matrix<T>
+where
+    T: Float,
+{
+    let (sin, cos) = (angle.sin(), angle.cos());
+    let (zero, one) = (T::zero(), T::one());
+    homogeneous([[cos, zero, -sin], [zero, one, zero], [sin, zero, cos]])
+}
+
+/// Returns the counter-clockwise rotation by `angle` radians about the `z`
+/// axis, turning `x` towards `y`.
+pub fn rotation_z<T>(angle: T) -> Matrix<T>
+where
+    T: Float,
+{
+    let (sin, cos) = (angle.sin(), angle.cos());
+    let (zero, one) = (T::zero(), T::one());
+    homogeneous([[cos, sin, zero], [-sin, cos, zero], [zero, zero, one]])
+}
+
+/// Returns the counter-clockwise rotation by `angle` radians about `axis`,
+/// using Rodrigues' formula. The axis does not need to be normalized.
+///
+/// # Errors
+///
+/// * `TransformError::InvalidVectorLength` - If `axis` is not three-dimensional
+/// * `TransformError::ZeroAxis` - If `axis` is the zero vector
+///
+/// # Examples
+///
+/// ```rust
+/// use std::f64::consts::FRAC_PI_2;
+/// use linear_algebra_42::{transform, Vector};
+///
+/// let m = transform::rotation_axis_angle(&Vector::from([0.0, 0.0, 2.0]), FRAC_PI_2).unwrap();
+///
+/// assert!(m.sub_new(&transform::rotation_z(FRAC_PI_2)).norm_max() < 1e-12);
+/// ```
+pub fn rotation_axis_angle<T>(axis: &Vector<T>, angle: T) -> Result<Matrix<T>, TransformError>
+where
+    T: Float,
+{
+    check_len(axis)?;
+    let axis = normalize(axis).ok_or(TransformError::ZeroAxis)?;
+    let (x, y, z) = (axis[0], axis[1], axis[2]);
+    let (sin, cos) = (angle.sin(), angle.cos());
+    let t = T::one() - cos;
+
+    Ok(homogeneous([
+        [t * x * x + cos, t * x * y + sin * z, t * x * z - sin * y],
+        [t * x * y - sin * z, t * y * y + cos, t * y * z + sin * x],
+        [t * x * z + sin * y, t * y * z - sin * x, t * z * z + cos],
+    ]))
+}
+
+/// Returns the composition of rotations by `x`, `y` and `z` radians about
+/// the corresponding fixed axes, applied in the given `order`.
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::transform::{self, EulerOrder};
+///
+/// let m = transform::rotation_euler(0.1, 0.2, 0.3, EulerOrder::Xyz);
+/// let expected = transform::rotation_x(0.1)
+///     .mul_mat(&transform::rotation_y(0.2))
+///     .mul_mat(&transform::rotation_z(0.3));
+///
+/// assert!(m.sub_new(&expected).norm_max() < 1e-12);
+/// ```
+pub fn rotation_euler<T>(x: T, y: T, z: T, order: EulerOrder) -> Matrix<T>
+where
+    T: Float,
+{
+    let (rx, ry, rz) = (rotation_x(x), rotation_y(y), rotation_z(z));
+    // Row vectors apply the leftmost factor first
+    let (first, second, third) = match order {
+        EulerOrder::Xyz => (rx, ry, rz),
+        EulerOrder::Xzy => (rx, rz, ry),
+        EulerOrder::Yxz => (ry, rx, rz),
+        EulerOrder::Yzx => (ry, rz, rx),
+        EulerOrder::Zxy => (rz, rx, ry),
+        EulerOrder::Zyx => (rz, ry, rx),
+    };
+    first.mul_mat(&second).mul_mat(&third)
+}
+
+/// Returns the view matrix of a camera at `eye` looking at `target`, with
+/// `up` pointing roughly upwards on screen.
+///
+/// The camera ends up at the origin looking down `-z` for right-handed
+/// views and `+z` for left-handed ones, with `+y` up.
+///
+/// # Errors
+///
+/// * `TransformError::InvalidVectorLength` - If a vector is not three-dimensional
+/// * `TransformError::DegenerateView` - If `eye` and `target` coincide, or `up`
+///   is parallel to the viewing direction
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::transform::{self, Handedness};
+/// use linear_algebra_42::Vector;
+///
+/// let eye = Vector::from([0.0, 0.0, 5.0]);
+/// let view = transform::look_at(
+///     &eye,
+///     &Vector::from([0.0, 0.0, 0.0]),
+///     &Vector::from([0.0, 1.0, 0.0]),
+///     Handedness::RightHanded,
+/// )
+/// .unwrap();
+///
+/// // The target lies 5 units in front of the camera
+/// let target = view.mul_vec(&Vector::from([0.0, 0.0, 0.0, 1.0]));
+/// assert_eq!(target, Vector::from([0.0, 0.0, -5.0, 1.0]));
+/// ```
+pub fn look_at<T>(
+    eye: &Vector<T>,
+    target: &Vector<T>,
+    up: &Vector<T>,
+    handedness: Handedness,
+) -> Result<Matrix<T>, TransformError>
+where
+    T: Float,
+{
+    check_len(eye)?;
+    check_len(target)?;
+    check_len(up)?;
+
+    let backward = match handedness {
+        Handedness::RightHanded => eye.sub_new(target),
+        Handedness::LeftHanded => target.sub_new(eye),
+    };
+    let z_axis = normalize(&backward).ok_or(TransformError::DegenerateView)?;
+    let x_axis = normalize(&cross_product(up, &z_axis)).ok_or(TransformError::DegenerateView)?;
+    let y_axis = cross_product(&z_axis, &x_axis);
+
+    let axes = [&x_axis, &y_axis, &z_axis];
+    Ok(Matrix::from_fn(4, 4, |i, j| match (i, j) {
+        (3, 3) => T::one(),
+        (3, _) => -axes[j].dot(eye),
+        (_, 3) => T::zero(),
+        _ => axes[j][i],
+    }))
+}
+
+/// Returns the perspective projection with vertical field of view `fov_y`
+/// (in radians) and `aspect` ratio `width / height`, mapping view depths
+/// between `near` and `far` onto the `clip` depth range.
+///
+/// `far` may be infinite; see `perspective_infinite`.
+///
+/// # Errors
+///
+/// * `TransformError::InvalidFrustum` - If `fov_y` is outside `(0, π)`,
+///   `aspect` or `near` is not positive, or `far` is not beyond `near`
+///
+/// # Examples
+///
+/// ```rust
+/// use std::f64::consts::FRAC_PI_2;
+/// use linear_algebra_42::transform::{self, ClipSpace, DepthRange, Handedness};
+/// use linear_algebra_42::Vector;
+///
+/// let clip = ClipSpace::new(Handedness::RightHanded, DepthRange::ZeroToOne);
+/// let m = transform::perspective(FRAC_PI_2, 1.0, 1.0, 10.0, clip).unwrap();
+///
+/// // A point on the near plane lands at depth 0 after the perspective divide
+/// let p = m.mul_vec(&Vector::from([0.0, 0.0, -1.0, 1.0]));
+/// assert!((p[2] / p[3]).abs() < 1e-12);
+/// ```
+pub fn perspective<T>(
+    fov_y: T,
+    aspect: T,
+    near: T,
+    far: T,
+    clip: ClipSpace,
+) -> Result<Matrix<T>, TransformError>
+where
+    T: Float,
+{
+    perspective_matrix(fov_y, aspect, near, far, clip, false)
+}
+
+/// Returns the perspective projection with the far plane at infinity.
+///
+/// Points at any distance beyond `near` stay inside the depth range, which
+/// avoids far-plane clipping for skies and large scenes.
+///
+/// # Errors
+///
+/// * `TransformError::InvalidFrustum` - If `fov_y` is outside `(0, π)`, or
+///   `aspect` or `near` is not positive
+pub fn perspective_infinite<T>(
+    fov_y: T,
+    aspect: T,
+    near: T,
+    clip: ClipSpace,
+) -> Result<Matrix<T>, TransformError>
+where
+    T: Float,
+{
+    perspective_matrix(fov_y, aspect, near, T::infinity(), clip, false)
+}
+
+/// Returns the perspective projection with reversed depth: the near plane
+/// maps to `1` and the far plane to the low end of the `clip` depth range.
+///
+/// With a `[0, 1]` depth range and a floating point depth buffer this
+/// spreads precision evenly over distance. `far` may be infinite, which
+/// sends the horizon to depth `0`.
+///
+/// # Errors
+///
+/// * `TransformError::InvalidFrustum` - If `fov_y` is outside `(0, π)`,
+///   `aspect` or `near` is not positive, or `far` is not beyond `near`
+///
+/// # Examples
+///
+/// ```rust
+/// use std::f64::consts::FRAC_PI_2;
+/// use linear_algebra_42::transform::{self, ClipSpace, DepthRange, Handedness};
+/// use linear_algebra_42::Vector;
+///
+/// let clip = ClipSpace::new(Handedness::RightHanded, DepthRange::ZeroToOne);
+/// let m = transform::perspective_reversed_z(FRAC_PI_2, 1.0, 1.0, f64::INFINITY, clip).unwrap();
+///
+/// let near = m.mul_vec(&Vector::from([0.0, 0.0, -1.0, 1.0]));
+/// assert!((near[2] / near[3] - 1.0).abs() < 1e-12);
+/// ```
+pub fn perspective_reversed_z<T>(
+    fov_y: T,
+    aspect: T,
+    near: T,
+    far: T,
+    clip: ClipSpace,
+) -> Result<Matrix<T>, TransformError>
+where
+    T: Float,
+{
+    perspective_matrix(fov_y, aspect, near, far, clip, true)
+}
+
+fn perspective_matrix<T>(
+    fov_y: T,
+    aspect: T,
+    near: T,
+    far: T,
+    clip: ClipSpace,
+    reversed: bool,
+) -> Result<Matrix<T>, TransformError>
+where
+    T: Float,
+{
+    let pi = T::from_f64(std::f64::consts::PI);
+    // Negated comparisons also reject NaN
+    if !(fov_y > T::zero() && fov_y < pi && aspect > T::zero() && near > T::zero() && far > near) {
+        return Err(TransformError::InvalidFrustum);
+    }
+
+    let (one, two) = (T::one(), T::from_f64(2.0));
+    let y_scale = one / (fov_y / two).tan();
+    let x_scale = y_scale / aspect;
+    let forward = clip.forward::<T>();
+
+    // Written in terms of near / far so that an infinite far plane gives
+    // ratio = 0 instead of ∞ / ∞; k = far / (far - near)
+    let ratio = near / far;
+    let k = one / (one - ratio);
+    let (z_scale, z_translation) = match (clip.depth_range, reversed) {
+        (DepthRange::ZeroToOne, false) => (k, -near * k),
+        (DepthRange::ZeroToOne, true) => (-ratio * k, near * k),
+        (DepthRange::NegativeOneToOne, false) => ((one + ratio) * k, -two * near * k),
+        (DepthRange::NegativeOneToOne, true) => (-(one + ratio) * k, two * near * k),
+    };
+
+    let zero = T::zero();
+    Ok(Matrix::from([
+        [x_scale, zero, zero, zero],
+        [zero, y_scale, zero, zero],
+        [zero, zero, forward * z_scale, forward],
+        [zero, zero, z_translation, zero],
+    ]))
+}
+
+/// Returns the orthographic projection mapping the box between `left` and
+/// `right`, `bottom` and `top`, and view depths `near` and `far` onto the
+/// normalized device cube with the `clip` depth range.
+///
+/// # Errors
+///
+/// * `TransformError::InvalidFrustum` - If the box is empty along an axis
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::transform::{self, ClipSpace, DepthRange, Handedness};
+/// use linear_algebra_42::Vector;
+///
+/// let clip = ClipSpace::new(Handedness::LeftHanded, DepthRange::ZeroToOne);
+/// let m = transform::orthographic(-2.0, 2.0, -1.0, 1.0, 0.0, 10.0, clip).unwrap();
+///
+/// let corner = m.mul_vec(&Vector::from([2.0, 1.0, 10.0, 1.0]));
+/// assert_eq!(corner, Vector::from([1.0, 1.0, 1.0, 1.0]));
+/// ```
+pub fn orthographic<T>(
+    left: T,
+    right: T,
+    bottom: T,
+    top: T,
+    near: T,
+    far: T,
+    clip: ClipSpace,
+) -> Result<Matrix<T>, TransformError>
+where
+    T: Float,
+{
+    let (width, height, depth) = (right - left, top - bottom, far - near);
+    if width.is_zero() || height.is_zero() || depth.is_zero() {
+        return Err(TransformError::InvalidFrustum);
+    }
+
+    let two = T::from_f64(2.0);
+    let forward = clip.forward::<T>();
+    let (z_scale, z_translation) = match clip.depth_range {
+        DepthRange::ZeroToOne => (T::one() / depth, -near / depth),
+        DepthRange::NegativeOneToOne => (two / depth, -(far + near) / depth),
+    };
+
+    let zero = T::zero();
+    Ok(Matrix::from([
+        [two / width, zero, zero, zero],
+        [zero, two / height, zero, zero],
+        [zero, zero, forward * z_scale, zero],
+        [
+            -(right + left) / width,
+            -(top + bottom) / height,
+            z_translation,
+            T::one(),
+        ],
+    ]))
+}
+
+fn check_transform<T>(m: &Matrix<T>) -> Result<(), TransformError> {
+    if m.shape() == (4, 4) {
+        Ok(())
+    } else {
+        Err(TransformError::InvalidMatrixShape {
+            rows: m.rows(),
+            columns: m.columns(),
+        })
+    }
+}
+
+/// Returns `(L⁻¹)ᵀ` for the upper-left 3×3 block `L` of a 4×4 transform,
+/// computed from its cofactors.
+fn linear_inverse_transpose<T>(m: &Matrix<T>) -> Result<Matrix<T>, TransformError>
+where
+    T: Float,
+{
+    let rows: Vec<Vector<T>> = (0..3)
+        .map(|i| Vector::from([m[i][0], m[i][1], m[i][2]]))
+        .collect();
+    // Rows of the cofactor matrix, which is det · (L⁻¹)ᵀ
+    let cofactors = [
+        cross_product(&rows[1], &rows[2]),
+        cross_product(&rows[2], &rows[0]),
+        cross_product(&rows[0], &rows[1]),
+    ];
+    let det = rows[0].dot(&cofactors[0]);
+    if det.is_zero() {
+        return Err(TransformError::SingularTransform);
+    }
+
+    Ok(Matrix::from_fn(3, 3, |i, j| cofactors[i][j] / det))
+}
+
+/// Homogeneous coordinates for three-dimensional points and directions.
+///
+/// Points are promoted with `w = 1`, so they pick up translation, and
+/// directions with `w = 0`, so they do not. All methods expect the
+/// row-vector 4×4 matrices built by this module.
+impl<T> Vector<T>
+where
+    T: Float,
+{
+    /// Returns the point `[x, y, z, 1]`.
+    ///
+    /// # Errors
+    ///
+    /// * `TransformError::InvalidVectorLength` - If the vector is not three-dimensional
+    pub fn to_homogeneous_point(&self) -> Result<Vector<T>, TransformError> {
+        check_len(self)?;
+        Ok(Vector::from([self[0], self[1], self[2], T::one()]))
+    }
+
+    /// Returns the direction `[x, y, z, 0]`.
+    ///
+    /// # Errors
+    ///
+    /// * `TransformError::InvalidVectorLength` - If the vector is not three-dimensional
+    pub fn to_homogeneous_direction(&self) -> Result<Vector<T>, TransformError> {
+        check_len(self)?;
+        Ok(Vector::from([self[0], self[1], self[2], T::zero()]))
+    }
+
+    /// Divides `[x, y, z, w]` by `w`, returning the point `[x/w, y/w, z/w]`.
+    ///
+    /// # Errors
+    ///
+    /// * `TransformError::InvalidHomogeneousLength` - If the vector is not four-dimensional
+    /// * `TransformError::PointAtInfinity` - If `w` is zero
+    pub fn perspective_divide(&self) -> Result<Vector<T>, TransformError> {
+        if self.len() != 4 {
+            return Err(TransformError::InvalidHomogeneousLength { len: self.len() });
+        }
+        let w = self[3];
+        if w.is_zero() {
+            return Err(TransformError::PointAtInfinity);
+        }
+        Ok(Vector::from([self[0] / w, self[1] / w, self[2] / w]))
+    }
+
+    /// Applies `transform` to this point, including translation and the
+    /// perspective divide.
+    ///
+    /// # Errors
+    ///
+    /// * `TransformError::InvalidVectorLength` - If the vector is not three-dimensional
+    /// * `TransformError::InvalidMatrixShape` - If `transform` is not 4×4
+    /// * `TransformError::PointAtInfinity` - If the point is sent to `w = 0`,
+    ///   e.g. a point on the camera plane under a perspective projection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::{transform, Vector};
+    ///
+    /// let m = transform::translation(1.0, 0.0, 0.0).mul_mat(&transform::scaling(2.0, 2.0, 2.0));
+    /// let p = Vector::from([1.0, 2.0, 3.0]).transform_point(&m).unwrap();
+    ///
+    /// assert_eq!(p, Vector::from([4.0, 4.0, 6.0]));
+    /// ```
+    pub fn transform_point(&self, transform: &Matrix<T>) -> Result<Vector<T>, TransformError> {
+        check_transform(transform)?;
+        transform
+            .mul_vec(&self.to_homogeneous_point()?)
+            .perspective_divide()
+    }
+
+    /// Applies the linear part of `transform` to this direction, ignoring
+    /// translation.
+    ///
+    /// The result is not renormalized. Projective matrices have no
+    /// meaningful action on directions; only their upper-left 3×3 block is
+    /// used.
+    ///
+    /// # Errors
+    ///
+    /// * `TransformError::InvalidVectorLength` - If the vector is not three-dimensional
+    /// * `TransformError::InvalidMatrixShape` - If `transform` is not 4×4
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::{transform, Vector};
+    ///
+    /// let m = transform::translation(5.0, 5.0, 5.0);
+    /// let d = Vector::from([1.0, 0.0, 0.0]).transform_direction(&m).unwrap();
+    ///
+    /// assert_eq!(d, Vector::from([1.0, 0.0, 0.0]));
+    /// ```
+    pub fn transform_direction(&self, transform: &Matrix<T>) -> Result<Vector<T>, TransformError> {
+        check_transform(transform)?;
+        let d = transform.mul_vec(&self.to_homogeneous_direction()?);
+        Ok(Vector::from([d[0], d[1], d[2]]))
+    }
+
+    /// Transforms this surface normal by the inverse-transpose of the linear
+    /// part of `transform`, so it stays perpendicular to transformed
+    /// tangents under non-uniform scaling and shear.
+    ///
+    /// The inverse-transpose is computed from the cofactors of the 3×3
+    /// block. The result is not renormalized.
+    ///
+    /// # Errors
+    ///
+    /// * `TransformError::InvalidVectorLength` - If the vector is not three-dimensional
+    /// * `TransformError::InvalidMatrixShape` - If `transform` is not 4×4
+    /// * `TransformError::SingularTransform` - If the 3×3 block is singular
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::{transform, Vector};
+    ///
+    /// // The plane x + y = 0 stretched along x becomes x + 2y = 0
+    /// let m = transform::scaling(2.0, 1.0, 1.0);
+    /// let n = Vector::from([1.0, 1.0, 0.0]).transform_normal(&m).unwrap();
+    ///
+    /// assert_eq!(n, Vector::from([0.5, 1.0, 0.0]));
+    /// ```
+    pub fn transform_normal(&self, transform: &Matrix<T>) -> Result<Vector<T>, TransformError> {
+        check_len(self)?;
+        check_transform(transform)?;
+
+        let inverse_transpose = linear_inverse_transpose(transform)?;
+        Ok(inverse_transpose.mul_vec(self))
+    }
+}
+
+/// Components of an affine transform, as returned by `decompose_affine`.
+///
+/// The transform applies `scale`, then `shear`, then `rotation`, then
+/// `translation`; `to_matrix` rebuilds it as
+/// `scaling · shear · rotation · translation`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AffineDecomposition<T> {
+    /// Translation `[x, y, z]`
+    pub translation: Vector<T>,
+    /// Proper 3×3 rotation matrix, in row-vector layout
+    pub rotation: Matrix<T>,
+    /// Scale factors `[x, y, z]`; a reflection makes all three negative
+    pub scale: Vector<T>,
+    /// Shear factors `[xy, xz, yz]`, where `xy` adds `xy · y` to `x`
+    pub shear: Vector<T>,
+}
+
+impl<T> AffineDecomposition<T>
+where
+    T: Float,
+{
+    /// Returns the rotation as a unit quaternion.
+    pub fn rotation_quaternion(&self) -> Quaternion<T> {
+        Quaternion::from_rotation_matrix(&self.rotation).expect("Decomposed rotation is 3×3")
+    }
+
+    /// Recomposes the 4×4 affine matrix.
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let (zero, one) = (T::zero(), T::one());
+        let shear = homogeneous([
+            [one, zero, zero],
+            [self.shear[0], one, zero],
+            [self.shear[1], self.shear[2], one],
+        ]);
+        let r = &self.rotation;
+        let rotation = homogeneous([
+            [r[0][0], r[0][1], r[0][2]],
+            [r[1][0], r[1][1], r[1][2]],
+            [r[2][0], r[2][1], r[2][2]],
+        ]);
+
+        scaling(self.scale[0], self.scale[1], self.scale[2])
+            .mul_mat(&shear)
+            .mul_mat(&rotation)
+            .mul_mat(&translation(
+                self.translation[0],
+                self.translation[1],
+                self.translation[2],
+            ))
+    }
+}
+
+/// Checks that `m` is a 4×4 affine matrix.
+///
+/// Textbook column-vector matrices have the affine row `[0, 0, 0, 1]` at
+/// the bottom. This crate's matrices are their transpose, so the same
+/// condition applies to the last column instead.
+fn check_affine<T>(m: &Matrix<T>) -> Result<(), TransformError>
+where
+    T: Float,
+{
+    check_transform(m)?;
+    if (0..3).all(|i| m[i][3].is_zero()) && m[3][3] == T::one() {
+        Ok(())
+    } else {
+        Err(TransformError::NotAffine)
+    }
+}
+
+/// Splits an affine transform into translation, rotation, scale and shear.
+///
+/// The rows of the linear part are orthogonalized in order with
+/// Gram–Schmidt: the first row gives the `x` scale, the second the `xy`
+/// shear and `y` scale, and the third the remaining shears and the `z`
+/// scale. If the transform mirrors space, all scale factors are negated
+/// so that the rotation stays proper.
+///
+/// # Errors
+///
+/// * `TransformError::InvalidMatrixShape` - If `m` is not 4×4
+/// * `TransformError::NotAffine` - If the last column is not `[0, 0, 0, 1]`
+/// * `TransformError::SingularTransform` - If a scale factor is zero
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::transform;
+///
+/// let m = transform::scaling(2.0_f64, 3.0, 4.0)
+///     .mul_mat(&transform::rotation_z(0.5))
+///     .mul_mat(&transform::translation(1.0, -1.0, 0.0));
+/// let parts = transform::decompose_affine(&m).unwrap();
+///
+/// assert!((parts.scale[1] - 3.0).abs() < 1e-12);
+/// assert!((parts.rotation[0][0] - 0.5_f64.cos()).abs() < 1e-12);
+/// assert!(parts.to_matrix().sub_new(&m).norm_max() < 1e-12);
+/// ```
+pub fn decompose_affine<T>(m: &Matrix<T>) -> Result<AffineDecomposition<T>, TransformError>
+where
+    T: Float,
+{
+    check_affine(m)?;
+
+    let row = |i: usize| Vector::from([m[i][0], m[i][1], m[i][2]]);
+    let split = |v: &Vector<T>| {
+        let norm = v.dot(v).sqrt();
+        normalize(v)
+            .map(|unit| (norm, unit))
+            .ok_or(TransformError::SingularTransform)
+    };
+
+    let (mut sx, mut x_axis) = split(&row(0))?;
+
+    let r1 = row(1);
+    let xy = x_axis.dot(&r1);
+    let (mut sy, mut y_axis) = split(&r1.sub_new(&x_axis.scl_new(xy)))?;
+
+    let r2 = row(2);
+    let (xz, yz) = (x_axis.dot(&r2), y_axis.dot(&r2));
+    let r2 = r2.sub_new(&x_axis.scl_new(xz)).sub_new(&y_axis.scl_new(yz));
+    let (mut sz, mut z_axis) = split(&r2)?;
+
+    let shear = Vector::from([xy / sy, xz / sz, yz / sz]);
+
+    if x_axis.dot(&cross_product(&y_axis, &z_axis)) < T::zero() {
+        (sx, sy, sz) = (-sx, -sy, -sz);
+        x_axis = x_axis.scl_new(T::negative_one());
+        y_axis = y_axis.scl_new(T::negative_one());
+        z_axis = z_axis.scl_new(T::negative_one());
+    }
+
+    let axes = [&x_axis, &y_axis, &z_axis];
+    Ok(AffineDecomposition {
+        translation: row(3),
+        rotation: Matrix::from_fn(3, 3, |i, j| axes[i][j]),
+        scale: Vector::from([sx, sy, sz]),
+        shear,
+    })
+}
+
+/// Inverts an affine transform from its 3×3 linear block and translation,
+/// without the general elimination of `Matrix::inverse`.
+///
+/// For `p·L + t`, the inverse is `p·L⁻¹ - t·L⁻¹`; `L⁻¹` comes from the
+/// cofactors of `L`. Rigid transforms invert exactly up to rounding.
+///
+/// # Errors
+///
+/// * `TransformError::InvalidMatrixShape` - If `m` is not 4×4
+/// * `TransformError::NotAffine` - If the last column is not `[0, 0, 0, 1]`
+/// * `TransformError::SingularTransform` - If the linear part is singular
+///
+/// # Examples
+///
+/// ```rust
+/// use linear_algebra_42::{transform, Matrix};
+///
+/// let m = transform::rotation_x(0.3).mul_mat(&transform::translation(1.0, 2.0, 3.0));
+/// let inverse = transform::affine_inverse(&m).unwrap();
+///
+/// assert!(m.mul_mat(&inverse).sub_new(&Matrix::identity(4)).norm_max() < 1e-12);
+/// ```
+pub fn affine_inverse<T>(m: &Matrix<T>) -> Result<Matrix<T>, TransformError>
+where
+    T: Float,
+{
+    check_affine(m)?;
+    let linear = linear_inverse_transpose(m)?.transpose();
+    let translation = linear.mul_vec(&Vector::from([m[3][0], m[3][1], m[3][2]]));
+
+    Ok(Matrix::from_fn(4, 4, |i, j| match (i, j) {
+        (3, 3) => T::one(),
+        (3, _) => -translation[j],
+        (_, 3) => T::zero(),
+        _ => linear[i][j],
+    }))
+}
@@ -1,9 +1,9 @@
 use core::slice::{Iter, IterMut};
-use std::ops::{Add, Index, IndexMut, Mul, Sub};
+use std::ops::{Add, Div, Index, IndexMut, Mul, Sub};
 
 use crate::{
-    traits::{Conjugate, Magnitude, Zero},
-    Complex,
+    traits::{Conjugate, Float, Magnitude, One, Zero},
+    Complex, Matrix,
 };
 
 /// Represents a generic mathematical vector with elements of type `K`.
@@ -122,6 +122,56 @@ where
     }
 }
 
+impl<K> Vector<K>
+where
+    K: Zero + One + Clone,
+{
+    /// Builds the `i`-th standard basis vector of length `n`: all zeros
+    /// except for a one at index `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= n`.
+    pub fn basis(n: usize, i: usize) -> Self {
+        assert!(i < n, "Basis index {i} out of bounds for length {n}");
+
+        let mut data = vec![K::zero(); n];
+        data[i] = K::one();
+        Self { data }
+    }
+}
+
+impl<K> Vector<K>
+where
+    K: Float,
+{
+    /// Builds `n` evenly spaced values from `start` to `end`, both included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::Vector;
+    ///
+    /// assert_eq!(Vector::linspace(0.0, 1.0, 5), Vector::from([0.0, 0.25, 0.5, 0.75, 1.0]));
+    /// ```
+    pub fn linspace(start: K, end: K, n: usize) -> Self {
+        if n < 2 {
+            return Self {
+                data: vec![start; n],
+            };
+        }
+
+        let step = (end - start) / K::from_f64((n - 1) as f64);
+        let mut data: Vec<K> = (0..n)
+            .map(|i| start + step * K::from_f64(i as f64))
+            .collect();
+        if let Some(last) = data.last_mut() {
+            *last = end;
+        }
+        Self { data }
+    }
+}
+
 impl<K> Vector<K>
 where
     K: Copy + Add<Output = K>,
@@ -187,6 +237,69 @@ where
     }
 }
 
+impl<K> Vector<K>
+where
+    K: Copy + Mul<Output = K>,
+{
+    /// Computes the element-wise (Hadamard) product.
+    pub fn hadamard(&self, other: &Self) -> Self {
+        debug_assert_eq!(self.len(), other.len(), "Vector dimensions must match");
+
+        let mut data = Vec::with_capacity(self.len());
+        for (&a, &b) in self.data.iter().zip(&other.data) {
+            data.push(a * b);
+        }
+        Self { data }
+    }
+
+    /// Computes the outer product `u·vᵀ`, the matrix with entries `u[i]·v[j]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use linear_algebra_42::{Matrix, Vector};
+    ///
+    /// let u = Vector::from([1, 2]);
+    /// let v = Vector::from([3, 4, 5]);
+    ///
+    /// assert_eq!(u.outer(&v), Matrix::from([[3, 4, 5], [6, 8, 10]]));
+    /// ```
+    pub fn outer(&self, other: &Self) -> Matrix<K> {
+        Matrix::from_fn(self.len(), other.len(), |i, j| self.data[i] * other.data[j])
+    }
+}
+
+impl<K> Vector<K>
+where
+    K: Copy + Mul<Output = K> + Conjugate,
+{
+    /// Computes the conjugating outer product `u·vᴴ`, with entries `u[i]·v̄[j]`.
+    ///
+    /// This is the outer counterpart of `complex_dot`: the trace of
+    /// `u.complex_outer(&v)` equals `v.complex_dot(&u)`.
+    pub fn complex_outer(&self, other: &Self) -> Matrix<K> {
+        Matrix::from_fn(self.len(), other.len(), |i, j| {
+            self.data[i] * other.data[j].conjugate()
+        })
+    }
+}
+
+impl<K> Vector<K>
+where
+    K: Copy + Div<Output = K>,
+{
+    /// Computes the element-wise quotient.
+    pub fn hadamard_div(&self, other: &Self) -> Self {
+        debug_assert_eq!(self.len(), other.len(), "Vector dimensions must match");
+
+        let mut data = Vec::with_capacity(self.len());
+        for (&a, &b) in self.data.iter().zip(&other.data) {
+            data.push(a / b);
+        }
+        Self { data }
+    }
+}
+
 impl<K> Add for Vector<K>
 where
     K: Copy + Add<Output = K>,
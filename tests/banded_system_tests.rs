@@ -0,0 +1,259 @@
+mod common;
+
+use linear_algebra_42::{
+    solve_banded, solve_cyclic_tridiagonal, solve_tridiagonal, BandedSystemError, Matrix, Rational,
+    Vector,
+};
+
+#[cfg(test)]
+mod banded_system_tests {
+    use super::*;
+    use crate::common::assert_vec_close;
+
+    /// Dense `n×n` matrix with `lower[i] = A[i + 1][i]` and `upper[i] = A[i][i + 1]`.
+    fn tridiagonal_dense(lower: &[f64], diagonal: &[f64], upper: &[f64]) -> Matrix<f64> {
+        let n = diagonal.len();
+        Matrix::from_fn(n, n, |i, j| {
+            if i == j {
+                diagonal[i]
+            } else if i == j + 1 {
+                lower[j]
+            } else if j == i + 1 {
+                upper[i]
+            } else {
+                0.0
+            }
+        })
+    }
+
+    #[test]
+    fn test_tridiagonal_matches_dense() {
+        let lower = [1.0, -2.0, 0.5, 3.0, -1.0];
+        let diagonal = [4.0, 5.0, -6.0, 7.0, 3.0, 2.5];
+        let upper = [2.0, 1.0, -1.5, 0.25, 1.0];
+        let rhs = Vector::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let x = solve_tridiagonal(
+            &Vector::from(lower),
+            &Vector::from(diagonal),
+            &Vector::from(upper),
+            &rhs,
+        )
+        .unwrap();
+        let expected = tridiagonal_dense(&lower, &diagonal, &upper)
+            .solve(&rhs)
+            .unwrap();
+
+        assert_vec_close(&x, &expected);
+    }
+
+    #[test]
+    fn test_tridiagonal_poisson() {
+        // -u'' = 2 on [0, 1] with u(0) = u(1) = 0 has solution u = x(1 - x)
+        let n = 9;
+        let h = 1.0 / (n + 1) as f64;
+        let x = solve_tridiagonal(
+            &Vector::from(vec![-1.0; n - 1]),
+            &Vector::from(vec![2.0; n]),
+            &Vector::from(vec![-1.0; n - 1]),
+            &Vector::from(vec![2.0 * h * h; n]),
+        )
+        .unwrap();
+
+        for (i, u) in x.iter().enumerate() {
+            let t = (i + 1) as f64 * h;
+            assert!((u - t * (1.0 - t)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_tridiagonal_zero_pivot_falls_back() {
+        // Non-singular, but the Thomas algorithm meets a zero first pivot
+        let x = solve_tridiagonal(
+            &Vector::from([1.0, 1.0]),
+            &Vector::from([0.0, 1.0, 1.0]),
+            &Vector::from([1.0, 2.0]),
+            &Vector::from([1.0, 4.0, 3.0]),
+        )
+        .unwrap();
+
+        assert_vec_close(&x, &Vector::from([-1.0, 1.0, 2.0]));
+    }
+
+    #[test]
+    fn test_tridiagonal_singular() {
+        let result = solve_tridiagonal(
+            &Vector::from([1.0, 1.0]),
+            &Vector::from([1.0, 1.0, 1.0]),
+            &Vector::from([1.0, 0.0]),
+            &Vector::from([1.0, 1.0, 1.0]),
+        );
+
+        assert!(matches!(result, Err(BandedSystemError::Singular)));
+    }
+
+    #[test]
+    fn test_tridiagonal_exact() {
+        let r = |n: i64| Rational::new(n, 1);
+        let x = solve_tridiagonal(
+            &Vector::from([r(1), r(1)]),
+            &Vector::from([r(3), r(3), r(3)]),
+            &Vector::from([r(1), r(1)]),
+            &Vector::from([r(1), r(0), r(1)]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            x,
+            Vector::from([
+                Rational::new(3, 7),
+                Rational::new(-2, 7),
+                Rational::new(3, 7)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_tridiagonal_length_errors() {
+        let d = Vector::from([1.0, 2.0, 3.0]);
+        let off = Vector::from([1.0, 1.0]);
+
+        assert!(matches!(
+            solve_tridiagonal(&d, &d, &off, &d),
+            Err(BandedSystemError::DiagonalLength {
+                offset: -1,
+                expected: 2,
+                found: 3
+            })
+        ));
+        assert!(matches!(
+            solve_tridiagonal(&off, &d, &off, &off),
+            Err(BandedSystemError::RhsLength {
+                expected: 3,
+                found: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_cyclic_tridiagonal_matches_dense() {
+        let lower = [0.5, 1.0, -1.0, 2.0, 0.25];
+        let diagonal = [5.0, 4.0, 6.0, -5.0, 4.5];
+        let upper = [1.0, -0.5, 2.0, 1.0, -1.5];
+        let rhs = Vector::from([1.0, -2.0, 3.0, 0.0, 2.0]);
+        let n = diagonal.len();
+
+        let dense = Matrix::from_fn(n, n, |i, j| {
+            if i == j {
+                diagonal[i]
+            } else if j == (i + n - 1) % n {
+                lower[i]
+            } else if j == (i + 1) % n {
+                upper[i]
+            } else {
+                0.0
+            }
+        });
+        let x = solve_cyclic_tridiagonal(
+            &Vector::from(lower),
+            &Vector::from(diagonal),
+            &Vector::from(upper),
+            &rhs,
+        )
+        .unwrap();
+
+        assert_vec_close(&x, &dense.solve(&rhs).unwrap());
+    }
+
+    #[test]
+    fn test_cyclic_tridiagonal_small_and_degenerate() {
+        // n = 2: the corners add to the off-diagonal entries, [[2, 2], [2, 3]]
+        let x = solve_cyclic_tridiagonal(
+            &Vector::from([1.0, 1.0]),
+            &Vector::from([2.0, 3.0]),
+            &Vector::from([1.0, 1.0]),
+            &Vector::from([4.0, 5.0]),
+        )
+        .unwrap();
+        assert_vec_close(&x, &Vector::from([1.0, 1.0]));
+
+        // The last row is zero
+        let x = solve_cyclic_tridiagonal(
+            &Vector::from([1.0, 0.0, 0.0]),
+            &Vector::from([0.0, 0.0, 0.0]),
+            &Vector::from([1.0, 1.0, 0.0]),
+            &Vector::from([5.0, 3.0, 0.0]),
+        );
+        assert!(matches!(x, Err(BandedSystemError::Singular)));
+
+        // A zero diagonal defeats Sherman–Morrison but not the fallback
+        let permutation = solve_cyclic_tridiagonal(
+            &Vector::from([0.0, 0.0, 0.0]),
+            &Vector::from([0.0, 0.0, 0.0]),
+            &Vector::from([1.0, 1.0, 1.0]),
+            &Vector::from([2.0, 3.0, 1.0]),
+        )
+        .unwrap();
+        assert_vec_close(&permutation, &Vector::from([1.0, 2.0, 3.0]));
+    }
+
+    #[test]
+    fn test_cyclic_tridiagonal_singular() {
+        // Periodic second difference annihilates constants
+        let r = |n: i64| Rational::new(n, 1);
+        let result = solve_cyclic_tridiagonal(
+            &Vector::from([r(-1); 4]),
+            &Vector::from([r(2); 4]),
+            &Vector::from([r(-1); 4]),
+            &Vector::from([r(1), r(0), r(0), r(0)]),
+        );
+
+        assert!(matches!(result, Err(BandedSystemError::Singular)));
+    }
+
+    #[test]
+    fn test_banded_matches_dense() {
+        let n = 6;
+        let diagonals = [
+            Vector::from(vec![1.0; n - 2]),
+            Vector::from(vec![-2.0; n - 1]),
+            Vector::from(vec![0.5; n]),
+            Vector::from(vec![3.0; n - 1]),
+        ];
+        let dense = Matrix::from_fn(n, n, |i, j| match j as isize - i as isize {
+            -2 => 1.0,
+            -1 => -2.0,
+            0 => 0.5,
+            1 => 3.0,
+            _ => 0.0,
+        });
+        let rhs = Vector::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let x = solve_banded(&diagonals, 2, &rhs).unwrap();
+
+        assert_vec_close(&x, &dense.solve(&rhs).unwrap());
+    }
+
+    #[test]
+    fn test_banded_errors() {
+        let diagonals = [Vector::from([1.0, 1.0]), Vector::from([2.0, 2.0])];
+        let rhs = Vector::from([1.0, 1.0]);
+
+        assert!(matches!(
+            solve_banded(&diagonals, 2, &rhs),
+            Err(BandedSystemError::MissingMainDiagonal { lower: 2, count: 2 })
+        ));
+        assert!(matches!(
+            solve_banded(&diagonals, 1, &rhs),
+            Err(BandedSystemError::DiagonalLength {
+                offset: -1,
+                expected: 1,
+                found: 2
+            })
+        ));
+        assert_eq!(
+            format!("{}", BandedSystemError::Singular),
+            "O sistema é singular e não possui solução única"
+        );
+    }
+}
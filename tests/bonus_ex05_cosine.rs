@@ -9,10 +9,14 @@ fn test_cosine_similarity_complex() {
     let sim = angle_cos(&u, &v);
     assert!((sim - 1.0).abs() < 1e-6);
 
+    // conj(1 + i)·(1 - i) = -2i, whose real part is zero
     let u = Vector::from(vec![Complex::new(1.0, 1.0), Complex::new(0.0, 0.0)]);
 
     let v = Vector::from(vec![Complex::new(1.0, -1.0), Complex::new(0.0, 0.0)]);
 
     let sim = angle_cos(&u, &v);
-    assert!((sim - 1.0).abs() < 1e-6);
+    assert!(sim.abs() < 1e-6);
+
+    let i = Vector::from(vec![Complex::new(0.0, 1.0)]);
+    assert!((angle_cos(&i, &i) - 1.0).abs() < 1e-6);
 }
@@ -0,0 +1,23 @@
+//! Assertions shared by the integration tests.
+//!
+//! Each test crate compiles this module on its own and uses only some of
+//! the helpers.
+#![allow(dead_code)]
+
+use linear_algebra_42::{Matrix, Vector};
+
+/// Asserts that two vectors agree entry by entry up to `1e-9`.
+pub fn assert_vec_close(actual: &Vector<f64>, expected: &Vector<f64>) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected.iter()) {
+        assert!((a - e).abs() < 1e-9, "{actual} != {expected}");
+    }
+}
+
+/// Asserts that two matrices agree entry by entry up to `1e-9`.
+pub fn assert_mat_close(actual: &Matrix<f64>, expected: &Matrix<f64>) {
+    assert!(
+        actual.sub_new(expected).norm_max() < 1e-9,
+        "\n{actual}\n!=\n{expected}"
+    );
+}
@@ -102,4 +102,93 @@ mod complex_matrix_tests {
 
         assert_eq!(vu, uv.conjugate());
     }
+
+    #[test]
+    fn test_inverse_complex_requires_row_swap() {
+        let m = Matrix::from([
+            [Complex::new(0.0, 0.0), Complex::new(0.0, 1.0)],
+            [Complex::new(1.0, 1.0), Complex::new(2.0, 0.0)],
+        ]);
+
+        let inv = m.inverse().expect("Matrix should be invertible");
+        let product = m.mul_mat(&inv);
+
+        for i in 0..2 {
+            for j in 0..2 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((product[i][j].real() - expected).abs() < 1e-5);
+                assert!(product[i][j].imaginary().abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rank_complex_purely_imaginary_pivot() {
+        let mut m = Matrix::from([
+            [Complex::new(0.0, 1.0), Complex::new(1.0, 0.0)],
+            [Complex::new(0.0, 2.0), Complex::new(2.0, 0.0)],
+        ]);
+
+        assert_eq!(m.rank(), 1);
+    }
+
+    #[test]
+    fn test_row_echelon_complex_pivot_by_magnitude() {
+        let m = Matrix::from([
+            [Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)],
+            [Complex::new(0.0, 2.0), Complex::new(0.0, 0.0)],
+        ]);
+
+        let echelon = m.row_echelon();
+
+        assert_eq!(echelon[0][0], Complex::new(1.0, 0.0));
+        assert_eq!(echelon[0][1], Complex::new(0.0, 0.0));
+        assert_eq!(echelon[1][0], Complex::new(0.0, 0.0));
+        assert_eq!(echelon[1][1], Complex::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn test_complex_matrix_norms() {
+        let matrix = Matrix::from([
+            [Complex::new(3.0, 4.0), Complex::new(0.0, 1.0)],
+            [Complex::new(0.0, 0.0), Complex::new(1.0, -1.0)],
+        ]);
+
+        assert!((matrix.norm_frobenius() - 28.0_f32.sqrt()).abs() < 1e-5);
+        assert_eq!(matrix.norm_max(), 5.0);
+        assert_eq!(matrix.norm_1(), 5.0);
+        assert_eq!(matrix.norm_inf(), 6.0);
+    }
+
+    #[test]
+    fn test_complex_outer_conjugates_second_factor() {
+        let u = Vector::from([Complex::new(1.0, 1.0), Complex::new(0.0, 2.0)]);
+        let v = Vector::from([Complex::new(2.0, -1.0), Complex::new(1.0, 0.0)]);
+
+        let outer = u.outer(&v);
+        let complex_outer = u.complex_outer(&v);
+
+        assert_eq!(outer[0][0], Complex::new(3.0, 1.0));
+        assert_eq!(complex_outer[0][0], Complex::new(1.0, 3.0));
+        assert_eq!(complex_outer[1][1], Complex::new(0.0, 2.0));
+        assert_eq!(complex_outer.trace(), v.complex_dot(&u));
+        assert_eq!(v.complex_outer(&u), complex_outer.conjugate_transpose());
+    }
+
+    #[test]
+    fn test_complex_kronecker_and_hadamard() {
+        let i = Complex::new(0.0, 1.0);
+        let one = Complex::new(1.0, 0.0);
+        let zero = Complex::new(0.0, 0.0);
+        let a = Matrix::from([[one, i]]);
+        let b = Matrix::from([[i], [one]]);
+
+        let product = a.kronecker(&b);
+
+        assert_eq!(product, Matrix::from([[i, i * i], [one, i]]));
+        assert_eq!(
+            Matrix::from([[i, one]]).hadamard(&Matrix::from([[i, zero]])),
+            Matrix::from([[Complex::new(-1.0, 0.0), zero]])
+        );
+    }
 }
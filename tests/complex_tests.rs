@@ -100,14 +100,22 @@ mod complex_tests {
     }
 
     #[test]
-    fn test_complex_partial_ord() {
+    fn test_complex_cmp_by_norm() {
         let c1 = Complex::new(3.0, 4.0);
         let c2 = Complex::new(5.0, 0.0);
         let c3 = Complex::new(1.0, 1.0);
 
-        assert_eq!(c1.partial_cmp(&c2), Some(std::cmp::Ordering::Equal));
-        assert_eq!(c1.partial_cmp(&c3), Some(std::cmp::Ordering::Greater));
-        assert_eq!(c3.partial_cmp(&c1), Some(std::cmp::Ordering::Less));
+        assert_eq!(c1.cmp_by_norm(&c2), std::cmp::Ordering::Equal);
+        assert_eq!(c1.cmp_by_norm(&c3), std::cmp::Ordering::Greater);
+        assert_eq!(c3.cmp_by_norm(&c1), std::cmp::Ordering::Less);
+    }
+
+    #[test]
+    fn test_complex_try_into_real() {
+        assert_eq!(Complex::new(2.5, 0.0).try_into_real(0.0).unwrap(), 2.5);
+        assert_eq!(Complex::new(2.5, 1e-7).try_into_real(1e-6).unwrap(), 2.5);
+        assert!(Complex::new(2.5, 1.0).try_into_real(1e-6).is_err());
+        assert!(Complex::new(2.5, -1.0).try_into_real(0.5).is_err());
     }
 
     #[test]
@@ -0,0 +1,146 @@
+use linear_algebra_42::{
+    jacobian, Dual, Float, Matrix, MatrixInverseError, Negative, One, Quaternion, Vector, Zero,
+};
+
+#[cfg(test)]
+mod dual_tests {
+    use super::*;
+
+    const EPS: f64 = 1e-12;
+
+    #[test]
+    fn test_dual_identities() {
+        assert!(Dual::<f64>::zero().is_zero());
+        assert_eq!(Dual::<f64>::one(), Dual::new(1.0, 0.0));
+        assert_eq!(Dual::<f64>::negative_one(), Dual::new(-1.0, 0.0));
+        assert_eq!(Dual::from(4.0), Dual::constant(4.0));
+    }
+
+    #[test]
+    fn test_dual_arithmetic_rules() {
+        let x = Dual::variable(3.0);
+        let c = Dual::constant(2.0);
+
+        assert_eq!(x + c, Dual::new(5.0, 1.0));
+        assert_eq!(x - c, Dual::new(1.0, 1.0));
+        assert_eq!(x * x, Dual::new(9.0, 6.0));
+        assert_eq!(c / x, Dual::new(2.0 / 3.0, -2.0 / 9.0));
+        assert_eq!(-x, Dual::new(-3.0, -1.0));
+    }
+
+    #[test]
+    fn test_dual_elementary_functions() {
+        let x = Dual::variable(0.5_f64);
+
+        let checks = [
+            (x.sin(), 0.5_f64.sin(), 0.5_f64.cos()),
+            (x.cos(), 0.5_f64.cos(), -0.5_f64.sin()),
+            (x.exp(), 0.5_f64.exp(), 0.5_f64.exp()),
+            (x.ln(), 0.5_f64.ln(), 2.0),
+            (x.sqrt(), 0.5_f64.sqrt(), 0.5 / 0.5_f64.sqrt()),
+            (
+                x.tan(),
+                0.5_f64.tan(),
+                1.0 / (0.5_f64.cos() * 0.5_f64.cos()),
+            ),
+            (x.acos(), 0.5_f64.acos(), -1.0 / 0.75_f64.sqrt()),
+            (x.powi(3), 0.125, 0.75),
+            (x.powi(-2), 4.0, -16.0),
+        ];
+
+        for (result, value, derivative) in checks {
+            assert!((result.value() - value).abs() < EPS);
+            assert!((result.derivative() - derivative).abs() < EPS);
+        }
+    }
+
+    #[test]
+    fn test_jacobian_linear_map_is_matrix() {
+        let a = Matrix::from([[1.0, 2.0, 0.0], [-1.0, 0.5, 3.0]]);
+        let a_dual = Matrix::from([
+            [Dual::from(1.0), Dual::from(2.0), Dual::from(0.0)],
+            [Dual::from(-1.0), Dual::from(0.5), Dual::from(3.0)],
+        ]);
+
+        // mul_vec computes v·A, so its Jacobian is Aᵀ
+        let j = jacobian(|v| a_dual.mul_vec(v), &Vector::from([4.0, -2.0]));
+
+        assert_eq!(j, a.transpose());
+    }
+
+    #[test]
+    fn test_jacobian_nonlinear() {
+        // f(x, y, z) = [x·y·z, sin(x) + z², y / z]
+        let f = |v: &Vector<Dual<f64>>| {
+            Vector::from([v[0] * v[1] * v[2], v[0].sin() + v[2] * v[2], v[1] / v[2]])
+        };
+        let (x, y, z) = (0.3, 2.0, 4.0);
+
+        let j = jacobian(f, &Vector::from([x, y, z]));
+
+        let expected = [
+            [y * z, x * z, x * y],
+            [x.cos(), 0.0, 2.0 * z],
+            [0.0, 1.0 / z, -y / (z * z)],
+        ];
+        assert_eq!(j.shape(), (3, 3));
+        for i in 0..3 {
+            for k in 0..3 {
+                assert!((j[i][k] - expected[i][k]).abs() < EPS);
+            }
+        }
+    }
+
+    #[test]
+    fn test_jacobian_without_inputs() {
+        let f = |_: &Vector<Dual<f64>>| Vector::from([Dual::from(1.0), Dual::from(2.0)]);
+
+        let j = jacobian(f, &Vector::from(Vec::<f64>::new()));
+
+        assert_eq!(j.shape(), (2, 0));
+    }
+
+    #[test]
+    fn test_zero_valued_dual_is_not_a_pivot() {
+        // The first column is 0 + bε, which has no inverse
+        let a = Matrix::from([
+            [Dual::new(0.0, 1.0), Dual::from(1.0)],
+            [Dual::new(0.0, 2.0), Dual::from(1.0)],
+        ]);
+
+        assert!(!a[0][0].is_zero());
+        assert!(matches!(a.inverse(), Err(MatrixInverseError::Singular)));
+    }
+
+    #[test]
+    fn test_jacobian_of_vector_norm_uses_generic_api() {
+        let f = |v: &Vector<Dual<f64>>| Vector::from([v.dot(v).sqrt()]);
+
+        let j = jacobian(f, &Vector::from([3.0, 4.0]));
+
+        assert_eq!(j.shape(), (1, 2));
+        assert!((j[0][0] - 0.6).abs() < EPS);
+        assert!((j[0][1] - 0.8).abs() < EPS);
+    }
+
+    #[test]
+    fn test_jacobian_through_quaternion_rotation() {
+        // Derivative of a rotation by angle θ about z, applied to [1, 0, 0]
+        let f = |v: &Vector<Dual<f64>>| {
+            let axis = Vector::from([Dual::from(0.0), Dual::from(0.0), Dual::from(1.0)]);
+            let q = Quaternion::from_axis_angle(&axis, v[0]).unwrap();
+            q.rotate_vector(&Vector::from([
+                Dual::from(1.0),
+                Dual::from(0.0),
+                Dual::from(0.0),
+            ]))
+        };
+
+        let theta = 0.7_f64;
+        let j = jacobian(f, &Vector::from([theta]));
+
+        assert!((j[0][0] + theta.sin()).abs() < EPS);
+        assert!((j[1][0] - theta.cos()).abs() < EPS);
+        assert!(j[2][0].abs() < EPS);
+    }
+}
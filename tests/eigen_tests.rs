@@ -0,0 +1,112 @@
+use linear_algebra_42::{Complex, Matrix, MatrixFunctionError};
+
+#[cfg(test)]
+mod eigen_tests {
+    use super::*;
+
+    fn assert_values_close(actual: &[Complex], expected: &[(f32, f32)]) {
+        assert_eq!(actual.len(), expected.len(), "{actual:?}");
+        for (value, &(re, im)) in actual.iter().zip(expected) {
+            assert!(
+                (value.real() - re).abs() < 1e-4 && (value.imaginary() - im).abs() < 1e-4,
+                "{actual:?} != {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_eigenvalues_real() {
+        let m = Matrix::from([[2.0, 1.0], [1.0, 2.0]]);
+        assert_values_close(&m.eigenvalues().unwrap(), &[(3.0, 0.0), (1.0, 0.0)]);
+
+        let triangular = Matrix::from([[4.0, 5.0, 6.0], [0.0, -1.0, 2.0], [0.0, 0.0, 2.5]]);
+        assert_values_close(
+            &triangular.eigenvalues().unwrap(),
+            &[(4.0, 0.0), (2.5, 0.0), (-1.0, 0.0)],
+        );
+    }
+
+    #[test]
+    fn test_eigenvalues_complex_pairs() {
+        // Rotation by θ about z has eigenvalues 1 and e^{±iθ}
+        let (s, c) = 0.6_f64.sin_cos();
+        let rotation = Matrix::from([[c, s, 0.0], [-s, c, 0.0], [0.0, 0.0, 1.0]]);
+        assert_values_close(
+            &rotation.eigenvalues().unwrap(),
+            &[(1.0, 0.0), (c as f32, s as f32), (c as f32, -s as f32)],
+        );
+
+        // Companion matrix of x⁴ - 1, whose roots are ±1 and ±i
+        let companion = Matrix::from([
+            [0.0, 0.0, 0.0, 1.0],
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+        ]);
+        assert_values_close(
+            &companion.eigenvalues().unwrap(),
+            &[(1.0, 0.0), (0.0, 1.0), (0.0, -1.0), (-1.0, 0.0)],
+        );
+    }
+
+    #[test]
+    fn test_eigenvalues_match_invariants() {
+        let m = Matrix::from_fn(6, 6, |i, j| {
+            ((i * 7 + j * 3) % 5) as f64 - 1.5 + (i == j) as u8 as f64
+        });
+        let values = m.eigenvalues().unwrap();
+
+        // The sum is the trace and the product the determinant
+        let trace: f64 = (0..6).map(|i| m[i][i]).sum();
+        let sum: f32 = values.iter().map(|v| v.real()).sum();
+        let imaginary: f32 = values.iter().map(|v| v.imaginary()).sum();
+        assert!((sum - trace as f32).abs() < 1e-3);
+        assert!(imaginary.abs() < 1e-3);
+
+        let product = values
+            .iter()
+            .fold(Complex::new(1.0, 0.0), |acc, &v| acc * v);
+        let det = m.determinant() as f32;
+        assert!((product.real() - det).abs() < 1e-2 * det.abs().max(1.0));
+        assert!(product.imaginary().abs() < 1e-2 * det.abs().max(1.0));
+    }
+
+    #[test]
+    fn test_eigenvalues_edge_cases() {
+        assert!(Matrix::<f64>::zeros(0, 0).eigenvalues().unwrap().is_empty());
+        assert_values_close(
+            &Matrix::from([[-7.0]]).eigenvalues().unwrap(),
+            &[(-7.0, 0.0)],
+        );
+        assert_values_close(
+            &Matrix::<f64>::zeros(3, 3).eigenvalues().unwrap(),
+            &[(0.0, 0.0), (0.0, 0.0), (0.0, 0.0)],
+        );
+        assert!(matches!(
+            Matrix::from([[1.0, 2.0]]).eigenvalues(),
+            Err(MatrixFunctionError::NotSquare {
+                rows: 1,
+                columns: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_eigenvalues_out_of_f32_range() {
+        let huge = Matrix::from([[1e300, 0.0], [0.0, 1.0]]);
+        assert!(matches!(
+            huge.eigenvalues(),
+            Err(MatrixFunctionError::Overflow)
+        ));
+
+        let rotation = Matrix::from([[0.0, -1e300], [1e300, 0.0]]);
+        assert!(matches!(
+            rotation.eigenvalues(),
+            Err(MatrixFunctionError::Overflow)
+        ));
+
+        // Results inside the f32 range are still rounded, not rejected
+        let small = Matrix::from([[1e-300, 0.0], [0.0, 1.0]]);
+        assert_values_close(&small.eigenvalues().unwrap(), &[(1.0, 0.0), (0.0, 0.0)]);
+    }
+}
@@ -0,0 +1,190 @@
+use linear_algebra_42::{Matrix, MatrixInverseError, Rational, RowOperation};
+
+#[cfg(test)]
+mod elimination_tests {
+    use super::*;
+
+    fn r(n: i64) -> Rational<i64> {
+        Rational::from_integer(n)
+    }
+
+    #[test]
+    fn test_row_echelon_traced_matches_row_echelon() {
+        let matrices = [
+            Matrix::from([[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [2.0, 0.0, 3.0]]),
+            Matrix::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]]),
+            Matrix::from([[0.0, 0.0], [0.0, 5.0]]),
+        ];
+
+        for m in matrices {
+            let (echelon, trace) = m.row_echelon_traced();
+
+            assert_eq!(echelon, m.row_echelon());
+            assert_eq!(trace.initial(), &m);
+            assert_eq!(trace.result(), &echelon);
+        }
+    }
+
+    #[test]
+    fn test_trace_steps_chain_and_replay() {
+        let m = Matrix::from([[r(0), r(2), r(4)], [r(3), r(3), r(0)], [r(1), r(0), r(1)]]);
+
+        let (echelon, trace) = m.row_echelon_traced();
+
+        let mut replayed = m.clone();
+        let mut previous = &m;
+        for step in trace.steps() {
+            assert_eq!(step.before(), previous);
+            step.operation().apply(&mut replayed).unwrap();
+            assert_eq!(&replayed, step.after());
+            previous = step.after();
+        }
+        assert_eq!(replayed, echelon);
+        assert_eq!(
+            echelon,
+            Matrix::from([[r(1), r(0), r(0)], [r(0), r(1), r(0)], [r(0), r(0), r(1)]])
+        );
+    }
+
+    #[test]
+    fn test_trace_records_expected_operations() {
+        let m = Matrix::from([[r(1), r(2)], [r(3), r(4)]]);
+
+        let (_, trace) = m.row_echelon_traced();
+        let operations: Vec<_> = trace.steps().iter().map(|step| *step.operation()).collect();
+
+        assert_eq!(
+            operations,
+            vec![
+                RowOperation::Swap { i: 0, j: 1 },
+                RowOperation::Scale {
+                    row: 0,
+                    factor: Rational::new(1, 3)
+                },
+                RowOperation::AddMultiple {
+                    target: 1,
+                    source: 0,
+                    factor: r(-1)
+                },
+                RowOperation::Scale {
+                    row: 1,
+                    factor: Rational::new(3, 2)
+                },
+                RowOperation::AddMultiple {
+                    target: 0,
+                    source: 1,
+                    factor: Rational::new(-4, 3)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_inverse_traced_exact() {
+        let m = Matrix::from([[r(2), r(1), r(0)], [r(1), r(3), r(1)], [r(0), r(1), r(4)]]);
+
+        let (inverse, trace) = m.inverse_traced().unwrap();
+
+        assert_eq!(inverse, m.inverse().unwrap());
+        assert_eq!(trace.augmented_columns(), Some(3));
+        assert_eq!(trace.initial().shape(), (3, 6));
+        let (left, right) = trace.result().split_at_col(3).unwrap();
+        assert_eq!(left, m.mul_mat(&inverse));
+        assert_eq!(right, inverse);
+    }
+
+    #[test]
+    fn test_inverse_traced_errors() {
+        let singular = Matrix::from([[1.0, 2.0], [2.0, 4.0]]);
+        assert!(matches!(
+            singular.inverse_traced(),
+            Err(MatrixInverseError::Singular)
+        ));
+
+        let rectangular = Matrix::from([[1.0, 2.0, 3.0]]);
+        assert!(matches!(
+            rectangular.inverse_traced(),
+            Err(MatrixInverseError::NotSquare {
+                rows: 1,
+                columns: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn test_determinant_traced_matches_determinant() {
+        let m = Matrix::from([
+            [r(0), r(2), r(-1), r(3)],
+            [r(1), r(0), r(2), r(1)],
+            [r(4), r(1), r(0), r(-2)],
+            [r(2), r(3), r(1), r(0)],
+        ]);
+
+        let (det, trace) = m.determinant_traced();
+
+        assert_eq!(det, m.determinant());
+        assert!(trace
+            .steps()
+            .iter()
+            .all(|step| !matches!(step.operation(), RowOperation::Scale { .. })));
+        let result = trace.result();
+        for i in 0..4 {
+            for j in 0..i {
+                assert_eq!(result[i][j], r(0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_determinant_traced_singular_and_rectangular() {
+        let singular = Matrix::from([[1.0, 2.0], [2.0, 4.0]]);
+        assert_eq!(singular.determinant_traced().0, 0.0);
+
+        let (det, trace) = Matrix::from([[1.0, 2.0, 3.0]]).determinant_traced();
+        assert_eq!(det, 0.0);
+        assert!(trace.is_empty());
+    }
+
+    #[test]
+    fn test_trace_display() {
+        let m = Matrix::from([[0.0, 1.0], [2.0, 0.0]]);
+
+        let (_, trace) = m.row_echelon_traced();
+
+        assert_eq!(
+            format!("{trace}"),
+            "Matriz inicial:\n[0, 1]\n[2, 0]\n\n\
+             Passo 1: L1 ↔ L2\n[2, 0]\n[0, 1]\n\n\
+             Passo 2: L1 ← (0.5)·L1\n[1, 0]\n[0, 1]"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                RowOperation::AddMultiple {
+                    target: 2,
+                    source: 0,
+                    factor: -3
+                }
+            ),
+            "L3 ← L3 + (-3)·L1"
+        );
+    }
+
+    #[test]
+    fn test_trace_latex_marks_augmentation() {
+        let m = Matrix::from([[r(2), r(0)], [r(0), r(1)]]);
+
+        let (_, trace) = m.inverse_traced().unwrap();
+        let latex = trace.to_latex();
+
+        assert_eq!(trace.len(), 1);
+        assert_eq!(
+            latex,
+            "\\begin{aligned}\n\
+             & \\left[\\begin{array}{cc|cc} 2 & 0 & 1 & 0 \\\\ 0 & 1 & 0 & 1 \\end{array}\\right]\n\
+             \\\\ \\xrightarrow{L_{1} \\leftarrow (1/2) L_{1}} & \
+             \\left[\\begin{array}{cc|cc} 1 & 0 & 1/2 & 0 \\\\ 0 & 1 & 0 & 1 \\end{array}\\right]\n\
+             \\end{aligned}"
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use linear_algebra_42::{Matrix, ModP, Negative, One, Vector, Zero};
+
+#[cfg(test)]
+mod finite_field_tests {
+    use super::*;
+
+    type F7 = ModP<7>;
+    type F2 = ModP<2>;
+    type Big = ModP<18_446_744_073_709_551_557>;
+
+    fn f(value: u64) -> F7 {
+        F7::new(value)
+    }
+
+    #[test]
+    fn test_modp_reduction() {
+        assert_eq!(f(15).value(), 1);
+        assert_eq!(F7::from_i64(-3).value(), 4);
+        assert_eq!(F7::modulus(), 7);
+        assert!(F7::zero().is_zero());
+        assert_eq!(F7::one(), f(1));
+        assert_eq!(F7::negative_one(), f(6));
+    }
+
+    #[test]
+    fn test_modp_arithmetic() {
+        assert_eq!(f(5) + f(4), f(2));
+        assert_eq!(f(2) - f(5), f(4));
+        assert_eq!(f(3) * f(5), f(1));
+        assert_eq!(f(3) / f(5), f(2));
+        assert_eq!(-f(3), f(4));
+        assert_eq!(-f(0), f(0));
+        assert_eq!(f(3).pow(6), f(1));
+    }
+
+    #[test]
+    fn test_modp_inverse() {
+        for value in 1..7 {
+            let x = f(value);
+            assert_eq!(x * x.inverse().unwrap(), F7::one());
+        }
+        assert_eq!(f(0).inverse(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_modp_division_by_zero() {
+        let _ = f(3) / f(0);
+    }
+
+    #[test]
+    fn test_modp_large_prime_no_overflow() {
+        let a = Big::new(u64::MAX - 100);
+        let b = Big::new(u64::MAX - 200);
+
+        assert_eq!((a + b) - b, a);
+        assert_eq!(a * a.inverse().unwrap(), Big::one());
+    }
+
+    #[test]
+    fn test_modp_matrix_inverse() {
+        let m = Matrix::from([[f(0), f(1), f(2)], [f(1), f(0), f(3)], [f(4), f(5), f(6)]]);
+
+        let inv = m.inverse().expect("Matrix should be invertible mod 7");
+        let identity = Matrix::from([[f(1), f(0), f(0)], [f(0), f(1), f(0)], [f(0), f(0), f(1)]]);
+
+        assert_eq!(m.mul_mat(&inv), identity);
+        assert_eq!(inv.mul_mat(&m), identity);
+    }
+
+    #[test]
+    fn test_modp_singular_only_modulo_p() {
+        // det = 1·4 - 2·3 = -2, invertible over the rationals but singular in GF(2)
+        let m = Matrix::from([[F2::new(1), F2::new(2)], [F2::new(3), F2::new(4)]]);
+
+        assert!(m.inverse().is_err());
+        assert_eq!(m.clone().rank(), 1);
+        assert_eq!(m.determinant(), F2::new(0));
+    }
+
+    #[test]
+    fn test_modp_row_echelon() {
+        let m = Matrix::from([[f(2), f(4), f(1)], [f(1), f(2), f(3)]]);
+
+        let echelon = m.row_echelon();
+
+        assert_eq!(
+            echelon,
+            Matrix::from([[f(1), f(2), f(0)], [f(0), f(0), f(1)]])
+        );
+    }
+
+    #[test]
+    fn test_modp_null_space() {
+        // Parity-check matrix of the [7, 4] Hamming code over GF(2)
+        let b = F2::new;
+        let h = Matrix::from([
+            [b(1), b(0), b(1), b(0), b(1), b(0), b(1)],
+            [b(0), b(1), b(1), b(0), b(0), b(1), b(1)],
+            [b(0), b(0), b(0), b(1), b(1), b(1), b(1)],
+        ]);
+
+        let basis = h.null_space();
+
+        assert_eq!(basis.len(), 4);
+        for codeword in &basis {
+            let syndrome: Vector<F2> = Vector::from(
+                h.iter()
+                    .map(|row| Vector::from(row.as_slice()).dot(codeword))
+                    .collect::<Vec<_>>(),
+            );
+            assert_eq!(syndrome, Vector::zeros(3));
+        }
+    }
+}
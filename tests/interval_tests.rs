@@ -0,0 +1,268 @@
+use std::cmp::Ordering;
+
+use linear_algebra_42::{
+    DirectedRounding, Interval, LinearSystemError, Magnitude, Matrix, MatrixInverseError, Rational,
+    Vector, Zero,
+};
+
+#[cfg(test)]
+mod interval_tests {
+    use super::*;
+
+    type I = Interval<f64>;
+
+    /// Small deterministic generator so the enclosure checks cover many inputs.
+    struct Lcg(u64);
+
+    impl Lcg {
+        fn next(&mut self) -> i64 {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((self.0 >> 33) % 41) as i64 - 20
+        }
+    }
+
+    /// Returns `n / 10` both as an exact rational and as an enclosing interval.
+    fn tenth(n: i64) -> (Rational<i64>, I) {
+        let approx = n as f64 / 10.0;
+        (
+            Rational::new(n, 10),
+            Interval::new(
+                DirectedRounding::next_down(approx),
+                DirectedRounding::next_up(approx),
+            ),
+        )
+    }
+
+    /// Compares `x` with `exact` without rounding, writing `x` as `m·2ᵉ`.
+    fn compare_exact(x: f64, exact: &Rational<i64>) -> Ordering {
+        assert!(!x.is_nan());
+        if x.is_infinite() {
+            return if x > 0.0 {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            };
+        }
+
+        let bits = x.to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = (bits & ((1 << 52) - 1)) as i128;
+        let (mut mantissa, exponent) = if biased == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | 1 << 52, biased - 1075)
+        };
+        if bits >> 63 == 1 {
+            mantissa = -mantissa;
+        }
+
+        // x < p/q exactly when m·q·2ᵉ < p, as q > 0. A side that overflows
+        // when scaled is far larger than the other, so its sign decides.
+        let left = mantissa * exact.denominator() as i128;
+        let right = exact.numerator() as i128;
+        let scale = |value: i128, shift: i32| {
+            if value == 0 {
+                return Some(0);
+            }
+            (shift < 127)
+                .then(|| 1_i128 << shift)
+                .and_then(|power| value.checked_mul(power))
+        };
+        if exponent >= 0 {
+            scale(left, exponent).map_or(left.cmp(&0), |left| left.cmp(&right))
+        } else {
+            scale(right, -exponent).map_or(0.cmp(&right), |right| left.cmp(&right))
+        }
+    }
+
+    fn assert_encloses(interval: &I, exact: &Rational<i64>) {
+        assert!(
+            compare_exact(interval.lo(), exact).is_le()
+                && compare_exact(interval.hi(), exact).is_ge(),
+            "{interval:?} does not enclose {exact:?}"
+        );
+    }
+
+    fn random_pair(rng: &mut Lcg, n: usize) -> (Matrix<Rational<i64>>, Matrix<I>) {
+        let mut exact = Matrix::zeros(n, n);
+        let mut bounds = Matrix::zeros(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                let (r, interval) = tenth(rng.next());
+                exact[i][j] = r;
+                bounds[i][j] = interval;
+            }
+        }
+        (exact, bounds)
+    }
+
+    #[test]
+    fn test_interval_basic_arithmetic() {
+        let a = Interval::new(1.0, 2.0);
+        let b = Interval::new(-3.0, 4.0);
+
+        let sum = a + b;
+        assert!(sum.encloses(&Interval::new(-2.0, 6.0)));
+        let difference = a - b;
+        assert!(difference.encloses(&Interval::new(-3.0, 5.0)));
+        let product = a * b;
+        assert!(product.encloses(&Interval::new(-6.0, 8.0)));
+        assert!(product.width() < 14.0 + 1e-12);
+        assert_eq!(-a, Interval::new(-2.0, -1.0));
+    }
+
+    #[test]
+    fn test_interval_division() {
+        let a = Interval::new(1.0, 2.0);
+
+        let quotient = a / Interval::new(4.0, 8.0);
+        assert!(quotient.encloses(&Interval::new(0.125, 0.5)));
+
+        let unbounded = a / Interval::new(-1.0, 1.0);
+        assert_eq!(unbounded, Interval::entire());
+    }
+
+    #[test]
+    fn test_interval_outward_rounding() {
+        let sum = I::point(0.1) + I::point(0.2);
+
+        assert!(sum.lo() < sum.hi());
+        assert!(sum.contains(0.1 + 0.2));
+        assert!(sum.width() < 1e-15);
+    }
+
+    #[test]
+    fn test_interval_queries() {
+        let a = Interval::new(-3.0, 2.0);
+
+        assert_eq!(a.magnitude(), DirectedRounding::next_up(3.0_f32));
+        assert_eq!(a.mignitude(), 0.0);
+        assert_eq!(Interval::new(2.0, 5.0).mignitude(), 2.0);
+        assert!(a.contains_zero());
+        assert_eq!(a.midpoint(), -0.5);
+        assert_eq!(a.hull(&Interval::new(4.0, 5.0)), Interval::new(-3.0, 5.0));
+        assert!(I::zero().is_zero());
+        assert!(!Interval::new(0.0, 1e-300).is_zero());
+        assert_eq!(format!("{}", Interval::new(1.5, 2.5)), "[1.5, 2.5]");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_interval_invalid_bounds() {
+        let _ = Interval::new(2.0, 1.0);
+    }
+
+    #[test]
+    fn test_interval_dot_encloses_exact() {
+        let mut rng = Lcg(7);
+        for _ in 0..200 {
+            let (exact, bounds): (Vec<_>, Vec<_>) = (0..6).map(|_| tenth(rng.next())).unzip();
+            let (exact2, bounds2): (Vec<_>, Vec<_>) = (0..6).map(|_| tenth(rng.next())).unzip();
+
+            let exact_dot = Vector::from(exact).dot(&Vector::from(exact2));
+            let interval_dot = Vector::from(bounds).dot(&Vector::from(bounds2));
+
+            assert_encloses(&interval_dot, &exact_dot);
+        }
+    }
+
+    #[test]
+    fn test_interval_mul_mat_encloses_exact() {
+        let mut rng = Lcg(11);
+        for _ in 0..50 {
+            let (a_exact, a_bounds) = random_pair(&mut rng, 3);
+            let (b_exact, b_bounds) = random_pair(&mut rng, 3);
+
+            let exact = a_exact.mul_mat(&b_exact);
+            let bounds = a_bounds.mul_mat(&b_bounds);
+
+            for i in 0..3 {
+                for j in 0..3 {
+                    assert_encloses(&bounds[i][j], &exact[i][j]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_interval_determinant_encloses_exact() {
+        let mut rng = Lcg(23);
+        for n in [2, 3, 4, 6] {
+            for _ in 0..50 {
+                let (exact, bounds) = random_pair(&mut rng, n);
+
+                assert_encloses(&bounds.determinant(), &exact.determinant());
+            }
+        }
+    }
+
+    #[test]
+    fn test_interval_solve_encloses_exact() {
+        let mut rng = Lcg(42);
+        let mut solved = 0;
+        while solved < 50 {
+            let (a_exact, a_bounds) = random_pair(&mut rng, 3);
+            let (b_exact, b_bounds): (Vec<_>, Vec<_>) = (0..3).map(|_| tenth(rng.next())).unzip();
+
+            let Ok(x_exact) = a_exact.solve(&Vector::from(b_exact)) else {
+                continue;
+            };
+            let x_bounds = a_bounds
+                .solve(&Vector::from(b_bounds))
+                .expect("Non-singular system must be solvable with intervals");
+
+            for (bound, exact) in x_bounds.iter().zip(x_exact.iter()) {
+                assert_encloses(bound, exact);
+            }
+            solved += 1;
+        }
+    }
+
+    #[test]
+    fn test_interval_pivots_never_contain_zero() {
+        let one = Interval::point(1.0);
+        let around_zero = Interval::new(-1.0, 1.0);
+        let a = Matrix::from([[around_zero, one], [around_zero, Interval::point(2.0)]]);
+
+        assert!(matches!(
+            a.solve(&Vector::from([one, one])),
+            Err(LinearSystemError::Singular)
+        ));
+        assert!(matches!(a.inverse(), Err(MatrixInverseError::Singular)));
+
+        // The widest entry of the column straddles zero, so the other is the pivot
+        let b = Matrix::from([
+            [Interval::new(-10.0, 10.0), one],
+            [Interval::new(1.0, 2.0), I::zero()],
+        ]);
+        assert!(b.solve(&Vector::from([one, one])).is_ok());
+    }
+
+    #[test]
+    fn test_exact_comparison() {
+        let third = Rational::new(1, 3);
+
+        assert_eq!(compare_exact(third.to_f64(), &third), Ordering::Less);
+        assert_eq!(
+            compare_exact(DirectedRounding::next_up(third.to_f64()), &third),
+            Ordering::Greater
+        );
+        assert_eq!(compare_exact(0.25, &Rational::new(1, 4)), Ordering::Equal);
+        assert_eq!(compare_exact(-1e300, &third), Ordering::Less);
+        assert_eq!(
+            compare_exact(1e-300, &Rational::new(0, 1)),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_interval_f32() {
+        let third = Interval::<f32>::point(1.0) / Interval::point(3.0);
+
+        assert!(third.lo() < third.hi());
+        assert!((third.lo() as f64) < 1.0 / 3.0 && 1.0 / 3.0 < (third.hi() as f64));
+    }
+}
@@ -0,0 +1,206 @@
+use linear_algebra_42::{Complex, Matrix, MatrixFunctionError};
+
+#[cfg(test)]
+mod matrix_function_tests {
+    use super::*;
+
+    fn assert_close(a: &Matrix<f64>, b: &Matrix<f64>, tolerance: f64) {
+        assert_eq!(a.shape(), b.shape());
+        for (row_a, row_b) in a.iter().zip(b.iter()) {
+            for (x, y) in row_a.iter().zip(row_b.iter()) {
+                assert!(
+                    (x - y).abs() <= tolerance * (1.0 + y.abs()),
+                    "{a:?} != {b:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_exp_of_zero_is_identity() {
+        let zero: Matrix<f64> = Matrix::zeros(3, 3);
+
+        assert_eq!(zero.exp().unwrap(), Matrix::identity(3));
+    }
+
+    #[test]
+    fn test_exp_diagonal() {
+        let a = Matrix::from([[1.0, 0.0], [0.0, -2.0]]);
+
+        let expected = Matrix::from([[1.0_f64.exp(), 0.0], [0.0, (-2.0_f64).exp()]]);
+        assert_close(&a.exp().unwrap(), &expected, 1e-13);
+    }
+
+    #[test]
+    fn test_exp_rotation_generator() {
+        let theta = 2.5_f64;
+        let a = Matrix::from([[0.0, -theta], [theta, 0.0]]);
+
+        let expected = Matrix::from([[theta.cos(), -theta.sin()], [theta.sin(), theta.cos()]]);
+        assert_close(&a.exp().unwrap(), &expected, 1e-13);
+    }
+
+    #[test]
+    fn test_exp_requires_scaling() {
+        let a = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        // Reference values from the eigendecomposition of A
+        let expected = Matrix::from([
+            [51.968956198705, 74.73656456700328],
+            [112.10484685050491, 164.07380304920997],
+        ]);
+        assert_close(&a.exp().unwrap(), &expected, 1e-12);
+    }
+
+    #[test]
+    fn test_exp_discretizes_state_space_model() {
+        // ẋ = A·x with eigenvalues -1 and -2: e^(A·t) = 2e^(-t) - e^(-2t), ...
+        let dt = 0.1_f64;
+        let mut a = Matrix::from([[0.0, 1.0], [-2.0, -3.0]]);
+        a.scl(dt);
+
+        let (e1, e2) = ((-dt).exp(), (-2.0 * dt).exp());
+        let expected = Matrix::from([
+            [2.0 * e1 - e2, e1 - e2],
+            [-2.0 * e1 + 2.0 * e2, -e1 + 2.0 * e2],
+        ]);
+        assert_close(&a.exp().unwrap(), &expected, 1e-14);
+    }
+
+    #[test]
+    fn test_exp_complex() {
+        let a = Matrix::from([
+            [Complex::new(0.0, 1.0), Complex::new(0.0, 0.0)],
+            [Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)],
+        ]);
+
+        let result = a.exp().unwrap();
+
+        assert!((result[0][0].real() - 1.0_f32.cos()).abs() < 1e-6);
+        assert!((result[0][0].imaginary() - 1.0_f32.sin()).abs() < 1e-6);
+        assert!((result[1][1].real() - 1.0_f32.exp()).abs() < 1e-5);
+        assert!(result[0][1].real().abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_sqrt_squares_back() {
+        let a = Matrix::from([[4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]]);
+
+        let root = a.sqrt().unwrap();
+
+        assert_close(&root.mul_mat(&root), &a, 1e-13);
+    }
+
+    #[test]
+    fn test_sqrt_complex() {
+        let a = Matrix::from([
+            [Complex::new(3.0, 4.0), Complex::new(1.0, 0.0)],
+            [Complex::new(0.0, 0.0), Complex::new(-5.0, 12.0)],
+        ]);
+
+        let root = a.sqrt().unwrap();
+
+        // √(3 + 4i) = 2 + i and √(-5 + 12i) = 2 + 3i
+        assert!((root[0][0].real() - 2.0).abs() < 1e-5);
+        assert!((root[0][0].imaginary() - 1.0).abs() < 1e-5);
+        assert!((root[1][1].real() - 2.0).abs() < 1e-5);
+        assert!((root[1][1].imaginary() - 3.0).abs() < 1e-5);
+        assert!(root.mul_mat(&root).sub_new(&a).norm_max() < 1e-4);
+    }
+
+    #[test]
+    fn test_sqrt_errors() {
+        let singular = Matrix::from([[1.0, 2.0], [2.0, 4.0]]);
+        assert!(matches!(
+            singular.sqrt(),
+            Err(MatrixFunctionError::Singular)
+        ));
+
+        let negative = Matrix::from([[-1.0, 0.0], [0.0, 1.0]]);
+        assert!(negative.sqrt().is_err());
+    }
+
+    #[test]
+    fn test_log_inverts_exp() {
+        let a = Matrix::from([[0.3, -1.2, 0.5], [0.8, 0.1, -0.4], [-0.2, 0.6, 0.7]]);
+
+        let round_trip = a.exp().unwrap().log().unwrap();
+
+        assert_close(&round_trip, &a, 1e-11);
+        assert_close(
+            &Matrix::identity(3).log().unwrap(),
+            &Matrix::zeros(3, 3),
+            0.0,
+        );
+    }
+
+    #[test]
+    fn test_log_diagonal() {
+        let a = Matrix::from([[10.0, 0.0], [0.0, 0.5]]);
+
+        let expected = Matrix::from([[10.0_f64.ln(), 0.0], [0.0, 0.5_f64.ln()]]);
+        assert_close(&a.log().unwrap(), &expected, 1e-12);
+    }
+
+    #[test]
+    fn test_log_singular() {
+        let singular = Matrix::from([[0.0, 1.0], [0.0, 0.0]]);
+
+        assert!(matches!(singular.log(), Err(MatrixFunctionError::Singular)));
+    }
+
+    #[test]
+    fn test_powi() {
+        let a = Matrix::from([[1.0, 2.0], [-1.0, 3.0]]);
+
+        let mut expected = Matrix::identity(2);
+        for _ in 0..7 {
+            expected = expected.mul_mat(&a);
+        }
+        assert_eq!(a.powi(7).unwrap(), expected);
+        assert_eq!(a.powi(0).unwrap(), Matrix::identity(2));
+
+        let inverse = a.inverse().unwrap();
+        assert_close(&a.powi(-2).unwrap(), &inverse.mul_mat(&inverse), 1e-14);
+    }
+
+    #[test]
+    fn test_powi_negative_of_singular() {
+        let singular = Matrix::from([[1.0, 2.0], [2.0, 4.0]]);
+
+        assert!(singular.powi(3).is_ok());
+        assert!(matches!(
+            singular.powi(-1),
+            Err(MatrixFunctionError::Singular)
+        ));
+    }
+
+    #[test]
+    fn test_powf_matches_sqrt_and_powi() {
+        let a = Matrix::from([[5.0, 2.0], [2.0, 3.0]]);
+
+        assert_close(&a.powf(0.5).unwrap(), &a.sqrt().unwrap(), 1e-11);
+        assert_close(&a.powf(3.0).unwrap(), &a.powi(3).unwrap(), 1e-11);
+    }
+
+    #[test]
+    fn test_matrix_functions_require_square() {
+        let a = Matrix::from([[1.0, 2.0, 3.0]]);
+
+        assert!(matches!(
+            a.exp(),
+            Err(MatrixFunctionError::NotSquare {
+                rows: 1,
+                columns: 3
+            })
+        ));
+        assert!(a.log().is_err());
+        assert!(a.sqrt().is_err());
+        assert!(a.powi(2).is_err());
+        assert!(a.powf(0.5).is_err());
+        assert_eq!(
+            a.exp().unwrap_err().to_string(),
+            "Matriz deve ser quadrada para calcular funções de matriz: encontrada 1x3"
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use linear_algebra_42::{Complex, Matrix, Rational};
+
+#[cfg(test)]
+mod matrix_properties_tests {
+    use super::*;
+
+    const TOL: f32 = 1e-9;
+
+    #[test]
+    fn test_symmetric_and_hermitian() {
+        let symmetric = Matrix::from([[1.0_f64, 2.0, 3.0], [2.0, 4.0, 5.0], [3.0, 5.0, 6.0]]);
+        assert!(symmetric.is_symmetric(0.0));
+        assert!(symmetric.is_hermitian(0.0));
+
+        let nearly = Matrix::from([[1.0_f64, 2.0], [2.0 + 1e-7, 1.0]]);
+        assert!(!nearly.is_symmetric(0.0));
+        assert!(nearly.is_symmetric(1e-6));
+
+        let rectangular = Matrix::from([[1.0_f64, 2.0, 3.0], [2.0, 4.0, 5.0]]);
+        assert!(!rectangular.is_symmetric(TOL));
+
+        let hermitian = Matrix::from([
+            [Complex::new(2.0, 0.0), Complex::new(1.0, -1.0)],
+            [Complex::new(1.0, 1.0), Complex::new(3.0, 0.0)],
+        ]);
+        assert!(hermitian.is_hermitian(0.0));
+        assert!(!hermitian.is_symmetric(0.0));
+
+        // A Hermitian matrix must have a real diagonal
+        let complex_diagonal = Matrix::from([[Complex::new(1.0, 1.0)]]);
+        assert!(!complex_diagonal.is_hermitian(0.0));
+        assert!(complex_diagonal.is_symmetric(0.0));
+    }
+
+    #[test]
+    fn test_orthogonal_and_unitary() {
+        let (sin, cos) = 0.3_f64.sin_cos();
+        let rotation = Matrix::from([[cos, -sin], [sin, cos]]);
+        assert!(rotation.is_orthogonal(TOL));
+        assert!(rotation.is_unitary(TOL));
+
+        let permutation = Matrix::<f64>::permutation(&[2, 0, 1]).unwrap();
+        assert!(permutation.is_orthogonal(0.0));
+
+        let shear = Matrix::from([[1.0_f64, 1.0], [0.0, 1.0]]);
+        assert!(!shear.is_orthogonal(TOL));
+
+        let s = std::f32::consts::FRAC_1_SQRT_2;
+        let unitary = Matrix::from([
+            [Complex::new(s, 0.0), Complex::new(0.0, s)],
+            [Complex::new(0.0, s), Complex::new(s, 0.0)],
+        ]);
+        assert!(unitary.is_unitary(1e-6));
+        // Transpose instead of conjugate transpose misses the phase
+        assert!(!unitary.is_orthogonal(1e-6));
+    }
+
+    #[test]
+    fn test_triangular_and_diagonal() {
+        let upper = Matrix::from([[1.0_f64, 2.0, 3.0], [0.0, 4.0, 5.0], [0.0, 0.0, 6.0]]);
+        assert!(upper.is_upper_triangular(0.0));
+        assert!(!upper.is_lower_triangular(0.0));
+        assert!(!upper.is_diagonal(0.0));
+
+        let lower = upper.transpose();
+        assert!(lower.is_lower_triangular(0.0));
+        assert!(!lower.is_upper_triangular(0.0));
+
+        let diagonal = Matrix::from([[1.0_f64, 1e-12], [-1e-12, 2.0]]);
+        assert!(!diagonal.is_diagonal(0.0));
+        assert!(diagonal.is_diagonal(TOL));
+        assert!(diagonal.is_upper_triangular(TOL));
+        assert!(diagonal.is_lower_triangular(TOL));
+
+        // Echelon shape applies to rectangular matrices too
+        let wide = Matrix::from([[1.0_f64, 2.0, 3.0], [0.0, 4.0, 5.0]]);
+        assert!(wide.is_upper_triangular(0.0));
+    }
+
+    #[test]
+    fn test_positive_definite() {
+        let spd = Matrix::from([
+            [4.0_f64, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+        assert!(spd.is_positive_definite(TOL));
+
+        let indefinite = Matrix::from([[1.0_f64, 2.0], [2.0, 1.0]]);
+        assert!(!indefinite.is_positive_definite(TOL));
+
+        let semidefinite = Matrix::from([[1.0_f64, 1.0], [1.0, 1.0]]);
+        assert!(!semidefinite.is_positive_definite(TOL));
+
+        let not_symmetric = Matrix::from([[2.0_f64, 1.0], [0.0, 2.0]]);
+        assert!(!not_symmetric.is_positive_definite(TOL));
+
+        let hermitian = Matrix::from([
+            [Complex::new(2.0, 0.0), Complex::new(0.0, -1.0)],
+            [Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)],
+        ]);
+        assert!(hermitian.is_positive_definite(1e-6));
+
+        let exact = Matrix::from([
+            [Rational::new(2, 1), Rational::new(1, 2)],
+            [Rational::new(1, 2), Rational::new(1, 3)],
+        ]);
+        assert!(exact.is_positive_definite(0.0));
+    }
+
+    #[test]
+    fn test_idempotent_and_nilpotent() {
+        // Projection onto the line y = x
+        let projection = Matrix::from([[0.5_f64, 0.5], [0.5, 0.5]]);
+        assert!(projection.is_idempotent(TOL));
+        assert!(!projection.is_nilpotent(TOL));
+
+        let identity = Matrix::<f64>::identity(3);
+        assert!(identity.is_idempotent(0.0));
+
+        let strictly_upper = Matrix::from([[0.0_f64, 1.0, 2.0], [0.0, 0.0, 3.0], [0.0, 0.0, 0.0]]);
+        assert!(strictly_upper.is_nilpotent(0.0));
+        assert!(!strictly_upper.is_idempotent(0.0));
+
+        let not_triangular = Matrix::from([[2.0_f64, -4.0], [1.0, -2.0]]);
+        assert!(not_triangular.is_nilpotent(0.0));
+
+        assert!(Matrix::<i32>::zeros(2, 2).is_nilpotent(0.0));
+        assert!(!Matrix::from([[1, 0], [0, 0]]).is_nilpotent(0.0));
+    }
+
+    #[test]
+    fn test_diagonally_dominant() {
+        let strict = Matrix::from([[4.0_f64, 1.0, -2.0], [1.0, -5.0, 3.0], [0.0, 2.0, 3.0]]);
+        assert!(strict.is_diagonally_dominant(0.0));
+        assert!(strict.is_strictly_diagonally_dominant(0.0));
+
+        // Equality in the second row is dominant but not strictly so
+        let weak = Matrix::from([[2.0_f64, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+        assert!(weak.is_diagonally_dominant(0.0));
+        assert!(!weak.is_strictly_diagonally_dominant(0.0));
+
+        let not_dominant = Matrix::from([[1.0_f64, 2.0], [0.0, 1.0]]);
+        assert!(!not_dominant.is_diagonally_dominant(0.0));
+        assert!(not_dominant.is_diagonally_dominant(1.0));
+    }
+}
@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod matrix_tests {
-    use linear_algebra_42::{matrix::Matrix, vector::Vector};
+    use linear_algebra_42::{
+        matrix::Matrix, vector::Vector, CheckedInverseError, LinearSystemError, MatrixInverseError,
+        MatrixShapeError, Rational,
+    };
 
     #[test]
     fn test_new_success() {
@@ -787,6 +790,35 @@ mod matrix_tests {
         assert_eq!(det, 0);
     }
 
+    #[test]
+    fn test_determinant_empty() {
+        let matrix = Matrix::<i32>::from_fn(0, 0, |_, _| 0);
+
+        assert_eq!(matrix.determinant(), 1);
+    }
+
+    #[test]
+    fn test_determinant_pivoting_stays_exact() {
+        // The zero in the corner forces a row swap, and Bareiss keeps
+        // every intermediate an integer
+        let matrix = Matrix::from([[0, 2, 1, 3], [1, 0, 2, 1], [4, 1, 0, 2], [3, 5, 1, 0]]);
+
+        assert_eq!(matrix.determinant(), -126);
+    }
+
+    #[test]
+    fn test_determinant_large_tridiagonal() {
+        // The n x n matrix with 2 on the diagonal and -1 beside it has determinant n + 1
+        let n = 40;
+        let matrix = Matrix::from_fn(n, n, |i, j| match i.abs_diff(j) {
+            0 => 2.0_f64,
+            1 => -1.0,
+            _ => 0.0,
+        });
+
+        assert!((matrix.determinant() - (n + 1) as f64).abs() < 1e-9);
+    }
+
     #[test]
     fn test_row_echelon_all_zeros_row() {
         let matrix = Matrix::from([[1.0_f64, 2.0, 3.0], [0.0, 0.0, 0.0], [4.0, 5.0, 6.0]]);
@@ -956,7 +988,12 @@ mod matrix_tests {
 
         let result = matrix.inverse();
 
-        assert!(result.is_err());
+        assert!(result.is_ok());
+        let inv = result.unwrap();
+        assert!((inv[0][0] - (-1.0)).abs() < 1e-10);
+        assert!((inv[0][1] - 1.0).abs() < 1e-10);
+        assert!((inv[1][0] - 1.0).abs() < 1e-10);
+        assert!(inv[1][1].abs() < 1e-10);
     }
 
     #[test]
@@ -1259,6 +1296,26 @@ mod matrix_tests {
         assert_eq!(rank, 2);
     }
 
+    #[test]
+    fn test_rank_is_exact_for_rationals() {
+        let r = |n: i64, d: i64| Rational::new(n, d);
+        let mut matrix = Matrix::from([
+            [r(1, 1), r(2, 1), r(3, 1)],
+            [
+                r(1, 1_000_000_000_000_000),
+                r(1, 100_000_000_000_000),
+                r(1, 10_000_000_000_000),
+            ],
+            [r(4, 1), r(5, 1), r(6, 1)],
+        ]);
+
+        // The tiny row is not a combination of the others
+        assert_eq!(matrix.rank(), 3);
+
+        let mut float = Matrix::from([[1.0_f64, 2.0, 3.0], [1e-15, 1e-14, 1e-13], [4.0, 5.0, 6.0]]);
+        assert_eq!(float.rank_with_tolerance(0.0), 3);
+    }
+
     #[test]
     fn test_rank_precision_boundary() {
         let mut matrix = Matrix::from([[1.0_f64, 2.0], [1e-11, 2e-11]]);
@@ -1302,4 +1359,612 @@ mod matrix_tests {
         assert_eq!(rank, 2);
         assert_ne!(matrix, original);
     }
+
+    #[test]
+    fn test_null_space_full_rank() {
+        let matrix = Matrix::from([[1.0_f64, 2.0], [3.0, 4.0]]);
+
+        assert!(matrix.null_space().is_empty());
+    }
+
+    #[test]
+    fn test_null_space_rank_deficient() {
+        let matrix = Matrix::from([[1.0_f64, 2.0, 3.0], [2.0, 4.0, 6.0]]);
+
+        let basis = matrix.null_space();
+
+        assert_eq!(basis.len(), 2);
+        for v in &basis {
+            let image = matrix.transpose().mul_vec(v);
+            assert!(image.norm() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_null_space_zero_matrix() {
+        let matrix = Matrix::from([[0.0_f64, 0.0], [0.0, 0.0]]);
+
+        let basis = matrix.null_space();
+
+        assert_eq!(
+            basis,
+            vec![Vector::from([1.0, 0.0]), Vector::from([0.0, 1.0])]
+        );
+    }
+
+    #[test]
+    fn test_solve_requires_pivoting() {
+        let matrix = Matrix::from([[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [2.0, 0.0, 3.0]]);
+        let b = Vector::from([7.0, 3.0, 11.0]);
+
+        let x = matrix.solve(&b).unwrap();
+
+        for (value, expected) in x.iter().zip([1.0_f64, 2.0, 3.0]) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        let singular = Matrix::from([[1.0, 2.0], [2.0, 4.0]]);
+        assert!(singular.solve(&Vector::from([1.0, 2.0])).is_err());
+
+        let rectangular = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert!(rectangular.solve(&Vector::from([1.0, 2.0])).is_err());
+
+        let square = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+        assert!(matches!(
+            square.solve(&Vector::from([1.0, 2.0, 3.0])),
+            Err(LinearSystemError::RhsLength {
+                expected: 2,
+                found: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn test_matrix_entrywise_and_induced_norms() {
+        let matrix = Matrix::from([[1.0, -2.0], [3.0, 4.0]]);
+
+        assert!((matrix.norm_frobenius() - 30.0_f32.sqrt()).abs() < 1e-6);
+        assert_eq!(matrix.norm_1(), 6.0);
+        assert_eq!(matrix.norm_inf(), 7.0);
+        assert_eq!(matrix.norm_max(), 4.0);
+    }
+
+    #[test]
+    fn test_singular_values_rectangular() {
+        let wide = Matrix::from([[1.0, 0.0, 0.0], [0.0, -2.0, 0.0]]);
+
+        let sigma = wide.singular_values();
+        assert_eq!(sigma.len(), 2);
+        assert!((sigma[0] - 2.0_f64).abs() < 1e-12);
+        assert!((sigma[1] - 1.0_f64).abs() < 1e-12);
+        assert_eq!(wide.transpose().singular_values(), sigma);
+    }
+
+    #[test]
+    fn test_spectral_norm_bounds() {
+        let matrix = Matrix::from([[4.0, 1.0, -2.0], [0.5, 3.0, 1.0], [2.0, -1.0, 5.0]]);
+        let norm_2 = matrix.norm_2();
+
+        // ‖A‖₂² ≤ ‖A‖₁·‖A‖∞ and ‖A‖₂ ≤ ‖A‖_F
+        assert!(norm_2 * norm_2 <= matrix.norm_1() * matrix.norm_inf() + 1e-4);
+        assert!(norm_2 <= matrix.norm_frobenius() + 1e-6);
+
+        let gram = matrix.transpose().mul_mat(&matrix);
+        let sigma = matrix.singular_values();
+        let trace: f64 = sigma.iter().map(|s| s * s).sum();
+        assert!((trace - gram.trace()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_condition_number_hilbert() {
+        let hilbert = Matrix::from([
+            [1.0, 1.0 / 2.0, 1.0 / 3.0],
+            [1.0 / 2.0, 1.0 / 3.0, 1.0 / 4.0],
+            [1.0 / 3.0, 1.0 / 4.0, 1.0 / 5.0],
+        ]);
+
+        assert!((hilbert.condition_number() - 524.0568).abs() < 1e-2);
+
+        let singular = Matrix::from([[1.0, 2.0], [2.0, 4.0]]);
+        assert_eq!(singular.condition_number(), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_condition_number_estimate_close_to_exact() {
+        let matrices = [
+            Matrix::from([[4.0, 1.0, -2.0], [0.5, 3.0, 1.0], [2.0, -1.0, 5.0]]),
+            Matrix::from([[1.0, 1.0], [1.0, 1.0001]]),
+            Matrix::from([
+                [10.0, -7.0, 0.0, 1.0],
+                [-3.0, 2.0, 6.0, 0.0],
+                [5.0, -1.0, 5.0, 2.0],
+                [0.0, 1.0, 0.0, 3.0],
+            ]),
+        ];
+
+        for matrix in matrices {
+            let exact = matrix.norm_1() * matrix.inverse().unwrap().norm_1();
+            let estimate = matrix.condition_number_estimate().unwrap();
+
+            assert!(estimate <= exact * 1.0001, "{estimate} > {exact}");
+            assert!(estimate >= exact / 3.0, "{estimate} < {exact} / 3");
+        }
+    }
+
+    #[test]
+    fn test_condition_number_estimate_edge_cases() {
+        let singular = Matrix::from([[1.0, 2.0], [2.0, 4.0]]);
+        assert_eq!(singular.condition_number_estimate().unwrap(), f32::INFINITY);
+
+        let rectangular = Matrix::from([[1.0, 2.0, 3.0]]);
+        assert!(rectangular.condition_number_estimate().is_err());
+    }
+
+    #[test]
+    fn test_inverse_checked_rejects_ill_conditioned() {
+        let well = Matrix::from([[2.0, 1.0], [1.0, 3.0]]);
+        assert_eq!(well.inverse_checked(1e3).unwrap(), well.inverse().unwrap());
+
+        let ill = Matrix::from([[1.0, 1.0], [1.0, 1.0001]]);
+        let err = ill.inverse_checked(1e3).unwrap_err();
+        assert!(matches!(
+            err,
+            CheckedInverseError::IllConditioned { condition } if condition > 1e4
+        ));
+        assert!(err.to_string().contains("mal condicionada"));
+
+        let singular = Matrix::from([[1.0, 2.0], [2.0, 4.0]]);
+        assert!(matches!(
+            singular.inverse_checked(1e3),
+            Err(CheckedInverseError::Inverse(MatrixInverseError::Singular))
+        ));
+    }
+
+    #[test]
+    fn test_hadamard_product_and_division() {
+        let a = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+        let b = Matrix::from([[2.0, 0.5], [-1.0, 8.0]]);
+
+        assert_eq!(a.hadamard(&b), Matrix::from([[2.0, 1.0], [-3.0, 32.0]]));
+        assert_eq!(a.hadamard_div(&b), Matrix::from([[0.5, 4.0], [-3.0, 0.5]]));
+        assert_eq!(a.hadamard(&b), b.hadamard(&a));
+    }
+
+    #[test]
+    fn test_kronecker_product() {
+        let a = Matrix::from([[1, 2], [3, 4]]);
+        let b = Matrix::from([[0, 5], [6, 7]]);
+
+        let expected = Matrix::from([
+            [0, 5, 0, 10],
+            [6, 7, 12, 14],
+            [0, 15, 0, 20],
+            [18, 21, 24, 28],
+        ]);
+        assert_eq!(a.kronecker(&b), expected);
+    }
+
+    #[test]
+    fn test_kronecker_shapes_and_mixed_product() {
+        let a = Matrix::from([[1.0, 2.0, 0.0], [0.0, 1.0, -1.0]]);
+        let b = Matrix::from([[2.0], [3.0]]);
+        let c = Matrix::from([[1.0, 0.0], [2.0, 1.0], [0.0, 3.0]]);
+        let d = Matrix::from([[4.0, -1.0]]);
+
+        assert_eq!(a.kronecker(&b).shape(), (4, 3));
+
+        // (A ⊗ B)(C ⊗ D) = (AC) ⊗ (BD)
+        let left = a.kronecker(&b).mul_mat(&c.kronecker(&d));
+        let right = a.mul_mat(&c).kronecker(&b.mul_mat(&d));
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn test_kronecker_determinant_identity() {
+        let a = Matrix::from([[2.0_f64, 1.0], [1.0, 3.0]]);
+        let b = Matrix::from([[1.0_f64, 4.0], [2.0, 1.0]]);
+
+        // det(A ⊗ B) = det(A)² · det(B)² for 2x2 factors
+        let expected = a.determinant().powi(2) * b.determinant().powi(2);
+        assert!((a.kronecker(&b).determinant() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_hstack_and_vstack() {
+        let a = Matrix::from([[1, 2], [3, 4]]);
+        let b = Matrix::from([[5], [6]]);
+        let c = Matrix::from([[7, 8]]);
+
+        assert_eq!(
+            Matrix::hstack(&[&a, &b, &a]).unwrap(),
+            Matrix::from([[1, 2, 5, 1, 2], [3, 4, 6, 3, 4]])
+        );
+        assert_eq!(
+            Matrix::vstack(&[&c, &a]).unwrap(),
+            Matrix::from([[7, 8], [1, 2], [3, 4]])
+        );
+        assert_eq!(Matrix::<i32>::hstack(&[]).unwrap().shape(), (0, 0));
+    }
+
+    #[test]
+    fn test_stack_dimension_mismatch() {
+        let a = Matrix::from([[1, 2], [3, 4]]);
+        let c = Matrix::from([[7, 8, 9]]);
+
+        assert!(matches!(
+            Matrix::hstack(&[&a, &c]),
+            Err(MatrixShapeError::RowsMismatch {
+                expected: 2,
+                found: 1
+            })
+        ));
+        assert!(matches!(
+            Matrix::vstack(&[&a, &c]),
+            Err(MatrixShapeError::ColumnsMismatch {
+                expected: 2,
+                found: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn test_block_diag() {
+        let a = Matrix::from([[1.0_f64, 2.0], [3.0, 4.0]]);
+        let b = Matrix::from([[5.0]]);
+
+        let result = Matrix::block_diag(&[&a, &b, &a]);
+
+        assert_eq!(result.shape(), (5, 5));
+        assert_eq!(result.block(0, 0, 2, 2).unwrap(), a);
+        assert_eq!(result[2][2], 5.0);
+        assert_eq!(result.block(3, 3, 2, 2).unwrap(), a);
+        assert_eq!(result.block(0, 2, 2, 3).unwrap(), Matrix::zeros(2, 3));
+        assert!((result.determinant() - 5.0 * 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_from_blocks_saddle_point_system() {
+        let h = Matrix::from([[4.0_f64, 1.0], [1.0, 3.0]]);
+        let a = Matrix::from([[1.0, 1.0]]);
+        let zero = Matrix::zeros(1, 1);
+
+        let kkt = Matrix::from_blocks([[&h, &a.transpose()], [&a, &zero]]).unwrap();
+
+        assert_eq!(
+            kkt,
+            Matrix::from([[4.0, 1.0, 1.0], [1.0, 3.0, 1.0], [1.0, 1.0, 0.0]])
+        );
+
+        // minimize ½xᵀHx subject to x₀ + x₁ = 1
+        let solution = kkt.solve(&Vector::from([0.0, 0.0, 1.0])).unwrap();
+        assert!((solution[0] + solution[1] - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_from_blocks_dimension_validation() {
+        let a = Matrix::from([[1, 2], [3, 4]]);
+        let b = Matrix::from([[5], [6]]);
+        let c = Matrix::from([[7, 8, 9]]);
+        let d = Matrix::from([[0]]);
+
+        // Same total width but misaligned block columns
+        assert!(matches!(
+            Matrix::from_blocks([[&a, &b], [&d, &Matrix::from([[1, 2]])]]),
+            Err(MatrixShapeError::ColumnsMismatch {
+                expected: 2,
+                found: 1
+            })
+        ));
+        assert!(matches!(
+            Matrix::from_blocks([[&a, &c]]),
+            Err(MatrixShapeError::RowsMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_split_round_trips() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+
+        let (top, bottom) = m.split_at_row(1).unwrap();
+        assert_eq!(top, Matrix::from([[1, 2, 3]]));
+        assert_eq!(Matrix::vstack(&[&top, &bottom]).unwrap(), m);
+
+        let (left, right) = m.split_at_col(2).unwrap();
+        assert_eq!(right, Matrix::from([[3], [6], [9]]));
+        assert_eq!(Matrix::hstack(&[&left, &right]).unwrap(), m);
+
+        let (all, none) = m.split_at_col(3).unwrap();
+        assert_eq!(all, m);
+        assert_eq!(none.shape(), (3, 0));
+    }
+
+    #[test]
+    fn test_split_and_block_out_of_bounds() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+
+        assert!(matches!(
+            m.split_at_row(3),
+            Err(MatrixShapeError::IndexOutOfBounds { index: 3, len: 2 })
+        ));
+        assert!(m.split_at_col(4).is_err());
+        assert!(m.block(1, 0, 2, 1).is_err());
+        assert!(m.block(0, usize::MAX, 1, 2).is_err());
+        assert_eq!(
+            m.block(0, 2, 1, 2).unwrap_err().to_string(),
+            "Bloco 1x2 na posição (0, 2) excede a matriz 2x3"
+        );
+    }
+
+    #[test]
+    fn test_swap_rows_and_cols() {
+        let mut m = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+
+        m.swap_rows(0, 1).unwrap();
+        assert_eq!(m, Matrix::from([[4, 5, 6], [1, 2, 3]]));
+
+        m.swap_cols(0, 2).unwrap();
+        assert_eq!(m, Matrix::from([[6, 5, 4], [3, 2, 1]]));
+
+        assert!(matches!(
+            m.swap_rows(0, 2),
+            Err(MatrixShapeError::IndexOutOfBounds { index: 2, len: 2 })
+        ));
+        assert!(m.swap_cols(3, 0).is_err());
+        assert_eq!(m, Matrix::from([[6, 5, 4], [3, 2, 1]]));
+    }
+
+    #[test]
+    fn test_scale_row_and_add_row_multiple() {
+        let mut m = Matrix::from([[2.0, 4.0], [1.0, 3.0]]);
+
+        m.scale_row(0, 0.5).unwrap();
+        m.add_row_multiple(1, 0, -1.0).unwrap();
+
+        assert_eq!(m, Matrix::from([[1.0, 2.0], [0.0, 1.0]]));
+        assert!(m.scale_row(2, 1.0).is_err());
+        assert!(m.add_row_multiple(0, 5, 1.0).is_err());
+        assert!(matches!(
+            m.add_row_multiple(1, 1, -1.0),
+            Err(MatrixShapeError::SameRow { index: 1 })
+        ));
+        assert_eq!(m, Matrix::from([[1.0, 2.0], [0.0, 1.0]]));
+    }
+
+    #[test]
+    fn test_insert_and_remove_rows() {
+        let mut m = Matrix::from([[1, 2], [5, 6]]);
+
+        m.insert_row(1, &Vector::from([3, 4])).unwrap();
+        m.insert_row(3, &Vector::from([7, 8])).unwrap();
+        assert_eq!(m, Matrix::from([[1, 2], [3, 4], [5, 6], [7, 8]]));
+        assert_eq!(m.shape(), (4, 2));
+
+        assert_eq!(m.remove_row(0).unwrap(), Vector::from([1, 2]));
+        assert_eq!(m.shape(), (3, 2));
+        assert_eq!(m, Matrix::from([[3, 4], [5, 6], [7, 8]]));
+
+        assert!(matches!(
+            m.insert_row(0, &Vector::from([1, 2, 3])),
+            Err(MatrixShapeError::ColumnsMismatch {
+                expected: 2,
+                found: 3
+            })
+        ));
+        assert!(m.insert_row(5, &Vector::from([1, 2])).is_err());
+        assert!(m.remove_row(3).is_err());
+    }
+
+    #[test]
+    fn test_insert_and_remove_cols() {
+        let mut m = Matrix::from([[1, 3], [4, 6]]);
+
+        m.insert_col(1, &Vector::from([2, 5])).unwrap();
+        assert_eq!(m, Matrix::from([[1, 2, 3], [4, 5, 6]]));
+        assert_eq!(m.shape(), (2, 3));
+
+        assert_eq!(m.remove_col(2).unwrap(), Vector::from([3, 6]));
+        assert_eq!(m, Matrix::from([[1, 2], [4, 5]]));
+        assert_eq!(m.shape(), (2, 2));
+
+        assert!(matches!(
+            m.insert_col(0, &Vector::from([1])),
+            Err(MatrixShapeError::RowsMismatch {
+                expected: 2,
+                found: 1
+            })
+        ));
+        assert!(m.remove_col(2).is_err());
+    }
+
+    #[test]
+    fn test_insert_into_empty_matrix() {
+        let mut rows: Matrix<i32> = Matrix::zeros(0, 0);
+        rows.insert_row(0, &Vector::from([1, 2, 3])).unwrap();
+        assert_eq!(rows, Matrix::from([[1, 2, 3]]));
+
+        let mut cols: Matrix<i32> = Matrix::zeros(0, 0);
+        cols.insert_col(0, &Vector::from([1, 2])).unwrap();
+        assert_eq!(cols, Matrix::from([[1], [2]]));
+    }
+
+    #[test]
+    fn test_select_rows_and_cols() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+
+        assert_eq!(
+            m.select_rows(&[2, 2, 0]).unwrap(),
+            Matrix::from([[7, 8, 9], [7, 8, 9], [1, 2, 3]])
+        );
+        assert_eq!(m.select_cols(&[1]).unwrap(), Matrix::from([[2], [5], [8]]));
+        assert_eq!(m.select_cols(&[]).unwrap().shape(), (3, 0));
+        assert!(m.select_rows(&[0, 3]).is_err());
+        assert!(m.select_cols(&[9]).is_err());
+    }
+
+    #[test]
+    fn test_elementary_matrices_apply_row_operations() {
+        let a = Matrix::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        let mut swapped = a.clone();
+        swapped.swap_rows(0, 2).unwrap();
+        assert_eq!(
+            Matrix::elementary_swap(3, 0, 2).unwrap().mul_mat(&a),
+            swapped
+        );
+
+        let mut scaled = a.clone();
+        scaled.scale_row(1, -2.0).unwrap();
+        assert_eq!(
+            Matrix::elementary_scale(3, 1, -2.0).unwrap().mul_mat(&a),
+            scaled
+        );
+
+        let mut added = a.clone();
+        added.add_row_multiple(2, 0, 3.0).unwrap();
+        let e = Matrix::elementary_add(3, 2, 0, 3.0).unwrap();
+        assert_eq!(e.mul_mat(&a), added);
+        assert_eq!(e.determinant(), 1.0);
+
+        assert!(Matrix::<f64>::elementary_swap(2, 0, 2).is_err());
+        assert!(Matrix::elementary_scale(2, 2, 1.0).is_err());
+        assert!(matches!(
+            Matrix::elementary_scale(2, 0, 0.0),
+            Err(MatrixShapeError::ZeroScaleFactor)
+        ));
+        assert!(matches!(
+            Matrix::elementary_add(3, 1, 1, -1.0),
+            Err(MatrixShapeError::SameRow { index: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_permutation_matrix() {
+        let a = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+        let order = [2, 0, 1];
+
+        let p: Matrix<i32> = Matrix::permutation(&order).unwrap();
+
+        assert_eq!(p.mul_mat(&a), a.select_rows(&order).unwrap());
+        assert_eq!(
+            p.mul_mat(&p.transpose()),
+            Matrix::permutation(&[0, 1, 2]).unwrap()
+        );
+        assert!(matches!(
+            Matrix::<i32>::permutation(&[0, 0, 1]),
+            Err(MatrixShapeError::InvalidPermutation { index: 0 })
+        ));
+        assert!(Matrix::<i32>::permutation(&[0, 3, 1]).is_err());
+    }
+
+    #[test]
+    fn test_identity_and_filled() {
+        let identity: Matrix<f64> = Matrix::identity(3);
+
+        assert_eq!(
+            identity,
+            Matrix::from([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]])
+        );
+        assert_eq!(Matrix::<i32>::identity(0).shape(), (0, 0));
+        assert_eq!(
+            Matrix::filled(2, 3, 7),
+            Matrix::from([[7, 7, 7], [7, 7, 7]])
+        );
+
+        let a = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+        assert_eq!(a.mul_mat(&Matrix::identity(2)), a);
+    }
+
+    #[test]
+    fn test_from_diagonal_and_from_fn() {
+        let d = Matrix::from_diagonal(&Vector::from([1, 2, 3]));
+
+        assert_eq!(d, Matrix::from([[1, 0, 0], [0, 2, 0], [0, 0, 3]]));
+        assert_eq!(
+            Matrix::from_fn(3, 2, |i, j| (i as i32) - (j as i32)),
+            Matrix::from([[0, -1], [1, 0], [2, 1]])
+        );
+    }
+
+    #[test]
+    fn test_vandermonde_interpolation() {
+        // Fit p(x) = 1 + 2x + 3x² through three points
+        let points = Vector::from([-1.0, 0.5, 2.0]);
+        let values = Vector::from([2.0, 2.75, 17.0]);
+
+        let v = Matrix::vandermonde(&points, 3);
+        let coefficients = v.solve(&values).unwrap();
+
+        for (c, expected) in coefficients.iter().zip([1.0_f64, 2.0, 3.0]) {
+            assert!((c - expected).abs() < 1e-12);
+        }
+        assert_eq!(Matrix::vandermonde(&points, 2).shape(), (3, 2));
+    }
+
+    #[test]
+    fn test_toeplitz_and_hankel_rectangular() {
+        let t = Matrix::toeplitz(&Vector::from([1, 2]), &Vector::from([9, 3, 4]));
+        assert_eq!(t, Matrix::from([[1, 3, 4], [2, 1, 3]]));
+
+        let h = Matrix::hankel(&Vector::from([1, 2]), &Vector::from([9, 3, 4]));
+        assert_eq!(h, Matrix::from([[1, 2, 3], [2, 3, 4]]));
+    }
+
+    #[test]
+    fn test_circulant_multiplication_is_convolution() {
+        let c = Matrix::circulant(&Vector::from([1, 2, 3, 4]));
+
+        assert_eq!(c, c.transpose().transpose());
+        for i in 0..4 {
+            for j in 0..4 {
+                assert_eq!(c[(i + 1) % 4][(j + 1) % 4], c[i][j]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hilbert() {
+        let h: Matrix<f64> = Matrix::hilbert(3);
+
+        assert_eq!(h[0][0], 1.0);
+        assert_eq!(h[1][2], 0.25);
+        assert_eq!(h, h.transpose());
+        assert!(h.condition_number() > 500.0);
+    }
+
+    #[test]
+    fn test_companion_roots() {
+        // (x - 1)(x - 2)(x - 3) = x³ - 6x² + 11x - 6
+        let c = Matrix::companion(&Vector::from([-6.0_f64, 11.0, -6.0]));
+
+        assert_eq!(c.trace(), 6.0);
+        assert_eq!(c.determinant(), 6.0);
+        for root in [1.0, 2.0, 3.0] {
+            let mut shifted = c.clone();
+            shifted.sub(&Matrix::identity(3).scl_new(root));
+            assert!(shifted.determinant().abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_vector_basis_and_linspace() {
+        assert_eq!(Vector::basis(3, 1), Vector::from([0, 1, 0]));
+        assert_eq!(
+            Vector::linspace(-1.0, 1.0, 3),
+            Vector::from([-1.0, 0.0, 1.0])
+        );
+        assert_eq!(Vector::linspace(2.0, 5.0, 1), Vector::from([2.0]));
+        assert!(Vector::<f32>::linspace(0.0, 1.0, 0).is_empty());
+
+        let points = Vector::linspace(0.0, 0.3, 4);
+        assert_eq!(points[3], 0.3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vector_basis_out_of_bounds() {
+        let _: Vector<f32> = Vector::basis(2, 2);
+    }
 }
@@ -0,0 +1,272 @@
+mod common;
+
+use linear_algebra_42::{
+    Banded, Diagonal, LowerTriangular, Matrix, MatrixInverseError, PackedMatrixError, Rational,
+    Symmetric, UpperTriangular, Vector,
+};
+
+#[cfg(test)]
+mod packed_tests {
+    use super::*;
+    use crate::common::{assert_mat_close, assert_vec_close};
+
+    #[test]
+    fn test_diagonal() {
+        let d = Diagonal::from(Vector::from([2.0, -1.0, 4.0]));
+        let dense = d.to_matrix();
+        let v = Vector::from([1.0, 2.0, 3.0]);
+        let b = Matrix::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        assert_eq!(d.size(), 3);
+        assert_eq!(d.get(1, 1), -1.0);
+        assert_eq!(d.get(0, 2), 0.0);
+        assert_eq!(d.mul_vec(&v), dense.mul_vec(&v));
+        assert_eq!(d.mul_mat(&b), dense.mul_mat(&b));
+        assert_eq!(d.determinant(), -8.0);
+        assert_eq!(d.solve(&v).unwrap(), Vector::from([0.5, -2.0, 0.75]));
+        assert_eq!(
+            d.inverse().unwrap(),
+            Diagonal::from(Vector::from([0.5, -1.0, 0.25]))
+        );
+
+        assert_eq!(Diagonal::try_from(&dense).unwrap(), d);
+        assert_eq!(Matrix::from(&d), dense);
+    }
+
+    #[test]
+    fn test_diagonal_singular() {
+        let d = Diagonal::from(Vector::from([1.0, 0.0]));
+
+        assert!(matches!(
+            d.solve(&Vector::from([1.0, 1.0])),
+            Err(MatrixInverseError::Singular)
+        ));
+        assert!(matches!(d.inverse(), Err(MatrixInverseError::Singular)));
+    }
+
+    #[test]
+    fn test_upper_triangular() {
+        let dense = Matrix::from([[2.0, 1.0, -1.0], [0.0, 3.0, 2.0], [0.0, 0.0, 4.0]]);
+        let u = UpperTriangular::try_from(&dense).unwrap();
+        let v = Vector::from([1.0, -2.0, 3.0]);
+        let b = Matrix::from([[1.0, 0.0], [2.0, 1.0], [0.0, 3.0]]);
+
+        assert_eq!(u.to_matrix(), dense);
+        assert_eq!(u.get(2, 0), 0.0);
+        assert_eq!(u.mul_vec(&v), dense.mul_vec(&v));
+        assert_eq!(u.mul_mat(&b), dense.mul_mat(&b));
+        assert_eq!(u.determinant(), 24.0);
+        assert_vec_close(&u.solve(&v).unwrap(), &dense.solve(&v).unwrap());
+
+        let inverse = u.inverse().unwrap();
+        assert_mat_close(&inverse.to_matrix(), &dense.inverse().unwrap());
+        assert_mat_close(&u.mul_mat(&inverse.to_matrix()), &Matrix::identity(3));
+
+        assert_eq!(u.transpose().to_matrix(), dense.transpose());
+    }
+
+    #[test]
+    fn test_lower_triangular() {
+        let dense = Matrix::from([[3.0, 0.0, 0.0], [1.0, -2.0, 0.0], [4.0, 5.0, 0.5]]);
+        let l = LowerTriangular::try_from(&dense).unwrap();
+        let v = Vector::from([2.0, 1.0, -1.0]);
+        let b = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+        assert_eq!(Matrix::from(&l), dense);
+        assert_eq!(l.get(0, 1), 0.0);
+        assert_eq!(l.mul_vec(&v), dense.mul_vec(&v));
+        assert_eq!(l.mul_mat(&b), dense.mul_mat(&b));
+        assert_eq!(l.determinant(), -3.0);
+        assert_vec_close(&l.solve(&v).unwrap(), &dense.solve(&v).unwrap());
+
+        let inverse = l.inverse().unwrap();
+        assert_mat_close(&inverse.to_matrix(), &dense.inverse().unwrap());
+
+        assert_eq!(l.transpose().transpose(), l);
+    }
+
+    #[test]
+    fn test_triangular_singular() {
+        let u = UpperTriangular::from_fn(3, |i, j| if i == 1 && j == 1 { 0.0 } else { 1.0 });
+        let l = u.transpose();
+        let b = Vector::from([1.0, 1.0, 1.0]);
+
+        assert!(matches!(u.solve(&b), Err(MatrixInverseError::Singular)));
+        assert!(matches!(l.solve(&b), Err(MatrixInverseError::Singular)));
+        assert!(matches!(u.inverse(), Err(MatrixInverseError::Singular)));
+        assert!(matches!(l.inverse(), Err(MatrixInverseError::Singular)));
+        assert_eq!(u.determinant(), 0.0);
+    }
+
+    #[test]
+    fn test_triangular_exact() {
+        let l = LowerTriangular::from_fn(4, |i, j| Rational::new((i + j + 1) as i64, 1));
+        let inverse = l.inverse().unwrap();
+
+        assert_eq!(
+            l.to_matrix().mul_mat(&inverse.to_matrix()),
+            Matrix::identity(4)
+        );
+    }
+
+    #[test]
+    fn test_symmetric() {
+        let dense = Matrix::from([[0.0_f64, 1.0, 2.0], [1.0, 0.0, 3.0], [2.0, 3.0, 5.0]]);
+        let s = Symmetric::try_from(&dense).unwrap();
+        let v = Vector::from([1.0, 2.0, 3.0]);
+        let b = Matrix::from([[1.0], [0.0], [-1.0]]);
+
+        assert_eq!(s.to_matrix(), dense);
+        assert_eq!(s.get(0, 2), s.get(2, 0));
+        assert_eq!(s.mul_vec(&v), dense.mul_vec(&v));
+        assert_eq!(s.mul_mat(&b), dense.mul_mat(&b));
+        assert!((s.determinant() - dense.determinant()).abs() < 1e-12);
+
+        // Zero diagonal needs pivoting
+        assert_vec_close(&s.solve(&v).unwrap(), &dense.solve(&v).unwrap());
+    }
+
+    #[test]
+    fn test_symmetric_indefinite_matches_dense_exactly() {
+        // Zero or tiny diagonals against large off-diagonal entries force
+        // 2x2 pivots and symmetric swaps
+        let cases = [
+            Symmetric::from_fn(2, |i, j| if i == j { 0 } else { 1 }),
+            Symmetric::from_fn(5, |i, j| {
+                if i == j {
+                    (i as i64 % 2) - 1
+                } else {
+                    3 * i as i64 - 2 * j as i64 + 1
+                }
+            }),
+            Symmetric::from_fn(6, |i, j| ((i * 7 + j * 3) % 5) as i64 - 2),
+        ];
+
+        for s in cases {
+            let s = Symmetric::from_fn(s.size(), |i, j| Rational::from_integer(s.get(i, j)));
+            let dense = s.to_matrix();
+            let b = Vector::from(
+                (0..s.size())
+                    .map(|i| Rational::new(i as i64 + 1, 2))
+                    .collect::<Vec<_>>(),
+            );
+
+            assert_eq!(s.determinant(), dense.determinant());
+            assert_eq!(s.solve(&b).unwrap(), dense.solve(&b).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_symmetric_singular() {
+        let s = Symmetric::from_fn(3, |i, j| ((i + 1) * (j + 1)) as f64);
+
+        assert!(matches!(
+            s.solve(&Vector::from([1.0, 2.0, 3.0])),
+            Err(MatrixInverseError::Singular)
+        ));
+        assert_eq!(
+            Symmetric::from_fn(2, |_, _| Rational::from_integer(1)).determinant(),
+            Rational::from_integer(0)
+        );
+    }
+
+    #[test]
+    fn test_symmetric_not_symmetric() {
+        let dense = Matrix::from([[1.0, 2.0], [3.0, 1.0]]);
+
+        assert!(matches!(
+            Symmetric::try_from(&dense),
+            Err(PackedMatrixError::NotSymmetric { row: 1, col: 0 })
+        ));
+    }
+
+    #[test]
+    fn test_banded_matches_dense() {
+        // Pentadiagonal-ish band with small diagonal entries to force row swaps
+        let a = Banded::from_fn(7, 2, 1, |i, j| {
+            if i == j {
+                0.1 * (i as f64 + 1.0)
+            } else {
+                (i as f64) - 2.0 * (j as f64) + 3.0
+            }
+        });
+        let dense = a.to_matrix();
+        let v = Vector::from([1.0, -1.0, 2.0, 0.5, -3.0, 4.0, 1.5]);
+        let b = Matrix::from_fn(7, 2, |i, j| (i * 2 + j) as f64);
+
+        assert_eq!(a.lower_bandwidth(), 2);
+        assert_eq!(a.upper_bandwidth(), 1);
+        assert_eq!(a.get(0, 3), 0.0);
+        assert_eq!(a.get(5, 2), 0.0);
+        assert_eq!(a.mul_vec(&v), dense.mul_vec(&v));
+        assert_eq!(a.mul_mat(&b), dense.mul_mat(&b));
+
+        let x = a.solve(&v).unwrap();
+        assert_vec_close(&x, &dense.solve(&v).unwrap());
+
+        let det = a.determinant();
+        let expected = dense.determinant();
+        assert!((det - expected).abs() < 1e-9 * expected.abs().max(1.0));
+
+        assert_eq!(a.transpose().to_matrix(), dense.transpose());
+    }
+
+    #[test]
+    fn test_banded_conversion_detects_bandwidth() {
+        let dense = Matrix::from([
+            [1.0, 2.0, 0.0, 0.0],
+            [3.0, 4.0, 5.0, 0.0],
+            [0.0, 6.0, 7.0, 8.0],
+            [0.0, 0.0, 9.0, 1.0],
+        ]);
+        let a = Banded::try_from(&dense).unwrap();
+
+        assert_eq!((a.lower_bandwidth(), a.upper_bandwidth()), (1, 1));
+        assert_eq!(a.to_matrix(), dense);
+
+        let diagonal = Banded::try_from(&Matrix::<f64>::identity(3)).unwrap();
+        assert_eq!(
+            (diagonal.lower_bandwidth(), diagonal.upper_bandwidth()),
+            (0, 0)
+        );
+    }
+
+    #[test]
+    fn test_banded_singular() {
+        // First two rows coincide
+        let a = Banded::from_fn(3, 1, 1, |i, j| if (i, j) == (1, 2) { 0.0 } else { 1.0 });
+
+        assert!(matches!(
+            a.solve(&Vector::from([1.0, 2.0, 3.0])),
+            Err(MatrixInverseError::Singular)
+        ));
+        assert_eq!(a.determinant(), 0.0);
+    }
+
+    #[test]
+    fn test_conversion_errors() {
+        let rectangular = Matrix::from([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+        let upper = Matrix::from([[1.0, 2.0], [0.0, 3.0]]);
+
+        assert!(matches!(
+            Diagonal::try_from(&rectangular),
+            Err(PackedMatrixError::NotSquare {
+                rows: 2,
+                columns: 3
+            })
+        ));
+        assert!(matches!(
+            Diagonal::try_from(&upper),
+            Err(PackedMatrixError::OutsideStructure { row: 0, col: 1 })
+        ));
+        assert!(matches!(
+            LowerTriangular::try_from(&upper),
+            Err(PackedMatrixError::OutsideStructure { row: 0, col: 1 })
+        ));
+        assert!(UpperTriangular::try_from(&upper.transpose()).is_err());
+        assert_eq!(
+            format!("{}", PackedMatrixError::OutsideStructure { row: 0, col: 1 }),
+            "Entrada não nula na posição (0, 1) fora da estrutura compacta"
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use linear_algebra_42::{Complex, Matrix, ParseError, Vector};
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_vector() {
+        assert_eq!(
+            "[1, 2, 3]".parse::<Vector<i32>>().unwrap(),
+            Vector::from([1, 2, 3])
+        );
+        assert_eq!(
+            "  [ -1.5 ,2e1]\n".parse::<Vector<f64>>().unwrap(),
+            Vector::from([-1.5, 20.0])
+        );
+        assert!("[]".parse::<Vector<f64>>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_matrix_forms() {
+        let expected = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        for text in [
+            "[1, 2, 3]\n[4, 5, 6]",
+            "[1, 2, 3; 4, 5, 6]",
+            "[1, 2, 3;\n 4, 5, 6]",
+            "[[1, 2, 3], [4, 5, 6]]",
+            "[\n  [1, 2, 3],\n  [4, 5, 6]\n]",
+        ] {
+            assert_eq!(text.parse::<Matrix<f64>>().unwrap(), expected, "{text}");
+        }
+
+        assert_eq!("[7]".parse::<Matrix<i64>>().unwrap(), Matrix::from([[7]]));
+        assert_eq!("".parse::<Matrix<i64>>().unwrap().shape(), (0, 0));
+        assert_eq!("[]".parse::<Matrix<i64>>().unwrap().shape(), (0, 0));
+        assert_eq!(" [ ]\n".parse::<Matrix<i64>>().unwrap().shape(), (0, 0));
+        assert_eq!("[[]]".parse::<Matrix<i64>>().unwrap().shape(), (1, 0));
+    }
+
+    #[test]
+    fn test_row_forms_do_not_mix() {
+        assert_eq!(
+            "[1; 2]\n[3]".parse::<Matrix<i32>>(),
+            Err(ParseError::UnexpectedCharacter {
+                line: 2,
+                column: 1,
+                found: '['
+            })
+        );
+        assert_eq!(
+            "[1]\n[2; 3]".parse::<Matrix<i32>>(),
+            Err(ParseError::UnexpectedCharacter {
+                line: 2,
+                column: 3,
+                found: ';'
+            })
+        );
+        assert_eq!(
+            "[]\n[1]".parse::<Matrix<i32>>(),
+            Err(ParseError::UnexpectedCharacter {
+                line: 2,
+                column: 1,
+                found: '['
+            })
+        );
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let real = Matrix::from([[1.5, -20.0], [300.0, 0.25]]);
+        assert_eq!(real.to_string().parse::<Matrix<f64>>().unwrap(), real);
+
+        let complex = Matrix::from([
+            [Complex::new(1.0, 2.0), Complex::new(0.0, -1.0)],
+            [Complex::new(-3.0, -0.5), Complex::new(4.0, 0.0)],
+        ]);
+        assert_eq!(
+            complex.to_string().parse::<Matrix<Complex>>().unwrap(),
+            complex
+        );
+
+        let v = Vector::from([Complex::new(0.0, 1.0), Complex::new(2.0, -1.0)]);
+        assert_eq!(v.to_string().parse::<Vector<Complex>>().unwrap(), v);
+
+        // Any element type with FromStr works, not only the crate's own
+        let integers = Matrix::from([[10_u8, 2], [3, 255]]);
+        assert_eq!(
+            integers.to_string().parse::<Matrix<u8>>().unwrap(),
+            integers
+        );
+    }
+
+    #[test]
+    fn test_parse_complex() {
+        let cases = [
+            ("3", Complex::new(3.0, 0.0)),
+            ("i", Complex::new(0.0, 1.0)),
+            ("-i", Complex::new(0.0, -1.0)),
+            ("2.5i", Complex::new(0.0, 2.5)),
+            ("1 + i", Complex::new(1.0, 1.0)),
+            ("1 - 2i", Complex::new(1.0, -2.0)),
+            ("-1e2+3e-1i", Complex::new(-100.0, 0.3)),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(text.parse::<Complex>().unwrap(), expected, "{text}");
+        }
+
+        assert_eq!(
+            "  1 + xi".parse::<Complex>(),
+            Err(ParseError::InvalidNumber {
+                line: 1,
+                column: 3,
+                text: "1 + xi".to_string()
+            })
+        );
+        assert!("".parse::<Complex>().is_err());
+        assert!("1 + 2i + 3".parse::<Complex>().is_err());
+    }
+
+    #[test]
+    fn test_ragged_rows() {
+        assert_eq!(
+            "[1, 2, 3]\n[4, 5]".parse::<Matrix<i32>>(),
+            Err(ParseError::RaggedRow {
+                line: 2,
+                column: 2,
+                row: 2,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            "[1, 2; 3, 4; 5]".parse::<Matrix<i32>>(),
+            Err(ParseError::RaggedRow {
+                line: 1,
+                column: 14,
+                row: 3,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            "[[1], [2, 3]]"
+                .parse::<Matrix<i32>>()
+                .unwrap_err()
+                .position(),
+            (1, 8)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors_have_positions() {
+        assert_eq!(
+            "[1, 2,\n 3, x]".parse::<Vector<i32>>(),
+            Err(ParseError::InvalidNumber {
+                line: 2,
+                column: 5,
+                text: "x".to_string()
+            })
+        );
+        assert_eq!(
+            "[1, 2".parse::<Vector<i32>>(),
+            Err(ParseError::UnexpectedEnd { line: 1, column: 6 })
+        );
+        assert_eq!(
+            "[1, , 2]".parse::<Vector<i32>>(),
+            Err(ParseError::UnexpectedCharacter {
+                line: 1,
+                column: 5,
+                found: ','
+            })
+        );
+        assert_eq!(
+            "[1, 2] 3".parse::<Vector<i32>>(),
+            Err(ParseError::UnexpectedCharacter {
+                line: 1,
+                column: 8,
+                found: '3'
+            })
+        );
+        assert_eq!(
+            "[1; 2]".parse::<Vector<i32>>().unwrap_err().position(),
+            (1, 3)
+        );
+        assert_eq!(
+            "1, 2".parse::<Matrix<i32>>().unwrap_err().position(),
+            (1, 1)
+        );
+
+        let message = "[1, 2]\n[3]"
+            .parse::<Matrix<i32>>()
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("linha 2, coluna 2"), "{message}");
+    }
+}
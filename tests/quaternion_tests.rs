@@ -0,0 +1,203 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_3, PI};
+
+use linear_algebra_42::{Conjugate, Matrix, One, Quaternion, Vector, Zero};
+
+#[cfg(test)]
+mod quaternion_tests {
+    use super::*;
+
+    const EPS: f64 = 1e-9;
+
+    fn assert_vec_eq(a: &Vector<f64>, b: &Vector<f64>) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b.iter()) {
+            assert!((x - y).abs() < EPS, "{a:?} != {b:?}");
+        }
+    }
+
+    fn assert_quat_eq(a: &Quaternion<f64>, b: &Quaternion<f64>) {
+        // q and -q represent the same rotation
+        let sign = if a.dot(b) < 0.0 { -1.0 } else { 1.0 };
+        assert!((a.w() - sign * b.w()).abs() < EPS, "{a:?} != {b:?}");
+        assert!((a.x() - sign * b.x()).abs() < EPS, "{a:?} != {b:?}");
+        assert!((a.y() - sign * b.y()).abs() < EPS, "{a:?} != {b:?}");
+        assert!((a.z() - sign * b.z()).abs() < EPS, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn test_quaternion_hamilton_product_units() {
+        let i = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+        let j = Quaternion::new(0.0, 0.0, 1.0, 0.0);
+        let k = Quaternion::new(0.0, 0.0, 0.0, 1.0);
+        let minus_one = Quaternion::new(-1.0, 0.0, 0.0, 0.0);
+
+        assert_eq!(i * i, minus_one);
+        assert_eq!(j * j, minus_one);
+        assert_eq!(k * k, minus_one);
+        assert_eq!(i * j, k);
+        assert_eq!(j * i, -k);
+        assert_eq!(i * j * k, minus_one);
+    }
+
+    #[test]
+    fn test_quaternion_identities() {
+        let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+
+        assert_eq!(q * Quaternion::one(), q);
+        assert_eq!(q + Quaternion::zero(), q);
+        assert!(Quaternion::<f64>::zero().is_zero());
+        assert_eq!(q - q, Quaternion::zero());
+    }
+
+    #[test]
+    fn test_quaternion_conjugate_and_inverse() {
+        let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+
+        assert_eq!(q.conjugate(), Quaternion::new(1.0, -2.0, -3.0, -4.0));
+        assert_eq!(q.norm_squared(), 30.0);
+
+        let product = q * q.inverse().unwrap();
+        assert_quat_eq(&product, &Quaternion::identity());
+        assert!(Quaternion::<f64>::zero().inverse().is_err());
+    }
+
+    #[test]
+    fn test_quaternion_normalize() {
+        let q = Quaternion::new(0.0_f64, 3.0, 0.0, 4.0).normalize().unwrap();
+
+        assert!((q.norm() - 1.0).abs() < EPS);
+        assert_quat_eq(&q, &Quaternion::new(0.0, 0.6, 0.0, 0.8));
+        assert!(Quaternion::<f64>::zero().normalize().is_err());
+    }
+
+    #[test]
+    fn test_quaternion_axis_angle_round_trip() {
+        let axis = Vector::from([1.0, 2.0, 2.0]);
+        let q = Quaternion::from_axis_angle(&axis, FRAC_PI_3).unwrap();
+
+        let (back_axis, angle) = q.to_axis_angle().unwrap();
+
+        assert!((angle - FRAC_PI_3).abs() < EPS);
+        assert_vec_eq(&back_axis, &Vector::from([1.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0]));
+    }
+
+    #[test]
+    fn test_quaternion_axis_angle_errors() {
+        assert!(Quaternion::from_axis_angle(&Vector::from([0.0, 0.0, 0.0]), 1.0).is_err());
+        assert!(Quaternion::from_axis_angle(&Vector::from([1.0, 0.0]), 1.0).is_err());
+    }
+
+    #[test]
+    fn test_quaternion_rotate_vector() {
+        let q = Quaternion::from_axis_angle(&Vector::from([0.0, 0.0, 1.0]), FRAC_PI_2).unwrap();
+
+        let rotated = q.rotate_vector(&Vector::from([1.0, 0.0, 0.0]));
+
+        assert_vec_eq(&rotated, &Vector::from([0.0, 1.0, 0.0]));
+    }
+
+    #[test]
+    fn test_quaternion_composition_matches_sequential_rotation() {
+        let a = Quaternion::from_axis_angle(&Vector::from([0.0, 0.0, 1.0]), FRAC_PI_2).unwrap();
+        let b = Quaternion::from_axis_angle(&Vector::from([1.0, 0.0, 0.0]), FRAC_PI_2).unwrap();
+        let v = Vector::from([1.0, 2.0, 3.0]);
+
+        let sequential = b.rotate_vector(&a.rotate_vector(&v));
+        let composed = (b * a).rotate_vector(&v);
+
+        assert_vec_eq(&sequential, &composed);
+    }
+
+    #[test]
+    fn test_quaternion_rotation_matrix_matches_rotate_vector() {
+        let q = Quaternion::from_axis_angle(&Vector::from([1.0, -1.0, 0.5]), 1.2).unwrap();
+        let v = Vector::from([0.3, -2.0, 1.5]);
+
+        let m3 = q.to_rotation_matrix3();
+        assert_vec_eq(&m3.mul_vec(&v), &q.rotate_vector(&v));
+
+        let m4 = q.to_rotation_matrix4();
+        let h = m4.mul_vec(&Vector::from([0.3, -2.0, 1.5, 1.0]));
+        let expected = q.rotate_vector(&v);
+        assert_vec_eq(&Vector::from([h[0], h[1], h[2]]), &expected);
+        assert!((h[3] - 1.0).abs() < EPS);
+    }
+
+    #[test]
+    fn test_quaternion_from_rotation_matrix_round_trip() {
+        let cases = [
+            (Vector::from([0.0, 0.0, 1.0]), 0.5),
+            (Vector::from([1.0, 0.0, 0.0]), PI - 0.01),
+            (Vector::from([0.0, 1.0, 0.0]), PI),
+            (Vector::from([0.0, 0.0, 1.0]), PI),
+            (Vector::from([1.0, 1.0, 1.0]), 2.5),
+        ];
+
+        for (axis, angle) in cases {
+            let q = Quaternion::from_axis_angle(&axis, angle).unwrap();
+
+            let from3 = Quaternion::from_rotation_matrix(&q.to_rotation_matrix3()).unwrap();
+            let from4 = Quaternion::from_rotation_matrix(&q.to_rotation_matrix4()).unwrap();
+
+            assert_quat_eq(&from3, &q);
+            assert_quat_eq(&from4, &q);
+        }
+    }
+
+    #[test]
+    fn test_quaternion_from_rotation_matrix_invalid_shape() {
+        let m = Matrix::from([[1.0, 0.0], [0.0, 1.0]]);
+
+        assert!(Quaternion::from_rotation_matrix(&m).is_err());
+    }
+
+    #[test]
+    fn test_quaternion_rotation_between() {
+        let from = Vector::from([1.0, 0.0, 0.0]);
+        let to = Vector::from([0.0, 3.0, 0.0]);
+
+        let q = Quaternion::rotation_between(&from, &to).unwrap();
+        assert_vec_eq(&q.rotate_vector(&from), &Vector::from([0.0, 1.0, 0.0]));
+
+        let opposite = Vector::from([-2.0, 0.0, 0.0]);
+        let q = Quaternion::rotation_between(&from, &opposite).unwrap();
+        assert_vec_eq(&q.rotate_vector(&from), &Vector::from([-1.0, 0.0, 0.0]));
+    }
+
+    #[test]
+    fn test_quaternion_slerp() {
+        let axis = Vector::from([0.0, 0.0, 1.0]);
+        let start = Quaternion::identity();
+        let end = Quaternion::from_axis_angle(&axis, FRAC_PI_2).unwrap();
+
+        assert_quat_eq(&start.slerp(&end, 0.0).unwrap(), &start);
+        assert_quat_eq(&start.slerp(&end, 1.0).unwrap(), &end);
+
+        let half = start.slerp(&end, 0.5).unwrap();
+        let expected = Quaternion::from_axis_angle(&axis, FRAC_PI_2 / 2.0).unwrap();
+        assert_quat_eq(&half, &expected);
+
+        assert!(start.slerp(&end, 1.5).is_err());
+    }
+
+    #[test]
+    fn test_quaternion_slerp_takes_shortest_path() {
+        let axis = Vector::from([0.0, 0.0, 1.0]);
+        let start = Quaternion::identity();
+        let end = -Quaternion::from_axis_angle(&axis, FRAC_PI_2).unwrap();
+
+        let half = start.slerp(&end, 0.5).unwrap();
+        let (_, angle) = half.to_axis_angle().unwrap();
+        let angle = if angle > PI { 2.0 * PI - angle } else { angle };
+
+        assert!((angle - FRAC_PI_2 / 2.0).abs() < EPS);
+    }
+
+    #[test]
+    fn test_quaternion_f32() {
+        let q = Quaternion::from_axis_angle(&Vector::from([0.0_f32, 1.0, 0.0]), 1.0).unwrap();
+
+        assert!((q.norm() - 1.0).abs() < 1e-6);
+        assert_eq!(format!("{}", Quaternion::new(1, 2, 3, 4)), "1 + 2i + 3j + 4k");
+    }
+}
@@ -0,0 +1,169 @@
+use linear_algebra_42::{Magnitude, Matrix, Negative, One, Rational, Vector, Zero};
+
+#[cfg(test)]
+mod rational_tests {
+    use super::*;
+
+    fn r(numerator: i64, denominator: i64) -> Rational<i64> {
+        Rational::new(numerator, denominator)
+    }
+
+    fn int(value: i64) -> Rational<i64> {
+        Rational::from_integer(value)
+    }
+
+    #[test]
+    fn test_rational_normalization() {
+        let value = r(6, -8);
+        assert_eq!(value.numerator(), -3);
+        assert_eq!(value.denominator(), 4);
+        assert_eq!(r(2, 4), r(1, 2));
+        assert_eq!(r(0, -5), Rational::zero());
+        assert_eq!(r(0, -5).denominator(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rational_zero_denominator() {
+        let _ = r(1, 0);
+    }
+
+    #[test]
+    fn test_rational_identities() {
+        assert!(Rational::<i32>::zero().is_zero());
+        assert_eq!(Rational::<i32>::one(), Rational::from_integer(1));
+        assert_eq!(Rational::<i32>::negative_one(), Rational::new(-1, 1));
+    }
+
+    #[test]
+    fn test_rational_arithmetic() {
+        assert_eq!(r(1, 2) + r(1, 3), r(5, 6));
+        assert_eq!(r(1, 2) - r(1, 3), r(1, 6));
+        assert_eq!(r(2, 3) * r(9, 4), r(3, 2));
+        assert_eq!(r(2, 3) / r(4, 9), r(3, 2));
+        assert_eq!(-r(2, 3), r(-2, 3));
+        assert_eq!(r(1, 6) + r(1, 3), r(1, 2));
+    }
+
+    #[test]
+    fn test_rational_ordering_and_magnitude() {
+        assert!(r(1, 3) < r(1, 2));
+        assert!(r(-1, 2) < r(-1, 3));
+        assert_eq!(r(-3, 4).abs(), r(3, 4));
+        assert_eq!(r(-3, 4).magnitude(), 0.75);
+        assert_eq!(r(1, 3).recip(), int(3));
+    }
+
+    #[test]
+    fn test_rational_display() {
+        assert_eq!(format!("{}", r(3, 4)), "3/4");
+        assert_eq!(format!("{}", r(-6, 3)), "-2");
+        assert_eq!(format!("{}", r(1, -2)), "-1/2");
+    }
+
+    #[test]
+    fn test_rational_inverse_is_exact() {
+        let m = Matrix::from([
+            [int(1), r(1, 2), r(1, 3)],
+            [r(1, 2), r(1, 3), r(1, 4)],
+            [r(1, 3), r(1, 4), r(1, 5)],
+        ]);
+
+        let inv = m.inverse().expect("Hilbert matrix is invertible");
+
+        let expected = Matrix::from([
+            [int(9), int(-36), int(30)],
+            [int(-36), int(192), int(-180)],
+            [int(30), int(-180), int(180)],
+        ]);
+        assert_eq!(inv, expected);
+        assert_eq!(m.mul_mat(&inv), inv.mul_mat(&m));
+    }
+
+    #[test]
+    fn test_rational_inverse_singular() {
+        let m = Matrix::from([[int(1), int(2)], [int(2), int(4)]]);
+
+        assert!(m.inverse().is_err());
+    }
+
+    #[test]
+    fn test_rational_row_echelon_is_exact() {
+        let m = Matrix::from([[int(3), int(1), int(2)], [int(1), int(3), int(1)]]);
+
+        let echelon = m.row_echelon();
+
+        let expected = Matrix::from([[int(1), int(0), r(5, 8)], [int(0), int(1), r(1, 8)]]);
+        assert_eq!(echelon, expected);
+    }
+
+    #[test]
+    fn test_rational_determinant() {
+        let hilbert = Matrix::from([
+            [int(1), r(1, 2), r(1, 3)],
+            [r(1, 2), r(1, 3), r(1, 4)],
+            [r(1, 3), r(1, 4), r(1, 5)],
+        ]);
+        assert_eq!(hilbert.determinant(), r(1, 2160));
+
+        let m = Matrix::from([
+            [int(2), int(0), int(0), int(0), int(1)],
+            [int(0), int(3), int(0), int(0), int(0)],
+            [int(0), int(0), r(1, 2), int(0), int(0)],
+            [int(0), int(0), int(0), int(4), int(0)],
+            [int(1), int(0), int(0), int(0), int(1)],
+        ]);
+        assert_eq!(m.determinant(), int(6));
+    }
+
+    #[test]
+    fn test_rational_rank() {
+        let mut m = Matrix::from([
+            [r(1, 3), r(2, 3), int(1)],
+            [r(2, 3), r(4, 3), int(2)],
+            [int(1), int(0), r(1, 7)],
+        ]);
+
+        assert_eq!(m.rank(), 2);
+    }
+
+    #[test]
+    fn test_rational_vector_dot() {
+        let u = Vector::from([r(1, 2), r(1, 3)]);
+        let v = Vector::from([r(2, 3), r(3, 4)]);
+
+        assert_eq!(u.dot(&v), r(7, 12));
+    }
+
+    #[test]
+    fn test_rational_checked_overflow() {
+        let big = int(i64::MAX);
+        assert_eq!(big.checked_add(int(1)), None);
+        assert_eq!(int(i64::MIN).checked_neg(), None);
+        assert_eq!(big.checked_mul(int(2)), None);
+        assert_eq!(r(1, i64::MAX).checked_div(int(2)), None);
+        assert_eq!(int(1).checked_div(int(0)), None);
+
+        // Cancellation keeps results in range when they fit
+        assert_eq!(big.checked_mul(r(1, i64::MAX)), Some(int(1)));
+        assert_eq!(big.checked_sub(big), Some(int(0)));
+        assert_eq!(r(1, 3).checked_add(r(1, 6)), Some(r(1, 2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Rational arithmetic overflowed")]
+    fn test_rational_operator_overflow_panics() {
+        let _ = int(i64::MAX) + int(1);
+    }
+
+    #[test]
+    fn test_rational_compare_without_overflow() {
+        let a = r(i64::MAX - 1, i64::MAX);
+        let b = r(i64::MAX - 2, i64::MAX - 1);
+        assert!(a > b);
+        assert!(-a < -b);
+        assert!(r(i64::MIN + 1, i64::MAX) < r(-1, 2));
+        assert_eq!(a.cmp(&a), std::cmp::Ordering::Equal);
+        assert!(r(i64::MAX, 2) > r(i64::MAX - 1, 2));
+    }
+}
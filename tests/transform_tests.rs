@@ -0,0 +1,477 @@
+mod common;
+
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_3, PI};
+
+use linear_algebra_42::transform::{self, ClipSpace, DepthRange, EulerOrder, Handedness};
+use linear_algebra_42::{Matrix, Quaternion, TransformError, Vector};
+
+#[cfg(test)]
+mod transform_tests {
+    use super::*;
+    use crate::common::{assert_mat_close, assert_vec_close};
+
+    fn point(x: f64, y: f64, z: f64) -> Vector<f64> {
+        Vector::from([x, y, z, 1.0])
+    }
+
+    /// Applies `m` to `p` and performs the perspective divide.
+    fn project(m: &Matrix<f64>, p: &Vector<f64>) -> Vector<f64> {
+        let clip = m.mul_vec(p);
+        Vector::from([clip[0] / clip[3], clip[1] / clip[3], clip[2] / clip[3]])
+    }
+
+    #[test]
+    fn test_translation_and_scaling() {
+        let t = transform::translation(1.0, -2.0, 3.0);
+        let s = transform::scaling(2.0, 3.0, 4.0);
+        let p = point(1.0, 1.0, 1.0);
+
+        assert_eq!(t.mul_vec(&p), point(2.0, -1.0, 4.0));
+        assert_eq!(s.mul_vec(&p), point(2.0, 3.0, 4.0));
+
+        // Directions (w = 0) are not translated
+        let d = Vector::from([1.0, 0.0, 0.0, 0.0]);
+        assert_eq!(t.mul_vec(&d), d);
+
+        // Scale first, then translate
+        assert_eq!(s.mul_mat(&t).mul_vec(&p), point(3.0, 1.0, 7.0));
+    }
+
+    #[test]
+    fn test_axis_rotations() {
+        let x = point(1.0, 0.0, 0.0);
+        let y = point(0.0, 1.0, 0.0);
+        let z = point(0.0, 0.0, 1.0);
+
+        assert_vec_close(&transform::rotation_x(FRAC_PI_2).mul_vec(&y), &z);
+        assert_vec_close(&transform::rotation_y(FRAC_PI_2).mul_vec(&z), &x);
+        assert_vec_close(&transform::rotation_z(FRAC_PI_2).mul_vec(&x), &y);
+    }
+
+    #[test]
+    fn test_axis_angle_matches_quaternion() {
+        let axis = Vector::from([1.0, -2.0, 0.5]);
+        let angle = 0.7;
+        let m = transform::rotation_axis_angle(&axis, angle).unwrap();
+        let q = Quaternion::from_axis_angle(&axis, angle).unwrap();
+        let v = Vector::from([0.3, 1.5, -2.0]);
+
+        let rotated = m.mul_vec(&Vector::from([v[0], v[1], v[2], 1.0]));
+        let expected = q.rotate_vector(&v);
+        assert_vec_close(
+            &rotated,
+            &Vector::from([expected[0], expected[1], expected[2], 1.0]),
+        );
+
+        assert_mat_close(
+            &transform::rotation_axis_angle(&Vector::from([1.0, 0.0, 0.0]), angle).unwrap(),
+            &transform::rotation_x(angle),
+        );
+    }
+
+    #[test]
+    fn test_euler_orders() {
+        let (x, y, z) = (0.3, -0.5, 1.1);
+        let (rx, ry, rz) = (
+            transform::rotation_x(x),
+            transform::rotation_y(y),
+            transform::rotation_z(z),
+        );
+
+        assert_mat_close(
+            &transform::rotation_euler(x, y, z, EulerOrder::Zyx),
+            &rz.mul_mat(&ry).mul_mat(&rx),
+        );
+        assert_mat_close(
+            &transform::rotation_euler(x, y, z, EulerOrder::Yxz),
+            &ry.mul_mat(&rx).mul_mat(&rz),
+        );
+
+        // Applying x first then z moves the x axis only through z
+        let p = transform::rotation_euler(FRAC_PI_2, 0.0, FRAC_PI_2, EulerOrder::Xyz)
+            .mul_vec(&point(1.0, 0.0, 0.0));
+        assert_vec_close(&p, &point(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn test_look_at() {
+        let eye = Vector::from([1.0, 2.0, 3.0]);
+        let target = Vector::from([4.0, 2.0, -1.0]);
+        let up = Vector::from([0.0, 1.0, 0.0]);
+
+        let rh = transform::look_at(&eye, &target, &up, Handedness::RightHanded).unwrap();
+        let lh = transform::look_at(&eye, &target, &up, Handedness::LeftHanded).unwrap();
+
+        // |target - eye| = 5
+        assert_vec_close(&rh.mul_vec(&point(1.0, 2.0, 3.0)), &point(0.0, 0.0, 0.0));
+        assert_vec_close(&rh.mul_vec(&point(4.0, 2.0, -1.0)), &point(0.0, 0.0, -5.0));
+        assert_vec_close(&lh.mul_vec(&point(4.0, 2.0, -1.0)), &point(0.0, 0.0, 5.0));
+        assert_vec_close(&rh.mul_vec(&point(1.0, 3.0, 3.0)), &point(0.0, 1.0, 0.0));
+
+        // The rotation part is orthogonal
+        let rotation = Matrix::from_fn(3, 3, |i, j| rh[i][j]);
+        assert!(rotation.is_orthogonal(1e-6));
+    }
+
+    #[test]
+    fn test_perspective_depth_ranges() {
+        let (near, far) = (0.5, 20.0);
+        let cases = [
+            (Handedness::RightHanded, DepthRange::ZeroToOne, -1.0, 0.0),
+            (
+                Handedness::RightHanded,
+                DepthRange::NegativeOneToOne,
+                -1.0,
+                -1.0,
+            ),
+            (Handedness::LeftHanded, DepthRange::ZeroToOne, 1.0, 0.0),
+            (
+                Handedness::LeftHanded,
+                DepthRange::NegativeOneToOne,
+                1.0,
+                -1.0,
+            ),
+        ];
+
+        for (handedness, depth_range, forward, near_depth) in cases {
+            let m = transform::perspective(
+                FRAC_PI_3,
+                2.0,
+                near,
+                far,
+                ClipSpace::new(handedness, depth_range),
+            )
+            .unwrap();
+
+            let n = project(&m, &point(0.0, 0.0, forward * near));
+            let f = project(&m, &point(0.0, 0.0, forward * far));
+            assert!((n[2] - near_depth).abs() < 1e-9);
+            assert!((f[2] - 1.0).abs() < 1e-9);
+
+            // The top edge of the frustum maps to y = 1, the right edge to x = 1
+            let half_height = near * (FRAC_PI_3 / 2.0).tan();
+            let corner = project(&m, &point(2.0 * half_height, half_height, forward * near));
+            assert!((corner[0] - 1.0).abs() < 1e-9);
+            assert!((corner[1] - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_perspective_reversed_z_and_infinite() {
+        let (near, far) = (0.5, 20.0);
+
+        for handedness in [Handedness::RightHanded, Handedness::LeftHanded] {
+            let forward = if handedness == Handedness::RightHanded {
+                -1.0
+            } else {
+                1.0
+            };
+            for (depth_range, low) in [
+                (DepthRange::ZeroToOne, 0.0),
+                (DepthRange::NegativeOneToOne, -1.0),
+            ] {
+                let clip = ClipSpace::new(handedness, depth_range);
+                let depth = |m: &Matrix<f64>, d: f64| project(m, &point(0.0, 0.0, forward * d))[2];
+
+                let reversed =
+                    transform::perspective_reversed_z(FRAC_PI_3, 1.5, near, far, clip).unwrap();
+                assert!((depth(&reversed, near) - 1.0).abs() < 1e-9);
+                assert!((depth(&reversed, far) - low).abs() < 1e-9);
+
+                let infinite = transform::perspective_infinite(FRAC_PI_3, 1.5, near, clip).unwrap();
+                assert!((depth(&infinite, near) - low).abs() < 1e-9);
+                assert!((depth(&infinite, 1e12) - 1.0).abs() < 1e-9);
+
+                // Same x and y as the finite projection
+                let finite = transform::perspective(FRAC_PI_3, 1.5, near, far, clip).unwrap();
+                assert_eq!(reversed[0][0], finite[0][0]);
+                assert_eq!(infinite[1][1], finite[1][1]);
+
+                let both =
+                    transform::perspective_reversed_z(FRAC_PI_3, 1.5, near, f64::INFINITY, clip)
+                        .unwrap();
+                assert!((depth(&both, near) - 1.0).abs() < 1e-9);
+                assert!((depth(&both, 1e12) - low).abs() < 1e-9);
+            }
+        }
+
+        let clip = ClipSpace::new(Handedness::RightHanded, DepthRange::ZeroToOne);
+        assert!(matches!(
+            transform::perspective_infinite(FRAC_PI_3, 1.0, 0.0, clip),
+            Err(TransformError::InvalidFrustum)
+        ));
+    }
+
+    #[test]
+    fn test_orthographic() {
+        let rh = ClipSpace::new(Handedness::RightHanded, DepthRange::NegativeOneToOne);
+        let m = transform::orthographic(-4.0, 2.0, -1.0, 3.0, 1.0, 9.0, rh).unwrap();
+
+        assert_vec_close(
+            &m.mul_vec(&point(-4.0, -1.0, -1.0)),
+            &point(-1.0, -1.0, -1.0),
+        );
+        assert_vec_close(&m.mul_vec(&point(2.0, 3.0, -9.0)), &point(1.0, 1.0, 1.0));
+        assert_vec_close(&m.mul_vec(&point(-1.0, 1.0, -5.0)), &point(0.0, 0.0, 0.0));
+
+        let lh = ClipSpace::new(Handedness::LeftHanded, DepthRange::ZeroToOne);
+        let m = transform::orthographic(-1.0, 1.0, -1.0, 1.0, 1.0, 9.0, lh).unwrap();
+        assert_vec_close(&m.mul_vec(&point(0.0, 0.0, 1.0)), &point(0.0, 0.0, 0.0));
+        assert_vec_close(&m.mul_vec(&point(0.0, 0.0, 5.0)), &point(0.0, 0.0, 0.5));
+    }
+
+    #[test]
+    fn test_homogeneous_round_trip() {
+        let v = Vector::from([1.0, -2.0, 3.0]);
+
+        assert_eq!(v.to_homogeneous_point().unwrap(), point(1.0, -2.0, 3.0));
+        assert_eq!(
+            v.to_homogeneous_direction().unwrap(),
+            Vector::from([1.0, -2.0, 3.0, 0.0])
+        );
+        assert_eq!(
+            Vector::from([2.0, -4.0, 6.0, 2.0])
+                .perspective_divide()
+                .unwrap(),
+            v
+        );
+    }
+
+    #[test]
+    fn test_transform_point_and_direction() {
+        let m = transform::rotation_z(FRAC_PI_2).mul_mat(&transform::translation(1.0, 2.0, 3.0));
+        let v = Vector::from([1.0, 0.0, 0.0]);
+
+        assert_vec_close(
+            &v.transform_point(&m).unwrap(),
+            &Vector::from([1.0, 3.0, 3.0]),
+        );
+        assert_vec_close(
+            &v.transform_direction(&m).unwrap(),
+            &Vector::from([0.0, 1.0, 0.0]),
+        );
+
+        // Matches projecting by hand through a perspective matrix
+        let clip = ClipSpace::new(Handedness::RightHanded, DepthRange::ZeroToOne);
+        let p = transform::perspective(FRAC_PI_3, 1.5, 0.1, 50.0, clip).unwrap();
+        let q = Vector::from([0.4, -0.3, -2.0]);
+        assert_vec_close(
+            &q.transform_point(&p).unwrap(),
+            &project(&p, &point(0.4, -0.3, -2.0)),
+        );
+    }
+
+    #[test]
+    fn test_transform_normal_stays_perpendicular() {
+        let shear = Matrix::from([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.7, 1.0, 0.0, 0.0],
+            [0.0, -0.4, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        let m = transform::scaling(3.0, 0.5, -2.0)
+            .mul_mat(&shear)
+            .mul_mat(&transform::rotation_euler(0.2, 0.9, -0.4, EulerOrder::Zxy))
+            .mul_mat(&transform::translation(4.0, -1.0, 2.0));
+
+        let normal = Vector::from([1.0_f64, 2.0, -1.0]);
+        let tangents = [
+            Vector::from([2.0, -1.0, 0.0]),
+            Vector::from([1.0, 0.0, 1.0]),
+        ];
+        let transformed = normal.transform_normal(&m).unwrap();
+
+        for t in &tangents {
+            assert!(normal.dot(t).abs() < 1e-12);
+            let t = t.transform_direction(&m).unwrap();
+            assert!(transformed.dot(&t).abs() < 1e-9);
+        }
+
+        // Rotations leave normals unchanged relative to directions
+        let r = transform::rotation_axis_angle(&Vector::from([1.0, 1.0, 1.0]), 1.2).unwrap();
+        assert_vec_close(
+            &normal.transform_normal(&r).unwrap(),
+            &normal.transform_direction(&r).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_homogeneous_errors() {
+        let v = Vector::from([1.0, 2.0, 3.0]);
+
+        assert!(matches!(
+            Vector::from([1.0, 2.0, 3.0]).perspective_divide(),
+            Err(TransformError::InvalidHomogeneousLength { len: 3 })
+        ));
+        assert!(matches!(
+            Vector::from([1.0, 2.0, 3.0, 0.0]).perspective_divide(),
+            Err(TransformError::PointAtInfinity)
+        ));
+        assert!(matches!(
+            v.transform_point(&Matrix::identity(3)),
+            Err(TransformError::InvalidMatrixShape {
+                rows: 3,
+                columns: 3
+            })
+        ));
+        assert!(matches!(
+            Vector::from([1.0, 2.0]).transform_direction(&Matrix::identity(4)),
+            Err(TransformError::InvalidVectorLength { len: 2 })
+        ));
+        assert!(matches!(
+            v.transform_normal(&transform::scaling(1.0, 0.0, 1.0)),
+            Err(TransformError::SingularTransform)
+        ));
+
+        // A point on the camera plane has no projection
+        let clip = ClipSpace::new(Handedness::RightHanded, DepthRange::ZeroToOne);
+        let p = transform::perspective(FRAC_PI_3, 1.0, 0.1, 10.0, clip).unwrap();
+        assert!(matches!(
+            Vector::from([1.0, 1.0, 0.0]).transform_point(&p),
+            Err(TransformError::PointAtInfinity)
+        ));
+    }
+
+    #[test]
+    fn test_decompose_affine_components() {
+        let m = transform::scaling(2.0, 0.5, 3.0)
+            .mul_mat(&transform::rotation_axis_angle(&Vector::from([1.0, 2.0, 2.0]), 0.8).unwrap())
+            .mul_mat(&transform::translation(-1.0, 4.0, 2.5));
+        let parts = transform::decompose_affine(&m).unwrap();
+
+        assert_vec_close(&parts.translation, &Vector::from([-1.0, 4.0, 2.5]));
+        assert_vec_close(&parts.scale, &Vector::from([2.0, 0.5, 3.0]));
+        assert_vec_close(&parts.shear, &Vector::from([0.0, 0.0, 0.0]));
+
+        let expected = Quaternion::from_axis_angle(&Vector::from([1.0, 2.0, 2.0]), 0.8).unwrap();
+        let q = parts.rotation_quaternion();
+        // q and -q are the same rotation
+        assert!((q.dot(&expected).abs() - 1.0).abs() < 1e-9);
+        assert!(parts.rotation.is_orthogonal(1e-6));
+    }
+
+    #[test]
+    fn test_decompose_affine_round_trip() {
+        let shear = Matrix::from([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.3, 1.0, 0.0, 0.0],
+            [-0.6, 0.2, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        let rotation = transform::rotation_euler(0.4, -1.1, 2.0, EulerOrder::Yzx);
+
+        // A reflection is reported through negative scale factors
+        for scale in [[1.5, 2.0, 0.25], [1.5, -2.0, 0.25]] {
+            let m = transform::scaling(scale[0], scale[1], scale[2])
+                .mul_mat(&shear)
+                .mul_mat(&rotation)
+                .mul_mat(&transform::translation(3.0, 0.0, -7.0));
+            let parts = transform::decompose_affine(&m).unwrap();
+
+            assert_mat_close(&parts.to_matrix(), &m);
+            let r = &parts.rotation;
+            let det = Matrix::from_fn(3, 3, |i, j| r[i][j]).determinant();
+            assert!((det - 1.0).abs() < 1e-9);
+        }
+
+        let m = transform::scaling(1.5, 2.0, 0.25)
+            .mul_mat(&shear)
+            .mul_mat(&rotation);
+        let parts = transform::decompose_affine(&m).unwrap();
+        assert_vec_close(&parts.shear, &Vector::from([0.3, -0.6, 0.2]));
+    }
+
+    #[test]
+    fn test_affine_inverse() {
+        let m = transform::scaling(2.0, -1.0, 0.5)
+            .mul_mat(&transform::rotation_euler(0.3, 0.2, -0.9, EulerOrder::Xzy))
+            .mul_mat(&transform::translation(1.0, 2.0, -3.0));
+        let inverse = transform::affine_inverse(&m).unwrap();
+
+        assert_mat_close(&inverse, &m.inverse().unwrap());
+        assert_mat_close(&inverse.mul_mat(&m), &Matrix::identity(4));
+
+        // Rigid transforms invert to the transposed rotation
+        let rigid = transform::rotation_y(1.0).mul_mat(&transform::translation(5.0, 0.0, 0.0));
+        let inverse = transform::affine_inverse(&rigid).unwrap();
+        assert_mat_close(
+            &inverse,
+            &transform::translation(-5.0, 0.0, 0.0).mul_mat(&transform::rotation_y(-1.0)),
+        );
+    }
+
+    #[test]
+    fn test_affine_errors() {
+        let clip = ClipSpace::new(Handedness::RightHanded, DepthRange::ZeroToOne);
+        let projection = transform::perspective(1.0, 1.0, 0.1, 10.0, clip).unwrap();
+        let flat = transform::scaling(1.0, 1.0, 0.0);
+
+        assert!(matches!(
+            transform::decompose_affine(&projection),
+            Err(TransformError::NotAffine)
+        ));
+        assert!(matches!(
+            transform::affine_inverse(&projection),
+            Err(TransformError::NotAffine)
+        ));
+        assert!(matches!(
+            transform::decompose_affine(&flat),
+            Err(TransformError::SingularTransform)
+        ));
+        assert!(matches!(
+            transform::affine_inverse(&flat),
+            Err(TransformError::SingularTransform)
+        ));
+        assert!(matches!(
+            transform::affine_inverse(&Matrix::<f64>::identity(3)),
+            Err(TransformError::InvalidMatrixShape {
+                rows: 3,
+                columns: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn test_errors() {
+        let clip = ClipSpace::new(Handedness::RightHanded, DepthRange::ZeroToOne);
+        let origin = Vector::from([0.0, 0.0, 0.0]);
+        let up = Vector::from([0.0, 1.0, 0.0]);
+
+        assert!(matches!(
+            transform::rotation_axis_angle(&Vector::from([1.0, 0.0]), 1.0),
+            Err(TransformError::InvalidVectorLength { len: 2 })
+        ));
+        assert!(matches!(
+            transform::rotation_axis_angle(&origin, 1.0),
+            Err(TransformError::ZeroAxis)
+        ));
+        assert!(matches!(
+            transform::look_at(&origin, &origin, &up, Handedness::RightHanded),
+            Err(TransformError::DegenerateView)
+        ));
+        assert!(matches!(
+            transform::look_at(&origin, &up, &up, Handedness::RightHanded),
+            Err(TransformError::DegenerateView)
+        ));
+        assert!(matches!(
+            transform::perspective(PI, 1.0, 0.1, 10.0, clip),
+            Err(TransformError::InvalidFrustum)
+        ));
+        assert!(matches!(
+            transform::perspective(1.0, 1.0, 10.0, 0.1, clip),
+            Err(TransformError::InvalidFrustum)
+        ));
+        assert!(matches!(
+            transform::perspective(1.0, 1.0, 0.0, 10.0, clip),
+            Err(TransformError::InvalidFrustum)
+        ));
+        assert!(matches!(
+            transform::orthographic(1.0, 1.0, 0.0, 1.0, 0.0, 1.0, clip),
+            Err(TransformError::InvalidFrustum)
+        ));
+        assert_eq!(
+            format!("{}", TransformError::ZeroAxis),
+            "O eixo de rotação não pode ter norma zero"
+        );
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod vector_tests {
-    use linear_algebra_42::vector::Vector;
+    use linear_algebra_42::{matrix::Matrix, vector::Vector};
 
     #[test]
     fn test_new() {
@@ -257,4 +257,45 @@ mod vector_tests {
 
         assert_eq!(result, 2.8);
     }
+
+    #[test]
+    fn test_vector_hadamard() {
+        let u = Vector::from([1.0, -2.0, 3.0]);
+        let v = Vector::from([4.0, 0.5, -1.0]);
+
+        assert_eq!(u.hadamard(&v), Vector::from([4.0, -1.0, -3.0]));
+        assert_eq!(u.hadamard_div(&v), Vector::from([0.25, -4.0, -3.0]));
+    }
+
+    #[test]
+    fn test_vector_outer() {
+        let u = Vector::from([1, 2, 3]);
+        let v = Vector::from([4, 5]);
+
+        let outer = u.outer(&v);
+
+        assert_eq!(outer.shape(), (3, 2));
+        assert_eq!(outer, Matrix::from([[4, 5], [8, 10], [12, 15]]));
+        assert_eq!(v.outer(&u), outer.transpose());
+    }
+
+    #[test]
+    fn test_vector_outer_with_empty_factor() {
+        let empty = Vector::<i32>::from(Vec::new());
+        let v = Vector::from([1, 2, 3]);
+
+        assert_eq!(empty.outer(&v).shape(), (0, 3));
+        assert_eq!(v.outer(&empty).shape(), (3, 0));
+        assert_eq!(empty.complex_outer(&v).shape(), (0, 3));
+    }
+
+    #[test]
+    fn test_vector_outer_rank_one() {
+        let u = Vector::from([1.0, 2.0]);
+        let v = Vector::from([3.0, -1.0, 2.0]);
+
+        let mut outer = u.outer(&v);
+
+        assert_eq!(outer.rank(), 1);
+    }
 }